@@ -0,0 +1,55 @@
+use alloc::vec::Vec;
+
+/// Union-find over the items `0..count` with path halving and union by size.
+pub(crate) struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl DisjointSet {
+    pub(crate) fn new() -> Self {
+        Self {
+            parents: Vec::new(),
+            sizes: Vec::new(),
+        }
+    }
+
+    /// Puts each of `count` items into its own set while keeping the
+    /// allocations.
+    pub(crate) fn reset(&mut self, count: usize) {
+        self.parents.clear();
+        self.parents.extend(0..count);
+        self.sizes.clear();
+        self.sizes.resize(count, 1);
+    }
+
+    /// Returns the representative item of the set containing `index`.
+    pub(crate) fn find(&mut self, mut index: usize) -> usize {
+        while self.parents[index] != index {
+            self.parents[index] = self.parents[self.parents[index]];
+            index = self.parents[index];
+        }
+        index
+    }
+
+    pub(crate) fn union(&mut self, first: usize, second: usize) {
+        let first = self.find(first);
+        let second = self.find(second);
+        if first == second {
+            return;
+        }
+        let (root, child) = if self.sizes[first] >= self.sizes[second] {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+    }
+
+    /// Returns the number of items in the set containing `index`.
+    pub(crate) fn set_size(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+        self.sizes[root]
+    }
+}
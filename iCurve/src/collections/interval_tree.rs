@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+
+/// Dynamic overlap index for closed intervals over the ranks `0..size`.
+///
+/// Every interval is stored at the `O(log n)` segment-tree nodes that cover
+/// it exactly. Nodes also count the active entries stored in their subtree,
+/// so a query descends only into subtrees that can report something. Removal
+/// updates those counts eagerly and drops the stored entries lazily the next
+/// time a query visits their node.
+pub(crate) struct IntervalTree {
+    size: usize,
+    items: Vec<Vec<usize>>,
+    counts: Vec<usize>,
+    is_active: Vec<bool>,
+    stamps: Vec<usize>,
+    stamp: usize,
+}
+
+impl IntervalTree {
+    pub(crate) fn new() -> Self {
+        Self {
+            size: 0,
+            items: Vec::new(),
+            counts: Vec::new(),
+            is_active: Vec::new(),
+            stamps: Vec::new(),
+            stamp: 0,
+        }
+    }
+
+    /// Clears the index for `item_count` items over `size` ranks while
+    /// keeping the node allocations.
+    pub(crate) fn reset(&mut self, size: usize, item_count: usize) {
+        let node_count = size.max(1) << 2;
+        self.size = size;
+        self.items.iter_mut().for_each(Vec::clear);
+        self.items.resize_with(node_count, Vec::new);
+        self.counts.clear();
+        self.counts.resize(node_count, 0);
+        self.is_active.clear();
+        self.is_active.resize(item_count, false);
+        self.stamps.clear();
+        self.stamps.resize(item_count, 0);
+        self.stamp = 0;
+    }
+
+    /// Makes room for items up to `item_count` without touching the stored
+    /// intervals.
+    pub(crate) fn extend_items(&mut self, item_count: usize) {
+        if item_count > self.is_active.len() {
+            self.is_active.resize(item_count, false);
+            self.stamps.resize(item_count, 0);
+        }
+    }
+
+    pub(crate) fn insert(&mut self, item: usize, range: [usize; 2]) {
+        debug_assert!(!self.is_active[item]);
+        debug_assert!(range[0] <= range[1] && range[1] < self.size);
+        self.is_active[item] = true;
+        self.insert_node(1, 0, self.size - 1, range, item);
+    }
+
+    pub(crate) fn remove(&mut self, item: usize, range: [usize; 2]) {
+        debug_assert!(self.is_active[item]);
+        self.is_active[item] = false;
+        self.remove_node(1, 0, self.size - 1, range);
+    }
+
+    /// Visits every active item whose interval shares at least one rank with
+    /// `range`. Each item is visited once.
+    pub(crate) fn for_each_overlap(&mut self, range: [usize; 2], mut visit: impl FnMut(usize)) {
+        if self.size == 0 {
+            return;
+        }
+        self.stamp += 1;
+        self.query_node(1, 0, self.size - 1, range, &mut visit);
+    }
+
+    fn insert_node(
+        &mut self,
+        node: usize,
+        left: usize,
+        right: usize,
+        range: [usize; 2],
+        item: usize,
+    ) -> usize {
+        if range[1] < left || right < range[0] {
+            return 0;
+        }
+
+        let added = if range[0] <= left && right <= range[1] {
+            self.items[node].push(item);
+            1
+        } else {
+            let middle = (left + right) >> 1;
+            self.insert_node(node << 1, left, middle, range, item)
+                + self.insert_node((node << 1) | 1, middle + 1, right, range, item)
+        };
+        self.counts[node] += added;
+        added
+    }
+
+    fn remove_node(&mut self, node: usize, left: usize, right: usize, range: [usize; 2]) -> usize {
+        if range[1] < left || right < range[0] {
+            return 0;
+        }
+
+        let removed = if range[0] <= left && right <= range[1] {
+            1
+        } else {
+            let middle = (left + right) >> 1;
+            self.remove_node(node << 1, left, middle, range)
+                + self.remove_node((node << 1) | 1, middle + 1, right, range)
+        };
+        self.counts[node] -= removed;
+        removed
+    }
+
+    fn query_node(
+        &mut self,
+        node: usize,
+        left: usize,
+        right: usize,
+        range: [usize; 2],
+        visit: &mut impl FnMut(usize),
+    ) {
+        if self.counts[node] == 0 || range[1] < left || right < range[0] {
+            return;
+        }
+
+        if !self.items[node].is_empty() {
+            let is_active = &self.is_active;
+            self.items[node].retain(|&item| is_active[item]);
+
+            // An entry covers the whole node, so it overlaps any query that
+            // reaches the node.
+            for &item in &self.items[node] {
+                if self.stamps[item] != self.stamp {
+                    self.stamps[item] = self.stamp;
+                    visit(item);
+                }
+            }
+        }
+
+        if left < right {
+            let middle = (left + right) >> 1;
+            self.query_node(node << 1, left, middle, range, visit);
+            self.query_node((node << 1) | 1, middle + 1, right, range, visit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn overlaps(tree: &mut IntervalTree, range: [usize; 2]) -> Vec<usize> {
+        let mut result = Vec::new();
+        tree.for_each_overlap(range, |item| result.push(item));
+        result.sort_unstable();
+        result
+    }
+
+    #[test]
+    fn reports_each_overlapping_interval_once() {
+        let mut tree = IntervalTree::new();
+        tree.reset(8, 3);
+        tree.insert(0, [0, 7]);
+        tree.insert(1, [2, 3]);
+        tree.insert(2, [5, 6]);
+
+        assert_eq!(overlaps(&mut tree, [3, 5]), vec![0, 1, 2]);
+        assert_eq!(overlaps(&mut tree, [4, 4]), vec![0]);
+        assert_eq!(overlaps(&mut tree, [7, 7]), vec![0]);
+    }
+
+    #[test]
+    fn removed_intervals_are_not_reported() {
+        let mut tree = IntervalTree::new();
+        tree.reset(5, 2);
+        tree.insert(0, [0, 4]);
+        tree.insert(1, [1, 2]);
+        tree.remove(0, [0, 4]);
+
+        assert_eq!(overlaps(&mut tree, [0, 4]), vec![1]);
+
+        tree.remove(1, [1, 2]);
+        assert!(overlaps(&mut tree, [0, 4]).is_empty());
+
+        tree.reset(3, 1);
+        tree.insert(0, [2, 2]);
+        assert_eq!(overlaps(&mut tree, [1, 2]), vec![0]);
+    }
+
+    #[test]
+    fn extended_items_join_the_existing_index() {
+        let mut tree = IntervalTree::new();
+        tree.reset(4, 1);
+        tree.insert(0, [0, 1]);
+        tree.extend_items(3);
+        tree.insert(2, [1, 3]);
+
+        assert_eq!(overlaps(&mut tree, [1, 1]), vec![0, 2]);
+        assert_eq!(overlaps(&mut tree, [3, 3]), vec![2]);
+    }
+}
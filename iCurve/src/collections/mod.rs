@@ -1,2 +1,4 @@
 pub(crate) mod circular_merge_list;
+pub(crate) mod disjoint_set;
+pub(crate) mod interval_tree;
 pub(crate) mod stack_vec;
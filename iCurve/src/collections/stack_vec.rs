@@ -64,7 +64,6 @@ impl<T: Copy + Default, const CAP: usize> StackVec<T, CAP> {
     }
 
     #[inline]
-    #[cfg(test)]
     pub(crate) fn is_full(&self) -> bool {
         self.len == CAP
     }
@@ -0,0 +1,358 @@
+use crate::float::curve::path::CurvePath;
+use crate::float::curve::segment::CurveSegment;
+use crate::float::curve::shape::CurveShape;
+use crate::float::overlay::{
+    FloatCurveOverlay, FloatCurveOverlayConversionReport, FloatCurveOverlayOptions,
+    FloatCurveOverlayOptionsError,
+};
+use crate::float::resource::CurveResource;
+use crate::int::CurveOverlayError;
+use crate::{FillRule, OverlayRule, Solver};
+use alloc::vec::Vec;
+use i_overlay::i_float::float::compatible::FloatPointCompatible;
+use i_overlay::i_float::float::number::FloatNumber;
+
+/// Integer engine resolving an [`AdaptiveCurveOverlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CurveEngine {
+    /// The standard `i32` engine.
+    I32,
+    /// The wider `i64` engine.
+    I64,
+}
+
+/// Reason an [`AdaptiveCurveOverlay`] moved from `i32` to `i64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CurveEscalationReason {
+    /// The `i32` grid collapsed segments or contours, or linearized arcs.
+    Degeneracies,
+    /// A segment is smaller than
+    /// [`AdaptiveCurveOverlayOptions::min_feature_size`] grid steps of the
+    /// `i32` grid.
+    SmallFeature,
+}
+
+/// Escalation thresholds of an [`AdaptiveCurveOverlay`].
+///
+/// Construct this non-exhaustive configuration from [`Default`] and override
+/// only the values your application needs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct AdaptiveCurveOverlayOptions<F: FloatNumber> {
+    /// Smallest segment extent, in grid steps of the `i32` grid, that the
+    /// `i32` engine may resolve. The extent of a segment is the larger side
+    /// of the bounding box of its control points.
+    ///
+    /// A value that is not positive disables the check.
+    pub min_feature_size: F,
+}
+
+impl<F: FloatNumber> Default for AdaptiveCurveOverlayOptions<F> {
+    fn default() -> Self {
+        Self {
+            min_feature_size: F::from_float(8.0),
+        }
+    }
+}
+
+impl<F: FloatNumber> AdaptiveCurveOverlayOptions<F> {
+    /// Sets the smallest segment extent, in `i32` grid steps, resolved
+    /// without escalation.
+    #[must_use]
+    pub fn with_min_feature_size(mut self, size: F) -> Self {
+        self.min_feature_size = size;
+        self
+    }
+}
+
+/// Boolean overlay for float curve shapes that selects its integer engine.
+///
+/// The inputs are first converted for the standard `i32` engine. When that
+/// conversion loses geometry, linearizes an arc, or leaves a segment smaller
+/// than [`AdaptiveCurveOverlayOptions::min_feature_size`] grid steps, they
+/// are converted again for the `i64` engine. [`engine`](Self::engine)
+/// reports the choice.
+///
+/// ```
+/// use i_curve::{AdaptiveCurveOverlay, CurveBuilder, CurveEngine, FillRule, OverlayRule};
+///
+/// let subject = CurveBuilder::new()
+///     .move_to([0.0_f64, 0.0])?
+///     .line_to([1.0e6, 0.0])?
+///     .line_to([1.0e6, 1.0e6])?
+///     .close_contour()?
+///     .build()?;
+/// let clip = CurveBuilder::new()
+///     .move_to([10.0_f64, 1.0])?
+///     .line_to([10.001, 1.0])?
+///     .line_to([10.001, 1.001])?
+///     .close_contour()?
+///     .build()?;
+///
+/// let overlay = AdaptiveCurveOverlay::new(&subject, &clip);
+/// assert_eq!(overlay.engine(), CurveEngine::I64);
+/// let result = overlay.overlay(OverlayRule::Union, FillRule::NonZero);
+/// assert!(!result.is_empty());
+/// # Ok::<(), i_curve::CurveBuildError>(())
+/// ```
+pub struct AdaptiveCurveOverlay<P: FloatPointCompatible> {
+    overlay: EngineOverlay<P>,
+    escalation: Option<CurveEscalationReason>,
+}
+
+enum EngineOverlay<P: FloatPointCompatible> {
+    I32(FloatCurveOverlay<P, i32>),
+    I64(FloatCurveOverlay<P, i64>),
+}
+
+impl<P: FloatPointCompatible> AdaptiveCurveOverlay<P> {
+    /// Creates an overlay containing subject and clip curve resources with
+    /// the default escalation thresholds.
+    pub fn new<R0, R1>(subject: &R0, clip: &R1) -> Self
+    where
+        R0: CurveResource<P> + ?Sized,
+        R1: CurveResource<P> + ?Sized,
+    {
+        Self::with_options(subject, clip, AdaptiveCurveOverlayOptions::default())
+    }
+
+    /// Creates an overlay containing subject and clip curve resources with
+    /// explicit escalation thresholds.
+    pub fn with_options<R0, R1>(
+        subject: &R0,
+        clip: &R1,
+        options: AdaptiveCurveOverlayOptions<P::Scalar>,
+    ) -> Self
+    where
+        R0: CurveResource<P> + ?Sized,
+        R1: CurveResource<P> + ?Sized,
+    {
+        let narrow = FloatCurveOverlay::<P, i32>::new(subject, clip);
+        let escalation = if narrow.conversion_report().has_degeneracies() {
+            Some(CurveEscalationReason::Degeneracies)
+        } else {
+            let min_size = options.min_feature_size / narrow.scale();
+            let is_small = |extent: P::Scalar| extent < min_size;
+            let has_small_feature = min_segment_extent(subject).is_some_and(is_small)
+                || min_segment_extent(clip).is_some_and(is_small);
+            has_small_feature.then_some(CurveEscalationReason::SmallFeature)
+        };
+
+        let overlay = if escalation.is_some() {
+            EngineOverlay::I64(FloatCurveOverlay::new(subject, clip))
+        } else {
+            EngineOverlay::I32(narrow)
+        };
+        Self { overlay, escalation }
+    }
+
+    /// Returns the integer engine that resolves this overlay.
+    #[inline]
+    pub fn engine(&self) -> CurveEngine {
+        match self.overlay {
+            EngineOverlay::I32(_) => CurveEngine::I32,
+            EngineOverlay::I64(_) => CurveEngine::I64,
+        }
+    }
+
+    /// Returns why the overlay moved to the `i64` engine, or `None` when it
+    /// stayed on `i32`.
+    #[inline]
+    pub fn escalation(&self) -> Option<CurveEscalationReason> {
+        self.escalation
+    }
+
+    /// Sets the topology solver configuration.
+    #[must_use]
+    pub fn with_solver(self, solver: Solver) -> Self {
+        let overlay = match self.overlay {
+            EngineOverlay::I32(overlay) => EngineOverlay::I32(overlay.with_solver(solver)),
+            EngineOverlay::I64(overlay) => EngineOverlay::I64(overlay.with_solver(solver)),
+        };
+        Self { overlay, ..self }
+    }
+
+    /// Sets curve approximation options expressed in float input coordinates.
+    pub fn try_with_options(
+        self,
+        options: FloatCurveOverlayOptions<P::Scalar>,
+    ) -> Result<Self, FloatCurveOverlayOptionsError> {
+        let overlay = match self.overlay {
+            EngineOverlay::I32(overlay) => EngineOverlay::I32(overlay.try_with_options(options)?),
+            EngineOverlay::I64(overlay) => EngineOverlay::I64(overlay.try_with_options(options)?),
+        };
+        Ok(Self { overlay, ..self })
+    }
+
+    /// Returns the effective float-to-integer conversion scale of the
+    /// selected engine.
+    #[inline]
+    pub fn scale(&self) -> P::Scalar {
+        match &self.overlay {
+            EngineOverlay::I32(overlay) => overlay.scale(),
+            EngineOverlay::I64(overlay) => overlay.scale(),
+        }
+    }
+
+    /// Returns topology changes observed while converting each operand for
+    /// the selected engine.
+    #[inline]
+    pub fn conversion_report(&self) -> FloatCurveOverlayConversionReport {
+        match &self.overlay {
+            EngineOverlay::I32(overlay) => overlay.conversion_report(),
+            EngineOverlay::I64(overlay) => overlay.conversion_report(),
+        }
+    }
+
+    /// Performs the Boolean operation and returns float curve shapes.
+    pub fn overlay(self, overlay_rule: OverlayRule, fill_rule: FillRule) -> Vec<CurveShape<P>> {
+        match self.overlay {
+            EngineOverlay::I32(overlay) => overlay.overlay(overlay_rule, fill_rule),
+            EngineOverlay::I64(overlay) => overlay.overlay(overlay_rule, fill_rule),
+        }
+    }
+
+    /// Performs the Boolean operation like [`overlay`](Self::overlay), but
+    /// returns an error naming the failing stage instead of panicking.
+    pub fn try_overlay(
+        self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<Vec<CurveShape<P>>, CurveOverlayError> {
+        match self.overlay {
+            EngineOverlay::I32(overlay) => overlay.try_overlay(overlay_rule, fill_rule),
+            EngineOverlay::I64(overlay) => overlay.try_overlay(overlay_rule, fill_rule),
+        }
+    }
+}
+
+/// Smallest nonzero segment extent of a resource in float coordinates.
+fn min_segment_extent<P, R>(resource: &R) -> Option<P::Scalar>
+where
+    P: FloatPointCompatible,
+    R: CurveResource<P> + ?Sized,
+{
+    resource
+        .iter_paths()
+        .flat_map(path_segment_extents)
+        .filter(|&extent| extent > P::Scalar::ZERO)
+        .reduce(|a, b| if b < a { b } else { a })
+}
+
+fn path_segment_extents<P: FloatPointCompatible>(
+    path: &CurvePath<P>,
+) -> impl Iterator<Item = P::Scalar> + '_ {
+    let mut current = path.start();
+    path.segments().iter().map(move |segment| {
+        let extent = match segment {
+            CurveSegment::Line { to } => points_extent(&[current, *to]),
+            CurveSegment::Quad { ctrl, to } => points_extent(&[current, *ctrl, *to]),
+            CurveSegment::Cubic { ctrl0, ctrl1, to } => points_extent(&[current, *ctrl0, *ctrl1, *to]),
+            CurveSegment::Arc { arc } => points_extent(&arc.control_points),
+        };
+        current = segment.end_point();
+        extent
+    })
+}
+
+fn points_extent<P: FloatPointCompatible>(points: &[P]) -> P::Scalar {
+    let first = points[0];
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (first.x(), first.x(), first.y(), first.y());
+    for point in &points[1..] {
+        if point.x() < min_x {
+            min_x = point.x();
+        } else if point.x() > max_x {
+            max_x = point.x();
+        }
+        if point.y() < min_y {
+            min_y = point.y();
+        } else if point.y() > max_y {
+            max_y = point.y();
+        }
+    }
+
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+    if width > height { width } else { height }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurveBuilder;
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> CurveShape<[f64; 2]> {
+        CurveBuilder::new()
+            .move_to([x0, y0])
+            .unwrap()
+            .line_to([x1, y0])
+            .unwrap()
+            .line_to([x1, y1])
+            .unwrap()
+            .line_to([x0, y1])
+            .unwrap()
+            .close_contour()
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn coarse_input_stays_on_i32() {
+        let subject = rectangle(0.0, 0.0, 10.0, 10.0);
+        let clip = rectangle(5.0, 2.0, 12.0, 8.0);
+
+        let overlay = AdaptiveCurveOverlay::new(&subject, &clip);
+        assert_eq!(overlay.engine(), CurveEngine::I32);
+        assert_eq!(overlay.escalation(), None);
+
+        let expected = subject.overlay(&clip, OverlayRule::Intersect, FillRule::NonZero);
+        assert_eq!(
+            overlay.overlay(OverlayRule::Intersect, FillRule::NonZero),
+            expected
+        );
+    }
+
+    #[test]
+    fn collapsed_geometry_escalates_to_i64() {
+        let subject = rectangle(0.0, 0.0, 10.0, 10.0);
+        let clip = rectangle(5.0, 5.0, 5.0 + 1.0e-9, 5.0 + 1.0e-9);
+        assert!(
+            FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+                .conversion_report()
+                .has_degeneracies()
+        );
+
+        let overlay = AdaptiveCurveOverlay::new(&subject, &clip);
+        assert_eq!(overlay.engine(), CurveEngine::I64);
+        assert_eq!(overlay.escalation(), Some(CurveEscalationReason::Degeneracies));
+        assert!(!overlay.conversion_report().has_degeneracies());
+
+        let result = overlay
+            .try_overlay(OverlayRule::Intersect, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn feature_size_threshold_is_configurable() {
+        let subject = rectangle(0.0, 0.0, 10.0, 10.0);
+        let clip = rectangle(5.0, 2.0, 12.0, 8.0);
+
+        let strict = AdaptiveCurveOverlayOptions::default().with_min_feature_size(1.0e12);
+        let overlay = AdaptiveCurveOverlay::with_options(&subject, &clip, strict);
+        assert_eq!(overlay.engine(), CurveEngine::I64);
+        assert_eq!(overlay.escalation(), Some(CurveEscalationReason::SmallFeature));
+
+        let subject = rectangle(0.0, 0.0, 1.0e6, 1.0e6);
+        let clip = rectangle(10.0, 10.0, 10.03, 10.03);
+        let overlay = AdaptiveCurveOverlay::new(&subject, &clip);
+        assert_eq!(overlay.escalation(), Some(CurveEscalationReason::SmallFeature));
+
+        let disabled = AdaptiveCurveOverlayOptions::default().with_min_feature_size(0.0);
+        let overlay = AdaptiveCurveOverlay::with_options(&subject, &clip, disabled);
+        assert_eq!(overlay.engine(), CurveEngine::I32);
+    }
+}
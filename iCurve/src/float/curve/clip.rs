@@ -0,0 +1,721 @@
+use crate::float::curve::arc::{FloatArcPhase, RationalArc};
+use crate::float::curve::builder::CurveError;
+use crate::float::curve::path::CurvePath;
+use crate::float::curve::segment::CurveSegment;
+use alloc::vec::Vec;
+use i_overlay::i_float::float::compatible::FloatPointCompatible;
+use i_overlay::i_float::float::number::FloatNumber;
+use i_overlay::i_float::float::rect::FloatRect;
+
+const MAX_ROOT_COUNT: usize = 3;
+const BISECTION_STEPS: usize = 64;
+
+/// One side of an axis-aligned clip rectangle.
+#[derive(Clone, Copy)]
+struct ClipLine<F: FloatNumber> {
+    is_vertical: bool,
+    value: F,
+    keeps_greater: bool,
+}
+
+impl<F: FloatNumber> ClipLine<F> {
+    fn sides(rect: &FloatRect<F>) -> [Self; 4] {
+        [
+            Self::new(true, rect.min_x, true),
+            Self::new(true, rect.max_x, false),
+            Self::new(false, rect.min_y, true),
+            Self::new(false, rect.max_y, false),
+        ]
+    }
+
+    #[inline]
+    fn new(is_vertical: bool, value: F, keeps_greater: bool) -> Self {
+        Self {
+            is_vertical,
+            value,
+            keeps_greater,
+        }
+    }
+
+    /// Signed distance to the line; the kept half-plane is non-negative.
+    #[inline]
+    fn offset<P: FloatPointCompatible<Scalar = F>>(&self, point: P) -> F {
+        let coordinate = if self.is_vertical { point.x() } else { point.y() };
+        if self.keeps_greater {
+            coordinate - self.value
+        } else {
+            self.value - coordinate
+        }
+    }
+
+    #[inline]
+    fn snap<P: FloatPointCompatible<Scalar = F>>(&self, point: P) -> P {
+        if self.is_vertical {
+            P::from_xy(self.value, point.y())
+        } else {
+            P::from_xy(point.x(), self.value)
+        }
+    }
+
+    /// Returns `Some(true)` when `bounds` lies in the kept half-plane and
+    /// `Some(false)` when it lies strictly outside.
+    fn classify(&self, bounds: &FloatRect<F>) -> Option<bool> {
+        let (min, max) = if self.is_vertical {
+            (bounds.min_x, bounds.max_x)
+        } else {
+            (bounds.min_y, bounds.max_y)
+        };
+        let (inner, outer) = if self.keeps_greater {
+            (min - self.value, max - self.value)
+        } else {
+            (self.value - max, self.value - min)
+        };
+        if inner >= F::ZERO {
+            Some(true)
+        } else if outer < F::ZERO {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// Clips one closed contour to `rect` with one Sutherland-Hodgman pass per
+/// rectangle side.
+///
+/// Each segment is split where it crosses a side, and pieces outside the
+/// kept half-plane are replaced by a line along that side. Every pass keeps
+/// the winding number of all points inside the half-plane, so fill rules
+/// interpret the clipped contours exactly as the originals inside `rect`.
+///
+/// Returns `Ok(None)` when nothing remains inside `rect`. A clipped contour
+/// that fails validation is reported instead of dropped, because dropping
+/// it would change the winding inside `rect`.
+pub(crate) fn clip_path<P: FloatPointCompatible>(
+    path: &CurvePath<P>,
+    rect: &FloatRect<P::Scalar>,
+) -> Result<Option<CurvePath<P>>, CurveError> {
+    let bounds = path.bounds();
+    let sides = ClipLine::sides(rect);
+    if sides.iter().all(|side| side.classify(&bounds) == Some(true)) {
+        return Ok(Some(path.clone()));
+    }
+    if sides.iter().any(|side| side.classify(&bounds) == Some(false)) {
+        return Ok(None);
+    }
+
+    let mut start = path.start;
+    let mut segments = path.segments.clone();
+    for side in sides {
+        match clip_half_plane(start, &segments, side) {
+            Some(clipped) => (start, segments) = clipped,
+            None => return Ok(None),
+        }
+    }
+
+    CurvePath::validate_parts(start, &segments)?;
+    Ok(Some(CurvePath::from_validated_parts(start, segments)))
+}
+
+fn clip_half_plane<P: FloatPointCompatible>(
+    start: P,
+    segments: &[CurveSegment<P>],
+    side: ClipLine<P::Scalar>,
+) -> Option<(P, Vec<CurveSegment<P>>)> {
+    let mut output = Vec::with_capacity(segments.len());
+    let mut first = None;
+    let mut current = None;
+    let mut from = start;
+
+    for segment in segments {
+        let curve = SegmentCurve::new(from, segment);
+        let roots = curve.crossings(side);
+        let mut cut_start = (P::Scalar::ZERO, from);
+
+        for index in 0..=roots.len() {
+            let cut_end = match roots.get(index) {
+                Some(&t) => (t, side.snap(curve.point_at(t))),
+                None => (P::Scalar::ONE, segment.end_point()),
+            };
+            let middle = curve.point_at((cut_start.0 + cut_end.0) * P::Scalar::HALF);
+
+            if side.offset(middle) >= P::Scalar::ZERO {
+                match current {
+                    None => first = Some(cut_start.1),
+                    Some(point) if !same_point(point, cut_start.1) => {
+                        output.push(CurveSegment::Line { to: cut_start.1 });
+                    }
+                    Some(_) => {}
+                }
+                output.push(curve.piece(segment, cut_start, cut_end));
+                current = Some(cut_end.1);
+            }
+            cut_start = cut_end;
+        }
+
+        from = segment.end_point();
+    }
+
+    let first = first?;
+    if let Some(point) = current
+        && !same_point(point, first)
+    {
+        output.push(CurveSegment::Line { to: first });
+    }
+    Some((first, output))
+}
+
+/// Segment control data with its start point and the coordinate weights
+/// needed to solve axis crossings.
+struct SegmentCurve<P: FloatPointCompatible> {
+    points: [P; 4],
+    weights: [P::Scalar; 3],
+    degree: usize,
+    is_rational: bool,
+}
+
+impl<P: FloatPointCompatible> SegmentCurve<P> {
+    fn new(start: P, segment: &CurveSegment<P>) -> Self {
+        let one = P::Scalar::ONE;
+        match segment {
+            CurveSegment::Line { to } => Self {
+                points: [start, *to, *to, *to],
+                weights: [one; 3],
+                degree: 1,
+                is_rational: false,
+            },
+            CurveSegment::Quad { ctrl, to } => Self {
+                points: [start, *ctrl, *to, *to],
+                weights: [one; 3],
+                degree: 2,
+                is_rational: false,
+            },
+            CurveSegment::Cubic { ctrl0, ctrl1, to } => Self {
+                points: [start, *ctrl0, *ctrl1, *to],
+                weights: [one; 3],
+                degree: 3,
+                is_rational: false,
+            },
+            CurveSegment::Arc { arc } => Self {
+                points: [
+                    arc.control_points[0],
+                    arc.control_points[1],
+                    arc.control_points[2],
+                    arc.control_points[2],
+                ],
+                weights: arc.weights,
+                degree: 2,
+                is_rational: true,
+            },
+        }
+    }
+
+    fn point_at(&self, t: P::Scalar) -> P {
+        let [x, y, w] = self.homogeneous_at(t);
+        P::from_xy(x / w, y / w)
+    }
+
+    fn homogeneous_at(&self, t: P::Scalar) -> [P::Scalar; 3] {
+        let mut levels = self.homogeneous_points();
+        for level in (1..=self.degree).rev() {
+            for index in 0..level {
+                levels[index] = lerp3(levels[index], levels[index + 1], t);
+            }
+        }
+        levels[0]
+    }
+
+    fn homogeneous_points(&self) -> [[P::Scalar; 3]; 4] {
+        core::array::from_fn(|index| {
+            let point = self.points[index];
+            let weight = if self.is_rational {
+                self.weights[index.min(2)]
+            } else {
+                P::Scalar::ONE
+            };
+            [point.x() * weight, point.y() * weight, weight]
+        })
+    }
+
+    /// Returns the sorted parameters in `(0, 1)` where the segment crosses
+    /// the side line.
+    ///
+    /// The signed coordinate offset, multiplied by the positive rational
+    /// denominator for arcs, is a Bernstein polynomial of degree at most
+    /// three. Its derivative roots split `[0, 1]` into monotone intervals,
+    /// and a sign change inside an interval is bracketed by bisection. A
+    /// break where the offset is exactly zero is a root as well, so an
+    /// extremum that touches the line becomes a cut.
+    fn crossings(&self, side: ClipLine<P::Scalar>) -> StackRoots<P::Scalar> {
+        let mut coefficients = [0.0; 4];
+        for (index, coefficient) in coefficients.iter_mut().enumerate().take(self.degree + 1) {
+            let weight = if self.is_rational {
+                self.weights[index].to_f64()
+            } else {
+                1.0
+            };
+            *coefficient = side.offset(self.points[index]).to_f64() * weight;
+        }
+        let polynomial = &coefficients[..=self.degree];
+
+        let mut breaks = [0.0; MAX_ROOT_COUNT + 1];
+        let mut break_count = 0;
+        breaks[break_count] = 0.0;
+        break_count += 1;
+        for root in derivative_roots(polynomial) {
+            if root > 0.0 && root < 1.0 {
+                breaks[break_count] = root;
+                break_count += 1;
+            }
+        }
+        breaks[..break_count].sort_by(|a, b| a.total_cmp(b));
+
+        let mut roots = StackRoots::new();
+        for index in 0..break_count {
+            let low = breaks[index];
+            let high = if index + 1 < break_count {
+                breaks[index + 1]
+            } else {
+                1.0
+            };
+            if index > 0 && bernstein(polynomial, low) == 0.0 {
+                roots.push(P::Scalar::from_float(low));
+            }
+            if let Some(root) = bisect(polynomial, low, high)
+                && root > 0.0
+                && root < 1.0
+            {
+                roots.push(P::Scalar::from_float(root));
+            }
+        }
+        roots
+    }
+
+    /// Returns the part of the segment between two cuts with the cut points
+    /// as exact endpoints. An uncut segment is returned unchanged.
+    fn piece(
+        &self,
+        segment: &CurveSegment<P>,
+        (t0, start): (P::Scalar, P),
+        (t1, end): (P::Scalar, P),
+    ) -> CurveSegment<P> {
+        if t0 == P::Scalar::ZERO && t1 == P::Scalar::ONE {
+            return segment.clone();
+        }
+
+        let mut points = self.homogeneous_points();
+        split_range(&mut points, self.degree, t0, t1);
+        let mut controls: [P; 4] = core::array::from_fn(|index| {
+            let [x, y, w] = points[index];
+            P::from_xy(x / w, y / w)
+        });
+        controls[0] = start;
+        controls[self.degree] = end;
+
+        match segment {
+            CurveSegment::Line { .. } => CurveSegment::Line { to: end },
+            CurveSegment::Quad { .. } => CurveSegment::Quad {
+                ctrl: controls[1],
+                to: end,
+            },
+            CurveSegment::Cubic { .. } => CurveSegment::Cubic {
+                ctrl0: controls[1],
+                ctrl1: controls[2],
+                to: end,
+            },
+            CurveSegment::Arc { arc } => {
+                let weights = [points[0][2], points[1][2], points[2][2]];
+                match arc_piece(arc, [controls[0], controls[1], controls[2]], weights) {
+                    Some(arc) => CurveSegment::Arc { arc },
+                    None => CurveSegment::Line { to: end },
+                }
+            }
+        }
+    }
+}
+
+/// Restricts homogeneous Bernstein control points to `[t0, t1]`.
+fn split_range<F: FloatNumber>(points: &mut [[F; 3]; 4], degree: usize, t0: F, t1: F) {
+    if t1 < F::ONE {
+        // Keep the left part of the split at `t1`.
+        for level in 1..=degree {
+            for index in (level..=degree).rev() {
+                points[index] = lerp3(points[index - 1], points[index], t1);
+            }
+        }
+    }
+    if t0 > F::ZERO {
+        // Keep the right part of the remaining curve at `t0 / t1`.
+        let t = t0 / t1;
+        for level in 1..=degree {
+            for index in 0..=degree - level {
+                points[index] = lerp3(points[index], points[index + 1], t);
+            }
+        }
+    }
+}
+
+/// Rebuilds arc metadata for a rational subsegment in standard form.
+fn arc_piece<P: FloatPointCompatible>(
+    arc: &RationalArc<P>,
+    control_points: [P; 3],
+    weights: [P::Scalar; 3],
+) -> Option<RationalArc<P>> {
+    let ends = (weights[0] * weights[2]).sqrt();
+    let weights = [P::Scalar::ONE, weights[1] / ends, P::Scalar::ONE];
+
+    let direction = if arc.sweep_angle < P::Scalar::ZERO {
+        -P::Scalar::ONE
+    } else {
+        P::Scalar::ONE
+    };
+    let start_progress = arc_progress(arc, control_points[0]);
+    let end_progress = arc_progress(arc, control_points[2]).max(start_progress);
+    let sweep_angle = direction * (end_progress - start_progress);
+    if sweep_angle == P::Scalar::ZERO {
+        return None;
+    }
+
+    let piece = RationalArc {
+        ellipse: arc.ellipse,
+        control_points,
+        weights,
+        start_angle: arc.start_angle + direction * start_progress,
+        sweep_angle,
+    };
+    piece.validate().is_ok().then_some(piece)
+}
+
+/// Returns how far `point` lies along the directed supporting arc, in
+/// radians from its start and clamped to its sweep.
+fn arc_progress<P: FloatPointCompatible>(arc: &RationalArc<P>, point: P) -> P::Scalar {
+    let ellipse = arc.ellipse;
+    let (sin, cos) = ellipse.rotation.sin_cos();
+    let dx = point.x() - ellipse.center.x();
+    let dy = point.y() - ellipse.center.y();
+    let local_x = (dx * cos + dy * sin) / ellipse.radius_x;
+    let local_y = (-dx * sin + dy * cos) / ellipse.radius_y;
+    let length = (local_x * local_x + local_y * local_y).sqrt();
+    if length == P::Scalar::ZERO {
+        return P::Scalar::ZERO;
+    }
+
+    let phase = FloatArcPhase {
+        cos: local_x / length,
+        sin: local_y / length,
+    };
+    let start = FloatArcPhase::from_angle(arc.start_angle);
+    let dot = start.dot(phase).max(-P::Scalar::ONE).min(P::Scalar::ONE);
+    let angle = dot.acos();
+    let cross = start.cos * phase.sin - start.sin * phase.cos;
+    let is_forward = (cross >= P::Scalar::ZERO) == (arc.sweep_angle >= P::Scalar::ZERO);
+    let tau = P::Scalar::from_float(core::f64::consts::TAU);
+    let progress = if is_forward || angle == P::Scalar::ZERO {
+        angle
+    } else {
+        tau - angle
+    };
+    progress.min(arc.sweep_angle.abs())
+}
+
+fn derivative_roots(polynomial: &[f64]) -> StackRoots<f64> {
+    let mut roots = StackRoots::new();
+    match *polynomial {
+        [p0, p1, p2] => {
+            let denominator = p0 - 2.0 * p1 + p2;
+            if denominator != 0.0 {
+                roots.push((p0 - p1) / denominator);
+            }
+        }
+        [p0, p1, p2, p3] => {
+            // Bernstein coefficients of the derivative, up to a factor of 3.
+            let d0 = p1 - p0;
+            let d1 = p2 - p1;
+            let d2 = p3 - p2;
+            let a = d0 - 2.0 * d1 + d2;
+            let b = 2.0 * (d1 - d0);
+            let c = d0;
+            if a.abs() <= f64::EPSILON * (b.abs() + c.abs()) {
+                if b != 0.0 {
+                    roots.push(-c / b);
+                }
+            } else {
+                let discriminant = b * b - 4.0 * a * c;
+                if discriminant >= 0.0 {
+                    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+                    let first = q / a;
+                    if q == 0.0 {
+                        roots.push(first);
+                    } else {
+                        let second = c / q;
+                        roots.push(first.min(second));
+                        roots.push(first.max(second));
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    roots
+}
+
+fn bisect(polynomial: &[f64], mut low: f64, mut high: f64) -> Option<f64> {
+    let mut low_value = bernstein(polynomial, low);
+    let high_value = bernstein(polynomial, high);
+    if low_value == 0.0 || high_value == 0.0 || (low_value < 0.0) == (high_value < 0.0) {
+        return None;
+    }
+
+    for _ in 0..BISECTION_STEPS {
+        let middle = 0.5 * (low + high);
+        if middle <= low || middle >= high {
+            break;
+        }
+        let value = bernstein(polynomial, middle);
+        if value == 0.0 {
+            return Some(middle);
+        }
+        if (value < 0.0) == (low_value < 0.0) {
+            low = middle;
+            low_value = value;
+        } else {
+            high = middle;
+        }
+    }
+    Some(0.5 * (low + high))
+}
+
+fn bernstein(polynomial: &[f64], t: f64) -> f64 {
+    let mut levels = [0.0; 4];
+    levels[..polynomial.len()].copy_from_slice(polynomial);
+    for level in (1..polynomial.len()).rev() {
+        for index in 0..level {
+            levels[index] += (levels[index + 1] - levels[index]) * t;
+        }
+    }
+    levels[0]
+}
+
+#[inline]
+fn lerp3<F: FloatNumber>(a: [F; 3], b: [F; 3], t: F) -> [F; 3] {
+    core::array::from_fn(|index| a[index] + (b[index] - a[index]) * t)
+}
+
+#[inline]
+fn same_point<P: FloatPointCompatible>(a: P, b: P) -> bool {
+    a.x() == b.x() && a.y() == b.y()
+}
+
+/// Sorted list of at most three parameters.
+struct StackRoots<F> {
+    values: [F; MAX_ROOT_COUNT],
+    len: usize,
+}
+
+impl<F: FloatNumber> StackRoots<F> {
+    fn new() -> Self {
+        Self {
+            values: [F::ZERO; MAX_ROOT_COUNT],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, value: F) {
+        if self.len < MAX_ROOT_COUNT && (self.len == 0 || self.values[self.len - 1] < value) {
+            self.values[self.len] = value;
+            self.len += 1;
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<&F> {
+        self.values[..self.len].get(index)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl IntoIterator for StackRoots<f64> {
+    type Item = f64;
+    type IntoIter = core::iter::Take<core::array::IntoIter<f64, MAX_ROOT_COUNT>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter().take(self.len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::float::curve::arc::{Ellipse, EllipticArc};
+    use crate::float::curve::builder::{CurveBuilder, CurveError};
+
+    type Point = [f64; 2];
+
+    fn rect(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> FloatRect<f64> {
+        FloatRect {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+
+    fn assert_inside(path: &CurvePath<Point>, rect: &FloatRect<f64>) {
+        let mut from = path.start();
+        for segment in path.segments() {
+            let curve = SegmentCurve::new(from, segment);
+            for step in 0..=16 {
+                let [x, y] = curve.point_at(step as f64 / 16.0);
+                assert!(x >= rect.min_x - 1.0e-9 && x <= rect.max_x + 1.0e-9, "x = {x}");
+                assert!(y >= rect.min_y - 1.0e-9 && y <= rect.max_y + 1.0e-9, "y = {y}");
+            }
+            from = segment.end_point();
+        }
+    }
+
+    #[test]
+    fn keeps_contained_and_drops_disjoint_contours() -> Result<(), CurveError> {
+        let shape = CurveBuilder::new()
+            .move_to([1.0, 1.0])?
+            .line_to([2.0, 1.0])?
+            .quad_to([2.0, 2.0], [1.0, 1.0])?
+            .move_to([20.0, 20.0])?
+            .line_to([21.0, 20.0])?
+            .line_to([20.0, 21.0])?
+            .close_contour()?
+            .build()?;
+
+        let clipped = shape
+            .clip_rect(rect(0.0, 0.0, 10.0, 10.0))?
+            .expect("inside contour");
+        assert_eq!(clipped.contours(), &shape.contours()[..1]);
+        assert!(shape.clip_rect(rect(30.0, 30.0, 40.0, 40.0))?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn splits_lines_exactly_at_the_rectangle_sides() -> Result<(), CurveError> {
+        let shape = CurveBuilder::new()
+            .move_to([-2.0, -2.0])?
+            .line_to([2.0, -2.0])?
+            .line_to([2.0, 2.0])?
+            .line_to([-2.0, 2.0])?
+            .close_contour()?
+            .build()?;
+        let bounds = rect(0.0, -1.0, 5.0, 1.0);
+
+        let clipped = shape.clip_rect(bounds)?.expect("overlapping contour");
+        let path = &clipped.contours()[0];
+        assert!(
+            path.segments()
+                .iter()
+                .all(|segment| matches!(segment, CurveSegment::Line { .. }))
+        );
+        let clipped_bounds = path.bounds();
+        assert_eq!(
+            [
+                clipped_bounds.min_x,
+                clipped_bounds.min_y,
+                clipped_bounds.max_x,
+                clipped_bounds.max_y
+            ],
+            [0.0, -1.0, 2.0, 1.0]
+        );
+        assert_inside(path, &bounds);
+        Ok(())
+    }
+
+    #[test]
+    fn bezier_pieces_keep_their_kind_and_lie_on_the_source() -> Result<(), CurveError> {
+        let shape = CurveBuilder::new()
+            .move_to([-4.0, 0.0])?
+            .cubic_to([-4.0, 6.0], [4.0, 6.0], [4.0, 0.0])?
+            .quad_to([0.0, -4.0], [-4.0, 0.0])?
+            .build()?;
+        let source = &shape.contours()[0];
+        let bounds = rect(-1.0, -3.0, 1.0, 10.0);
+
+        let clipped = shape.clip_rect(bounds)?.expect("overlapping contour");
+        let path = &clipped.contours()[0];
+        assert!(
+            path.segments()
+                .iter()
+                .any(|segment| matches!(segment, CurveSegment::Cubic { .. }))
+        );
+        assert!(
+            path.segments()
+                .iter()
+                .any(|segment| matches!(segment, CurveSegment::Quad { .. }))
+        );
+        assert_inside(path, &bounds);
+
+        let cubic = SegmentCurve::new(source.start(), &source.segments()[0]);
+        let roots = cubic.crossings(ClipLine::new(true, -1.0, true));
+        assert_eq!(roots.len(), 1);
+        assert!((cubic.point_at(*roots.get(0).unwrap())[0] + 1.0).abs() < 1.0e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn extremum_touching_a_side_becomes_a_vertex() -> Result<(), CurveError> {
+        let shape = CurveBuilder::new()
+            .move_to([0.0, 0.0])?
+            .quad_to([2.0, 4.0], [4.0, 0.0])?
+            .line_to([0.0, 0.0])?
+            .build()?;
+
+        let bounds = rect(-1.0, -1.0, 5.0, 2.0);
+        let clipped = shape.clip_rect(bounds)?.expect("touching contour");
+        let path = &clipped.contours()[0];
+        assert!(
+            path.segments()
+                .iter()
+                .any(|segment| segment.end_point() == [2.0, 2.0])
+        );
+        assert_inside(path, &bounds);
+
+        // Touching from outside leaves nothing inside the rectangle.
+        assert!(shape.clip_rect(rect(-1.0, 2.0, 5.0, 3.0))?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn arc_pieces_stay_on_the_source_ellipse() -> Result<(), CurveError> {
+        let ellipse = Ellipse {
+            center: [0.0, 0.0],
+            radius_x: 4.0,
+            radius_y: 2.0,
+            rotation: 0.3,
+        };
+        let arc = EllipticArc {
+            ellipse,
+            start_angle: 0.0,
+            sweep_angle: core::f64::consts::TAU,
+        };
+        let shape = CurveBuilder::new()
+            .move_to(arc.start_point())?
+            .arc_to(arc)?
+            .build()?;
+        let bounds = rect(0.0, 0.0, 10.0, 10.0);
+
+        let clipped = shape.clip_rect(bounds)?.expect("overlapping contour");
+        let path = &clipped.contours()[0];
+        assert_inside(path, &bounds);
+        let arcs: Vec<_> = path
+            .segments()
+            .iter()
+            .filter_map(|segment| match segment {
+                CurveSegment::Arc { arc } => Some(arc),
+                _ => None,
+            })
+            .collect();
+        assert!(!arcs.is_empty());
+        for arc in arcs {
+            assert_eq!(arc.ellipse, ellipse);
+            assert!(arc.try_to_elliptic_arc(1.0e-9).is_some());
+        }
+        Ok(())
+    }
+}
@@ -266,7 +266,7 @@ pub fn try_convert_shape_to_float<P: FloatPointCompatible, I: CurveInt>(
     FloatCurveShape::try_new(contours).map_err(CurveToFloatError::InvalidFloatShape)
 }
 
-fn adapter_contains_bounds<P: FloatPointCompatible, I: CurveInt>(
+pub(crate) fn adapter_contains_bounds<P: FloatPointCompatible, I: CurveInt>(
     adapter: &FloatPointAdapter<P, I>,
     bounds: FloatRect<P::Scalar>,
 ) -> bool {
@@ -1,5 +1,6 @@
 pub(crate) mod arc;
 pub(crate) mod builder;
+pub(crate) mod clip;
 pub(crate) mod converter;
 pub(crate) mod path;
 pub(crate) mod segment;
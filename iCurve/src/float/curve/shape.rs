@@ -1,4 +1,5 @@
 use crate::float::curve::builder::CurveError;
+use crate::float::curve::clip::clip_path;
 use crate::float::curve::path::CurvePath;
 use crate::float::curve::path::finite_rect;
 use alloc::vec::Vec;
@@ -55,6 +56,25 @@ impl<P: FloatPointCompatible> CurveShape<P> {
         &self.contours
     }
 
+    /// Returns the first contour of the shape, or `None` for a shape without
+    /// contours.
+    ///
+    /// Shapes returned by Boolean operations store their outer boundary
+    /// first and its holes after it. Other shapes follow this convention
+    /// only if their contours were added in that order; neither the order
+    /// nor the orientation of the contours is checked.
+    #[inline]
+    pub fn outer(&self) -> Option<&CurvePath<P>> {
+        self.contours.first()
+    }
+
+    /// Returns the contours after the [`outer`](Self::outer) one, which are
+    /// the holes of a Boolean result.
+    #[inline]
+    pub fn holes(&self) -> &[CurvePath<P>] {
+        self.contours.get(1..).unwrap_or_default()
+    }
+
     /// Returns an iterator over the contours in this shape.
     #[inline]
     pub fn iter(&self) -> core::slice::Iter<'_, CurvePath<P>> {
@@ -82,6 +102,32 @@ impl<P: FloatPointCompatible> CurveShape<P> {
         self.contours.iter().map(|path| path.segments.len()).sum()
     }
 
+    /// Clips this shape to an axis-aligned rectangle.
+    ///
+    /// Lines, Bézier curves, and rational arcs are split exactly where they
+    /// cross the rectangle sides and keep their segment kinds. Portions
+    /// outside the rectangle are replaced by lines along its boundary, so
+    /// each contour keeps its winding contribution inside `rect` and the
+    /// result is interpreted under the same fill rule as the original.
+    /// Geometry stays in float coordinates without grid snapping.
+    ///
+    /// Returns `Ok(None)` when no contour reaches into `rect`. A clipped
+    /// contour that fails validation, for example because splitting
+    /// overflowed to non-finite control points, is reported as an error
+    /// rather than dropped, since dropping it would change the filled area.
+    pub fn clip_rect(&self, rect: FloatRect<P::Scalar>) -> Result<Option<Self>, CurveError> {
+        let mut contours = Vec::with_capacity(self.contours.len());
+        for path in &self.contours {
+            if let Some(clipped) = clip_path(path, &rect)? {
+                contours.push(clipped);
+            }
+        }
+        if contours.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self::from_validated_contours(contours)))
+    }
+
     pub(crate) fn validate_contours(contours: &[CurvePath<P>]) -> Result<(), CurveError> {
         if contours.is_empty() {
             return Err(CurveError::NoContours);
@@ -3,10 +3,15 @@
 //! Use [`CurveBuilder`] to create validated closed curves, [`CurveResource`]
 //! to pass paths or shape collections to an operation, and
 //! [`FloatCurveOverlay`] when conversion scale or solver settings must be
-//! controlled explicitly.
+//! controlled explicitly. [`AdaptiveCurveOverlay`] moves to the `i64`
+//! engine when the `i32` grid is too coarse for the input.
+//! [`FloatPreparedCurveSubject`] resolves one subject against many clips
+//! without preparing it again.
 
+mod adaptive;
 mod curve;
 mod overlay;
+mod prepared;
 mod resource;
 
 /// Ellipses and elliptic-arc representations in float coordinates.
@@ -14,6 +19,7 @@ pub mod arc {
     pub use super::curve::arc::{Ellipse, EllipticArc, EllipticArcError, RationalArc, RationalArcError};
 }
 
+pub use adaptive::{AdaptiveCurveOverlay, AdaptiveCurveOverlayOptions, CurveEngine, CurveEscalationReason};
 pub use curve::builder::{CurveBuilder, CurveError as CurveBuildError};
 pub use curve::converter::{
     CurveConversionError, CurveConversionReport, CurveConverter, CurveToFloatError,
@@ -25,7 +31,8 @@ pub use curve::shape::CurveShape;
 pub use i_overlay::i_float::adapter::FloatPointAdapter;
 pub use i_overlay::i_float::float::compatible::FloatPointCompatible;
 pub use overlay::{
-    CurveResourceOverlayExt, FloatCurveOverlay, FloatCurveOverlayConversionReport, FloatCurveOverlayOptions,
-    FloatCurveOverlayOptionsError,
+    CurveResourceOverlayExt, CurveResourceRelateExt, FloatCurveOverlay, FloatCurveOverlayConversionReport,
+    FloatCurveOverlayOptions, FloatCurveOverlayOptionsError,
 };
+pub use prepared::FloatPreparedCurveSubject;
 pub use resource::CurveResource;
@@ -1,10 +1,15 @@
-use crate::float::curve::converter::{CurveConversionReport, convert_resource, convert_shapes_to_float};
+use crate::float::curve::converter::{
+    CurveConversionReport, convert_resource, convert_shapes_to_float, try_convert_shape_to_float,
+};
 use crate::float::curve::path::CurvePath;
 use crate::float::curve::shape::CurveShape;
 use crate::float::resource::{CurveResource, resource_bounds};
 use crate::int::CURVE_COORDINATE_SAFETY_BITS;
-use crate::int::{CurveInt, CurveOverlayOptions, CurveOverlayOptionsError, IntCurveOverlay};
-use crate::{CurveConversionError, FillRule, OverlayRule, Solver};
+use crate::int::{
+    CurveContourTree, CurveInt, CurveOverlayError, CurveOverlayOptions, CurveOverlayOptionsError,
+    CurveOverlayRepro, CurveReproAdapter, IntCurveOverlay, ShapeType,
+};
+use crate::{ContourDirection, CurveConversionError, FillRule, OverlayRule, Solver};
 use i_overlay::i_float::adapter::FloatPointAdapter;
 use i_overlay::i_float::float::compatible::FloatPointCompatible;
 use i_overlay::i_float::float::number::FloatNumber;
@@ -37,6 +42,28 @@ pub struct FloatCurveOverlayOptions<F: FloatNumber> {
     pub refinement_angle_tolerance_power: u32,
     /// Maximum number of containment-refinement passes.
     pub max_refinement_iterations: u32,
+    /// Orientation of result contours, see
+    /// [`CurveOverlayOptions::output_direction`].
+    pub output_direction: ContourDirection,
+    /// Returns results in canonical order, see
+    /// [`CurveOverlayOptions::canonical_output`].
+    pub canonical_output: bool,
+    /// Area in squared input units below which a result contour is dropped,
+    /// see [`CurveOverlayOptions::min_contour_area`]. `None` keeps every
+    /// contour.
+    pub min_contour_area: Option<F>,
+    /// Area-to-perimeter ratio in input units below which a result contour
+    /// is dropped as a sliver, see
+    /// [`CurveOverlayOptions::min_area_perimeter_ratio`]. `None` keeps every
+    /// contour.
+    pub min_area_perimeter_ratio: Option<F>,
+    /// Length in input units below which a result segment is absorbed into
+    /// a neighbour, see [`CurveOverlayOptions::min_segment_length`]. `None`
+    /// keeps every segment.
+    pub min_segment_length: Option<F>,
+    /// Joins consecutive collinear result lines, see
+    /// [`CurveOverlayOptions::merge_collinear_lines`].
+    pub merge_collinear_lines: bool,
 }
 
 impl<F: FloatNumber> Default for FloatCurveOverlayOptions<F> {
@@ -48,6 +75,12 @@ impl<F: FloatNumber> Default for FloatCurveOverlayOptions<F> {
             refinement_subdivision_power: CurveOverlayOptions::default().refinement_subdivision_power,
             refinement_angle_tolerance_power: CurveOverlayOptions::default().refinement_angle_tolerance_power,
             max_refinement_iterations: CurveOverlayOptions::default().max_refinement_iterations,
+            output_direction: CurveOverlayOptions::default().output_direction,
+            canonical_output: CurveOverlayOptions::default().canonical_output,
+            min_contour_area: None,
+            min_area_perimeter_ratio: None,
+            min_segment_length: None,
+            merge_collinear_lines: CurveOverlayOptions::default().merge_collinear_lines,
         }
     }
 }
@@ -64,6 +97,13 @@ pub enum FloatCurveOverlayOptionsError {
     AngleToleranceNotFinite,
     /// The requested angle tolerance is outside `(0, 1]`.
     AngleToleranceOutOfRange,
+    /// The requested minimum contour area is negative, NaN, or infinite.
+    MinContourAreaInvalid,
+    /// The requested minimum area-to-perimeter ratio is negative, NaN, or
+    /// infinite.
+    MinAreaPerimeterRatioInvalid,
+    /// The requested minimum segment length is negative, NaN, or infinite.
+    MinSegmentLengthInvalid,
     /// Integer approximation limits rejected the converted configuration.
     Approximation(CurveOverlayOptionsError),
 }
@@ -83,6 +123,15 @@ impl core::fmt::Display for FloatCurveOverlayOptionsError {
             Self::AngleToleranceOutOfRange => {
                 formatter.write_str("angle tolerance must be in the range (0, 1]")
             }
+            Self::MinContourAreaInvalid => {
+                formatter.write_str("minimum contour area must be finite and non-negative")
+            }
+            Self::MinAreaPerimeterRatioInvalid => {
+                formatter.write_str("minimum area-to-perimeter ratio must be finite and non-negative")
+            }
+            Self::MinSegmentLengthInvalid => {
+                formatter.write_str("minimum segment length must be finite and non-negative")
+            }
             Self::Approximation(_) => formatter.write_str("invalid curve approximation options"),
         }
     }
@@ -140,7 +189,50 @@ impl<F: FloatNumber> FloatCurveOverlayOptions<F> {
         self
     }
 
-    fn to_int<P, I>(
+    /// Sets the orientation of result contours.
+    #[must_use]
+    pub fn with_output_direction(mut self, direction: ContourDirection) -> Self {
+        self.output_direction = direction;
+        self
+    }
+
+    /// Sets whether results are returned in canonical order.
+    #[must_use]
+    pub fn with_canonical_output(mut self, is_canonical: bool) -> Self {
+        self.canonical_output = is_canonical;
+        self
+    }
+
+    /// Sets the smallest area of a kept result contour.
+    #[must_use]
+    pub fn with_min_contour_area(mut self, area: F) -> Self {
+        self.min_contour_area = Some(area);
+        self
+    }
+
+    /// Sets the smallest area-to-perimeter ratio of a kept result contour.
+    #[must_use]
+    pub fn with_min_area_perimeter_ratio(mut self, ratio: F) -> Self {
+        self.min_area_perimeter_ratio = Some(ratio);
+        self
+    }
+
+    /// Sets the length below which result segments are absorbed into a
+    /// neighbour.
+    #[must_use]
+    pub fn with_min_segment_length(mut self, length: F) -> Self {
+        self.min_segment_length = Some(length);
+        self
+    }
+
+    /// Sets whether consecutive collinear result lines are joined.
+    #[must_use]
+    pub fn with_merge_collinear_lines(mut self, is_merged: bool) -> Self {
+        self.merge_collinear_lines = is_merged;
+        self
+    }
+
+    pub(crate) fn to_int<P, I>(
         self,
         adapter: &FloatPointAdapter<P, I>,
     ) -> Result<CurveOverlayOptions, FloatCurveOverlayOptionsError>
@@ -182,6 +274,31 @@ impl<F: FloatNumber> FloatCurveOverlayOptions<F> {
             truncated_power
         } as u32;
 
+        // Thresholds are rounded up so a contour below the float threshold
+        // never survives on the grid.
+        let scale = adapter.dir_scale().to_f64();
+        let min_contour_area = match self.min_contour_area {
+            Some(area) => match grid_threshold(area.to_f64() * scale * scale) {
+                Some(area) => area,
+                None => return Err(FloatCurveOverlayOptionsError::MinContourAreaInvalid),
+            },
+            None => 0,
+        };
+        let min_area_perimeter_ratio = match self.min_area_perimeter_ratio {
+            Some(ratio) => match grid_threshold(ratio.to_f64() * scale) {
+                Some(ratio) => u32::try_from(ratio).unwrap_or(u32::MAX),
+                None => return Err(FloatCurveOverlayOptionsError::MinAreaPerimeterRatioInvalid),
+            },
+            None => 0,
+        };
+        let min_segment_length = match self.min_segment_length {
+            Some(length) => match grid_threshold(length.to_f64() * scale) {
+                Some(length) => u32::try_from(length).unwrap_or(u32::MAX),
+                None => return Err(FloatCurveOverlayOptionsError::MinSegmentLengthInvalid),
+            },
+            None => 0,
+        };
+
         Ok(CurveOverlayOptions {
             min_chord_length_power,
             angle_tolerance_power,
@@ -189,10 +306,31 @@ impl<F: FloatNumber> FloatCurveOverlayOptions<F> {
             refinement_subdivision_power: self.refinement_subdivision_power,
             refinement_angle_tolerance_power: self.refinement_angle_tolerance_power,
             max_refinement_iterations: self.max_refinement_iterations,
+            output_direction: self.output_direction,
+            canonical_output: self.canonical_output,
+            min_contour_area,
+            min_area_perimeter_ratio,
+            min_segment_length,
+            merge_collinear_lines: self.merge_collinear_lines,
+            ..CurveOverlayOptions::default()
         })
     }
 }
 
+/// Rounds a non-negative grid threshold up to the next integer, saturating
+/// at `u64::MAX`.
+fn grid_threshold(value: f64) -> Option<u64> {
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+    let truncated = value as u64;
+    Some(if (truncated as f64) < value {
+        truncated.saturating_add(1)
+    } else {
+        truncated
+    })
+}
+
 /// Boolean overlay for float curve shapes.
 ///
 /// Inputs are mapped to one automatically selected fixed-point grid. The
@@ -228,6 +366,7 @@ pub struct FloatCurveOverlay<P: FloatPointCompatible, I: CurveInt> {
     overlay: IntCurveOverlay<I>,
     options: FloatCurveOverlayOptions<P::Scalar>,
     conversion_report: FloatCurveOverlayConversionReport,
+    conversion_error: Option<CurveOverlayError>,
 }
 
 /// Per-operand float-to-integer conversion diagnostics for an overlay.
@@ -323,8 +462,23 @@ where
     {
         let capacity = resource_segment_count(subject) + clip.map_or(0, resource_segment_count);
         let mut overlay = IntCurveOverlay::with_capacity(capacity);
-        let subject_report = add_converted_resource(&mut overlay, subject, &adapter, true);
-        let clip_report = clip.map(|clip| add_converted_resource(&mut overlay, clip, &adapter, false));
+        let mut conversion_error = None;
+        let subject_report = add_converted_resource(
+            &mut overlay,
+            subject,
+            &adapter,
+            ShapeType::Subject,
+            &mut conversion_error,
+        );
+        let clip_report = clip.map(|clip| {
+            add_converted_resource(
+                &mut overlay,
+                clip,
+                &adapter,
+                ShapeType::Clip,
+                &mut conversion_error,
+            )
+        });
         let conversion_report = FloatCurveOverlayConversionReport {
             subject: subject_report,
             clip: clip_report,
@@ -334,6 +488,7 @@ where
             overlay,
             options: FloatCurveOverlayOptions::default(),
             conversion_report,
+            conversion_error,
         }
     }
 
@@ -378,6 +533,22 @@ where
         self.conversion_report
     }
 
+    /// Records the integer-converted operands, the adapter, and the
+    /// configuration together with the rules of the operation about to run.
+    ///
+    /// See [`IntCurveOverlay::capture_repro`]. An operand whose conversion
+    /// failed is not part of the record.
+    pub fn capture_repro(&self, overlay_rule: OverlayRule, fill_rule: FillRule) -> CurveOverlayRepro<I> {
+        let offset = self.adapter.offset();
+        let adapter = CurveReproAdapter::new(
+            [offset.x().to_f64(), offset.y().to_f64()],
+            self.adapter.dir_scale().to_f64(),
+        );
+        self.overlay
+            .capture_repro(overlay_rule, fill_rule)
+            .with_adapter(adapter)
+    }
+
     /// Resolves the subject operand using the selected fill rule.
     ///
     /// This is the direct completion method for overlays created with
@@ -390,16 +561,139 @@ where
 
     /// Performs the Boolean operation and returns float curve shapes.
     pub fn overlay(self, overlay_rule: OverlayRule, fill_rule: FillRule) -> alloc::vec::Vec<CurveShape<P>> {
+        assert!(
+            self.conversion_error.is_none(),
+            "float conversion produced invalid curve topology"
+        );
         let shapes = self.overlay.overlay(overlay_rule, fill_rule);
         convert_shapes_to_float(shapes, &self.adapter)
     }
+
+    /// Performs the Boolean operation like [`overlay`](Self::overlay) and
+    /// returns the nesting of the result contours.
+    ///
+    /// See [`IntCurveOverlay::overlay_with_hierarchy`]. Shape and contour
+    /// indices of the tree refer to the returned float shapes.
+    pub fn overlay_with_hierarchy(
+        self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> (alloc::vec::Vec<CurveShape<P>>, CurveContourTree) {
+        assert!(
+            self.conversion_error.is_none(),
+            "float conversion produced invalid curve topology"
+        );
+        let (shapes, tree) = self.overlay.overlay_with_hierarchy(overlay_rule, fill_rule);
+        (convert_shapes_to_float(shapes, &self.adapter), tree)
+    }
+
+    /// Performs the Boolean operation like [`overlay`](Self::overlay), but
+    /// returns an error naming the failing stage instead of panicking or
+    /// returning inconsistent shapes.
+    pub fn try_overlay(
+        self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<CurveShape<P>>, CurveOverlayError> {
+        if let Some(error) = self.conversion_error {
+            return Err(error);
+        }
+        let shapes = self.overlay.try_overlay(overlay_rule, fill_rule)?;
+        shapes
+            .into_iter()
+            .enumerate()
+            .map(|(index, shape)| {
+                try_convert_shape_to_float(shape, &self.adapter)
+                    .map_err(|error| CurveOverlayError::FloatOutput { shape: index, error })
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the subject and clip share any point.
+    ///
+    /// See [`IntCurveOverlay::intersects`]. The predicates classify the
+    /// planarized edges without reconstructing result curves. Like
+    /// [`overlay`](Self::overlay), they panic when an operand was rejected
+    /// during float conversion; the `try_` variants return that error
+    /// instead.
+    pub fn intersects(self, fill_rule: FillRule) -> bool {
+        self.into_int_overlay().intersects(fill_rule)
+    }
+
+    /// Returns `true` if the subject and clip share no point.
+    pub fn disjoint(self, fill_rule: FillRule) -> bool {
+        self.into_int_overlay().disjoint(fill_rule)
+    }
+
+    /// Returns `true` if the boundaries meet while the interiors do not overlap.
+    pub fn touches(self, fill_rule: FillRule) -> bool {
+        self.into_int_overlay().touches(fill_rule)
+    }
+
+    /// Returns `true` if every filled area of the subject is also filled by the clip.
+    pub fn within(self, fill_rule: FillRule) -> bool {
+        self.into_int_overlay().within(fill_rule)
+    }
+
+    /// Returns `true` if every filled area of the clip is also filled by the subject.
+    pub fn contains(self, fill_rule: FillRule) -> bool {
+        self.into_int_overlay().contains(fill_rule)
+    }
+
+    /// Same as [`intersects`](Self::intersects), but returns the conversion
+    /// error instead of panicking.
+    pub fn try_intersects(self, fill_rule: FillRule) -> Result<bool, CurveOverlayError> {
+        Ok(self.try_into_int_overlay()?.intersects(fill_rule))
+    }
+
+    /// Same as [`disjoint`](Self::disjoint), but returns the conversion
+    /// error instead of panicking.
+    pub fn try_disjoint(self, fill_rule: FillRule) -> Result<bool, CurveOverlayError> {
+        Ok(self.try_into_int_overlay()?.disjoint(fill_rule))
+    }
+
+    /// Same as [`touches`](Self::touches), but returns the conversion error
+    /// instead of panicking.
+    pub fn try_touches(self, fill_rule: FillRule) -> Result<bool, CurveOverlayError> {
+        Ok(self.try_into_int_overlay()?.touches(fill_rule))
+    }
+
+    /// Same as [`within`](Self::within), but returns the conversion error
+    /// instead of panicking.
+    pub fn try_within(self, fill_rule: FillRule) -> Result<bool, CurveOverlayError> {
+        Ok(self.try_into_int_overlay()?.within(fill_rule))
+    }
+
+    /// Same as [`contains`](Self::contains), but returns the conversion
+    /// error instead of panicking.
+    pub fn try_contains(self, fill_rule: FillRule) -> Result<bool, CurveOverlayError> {
+        Ok(self.try_into_int_overlay()?.contains(fill_rule))
+    }
+
+    fn into_int_overlay(self) -> IntCurveOverlay<I> {
+        assert!(
+            self.conversion_error.is_none(),
+            "float conversion produced invalid curve topology"
+        );
+        self.overlay
+    }
+
+    fn try_into_int_overlay(self) -> Result<IntCurveOverlay<I>, CurveOverlayError> {
+        match self.conversion_error {
+            Some(error) => Err(error),
+            None => Ok(self.overlay),
+        }
+    }
 }
 
+/// Converts and adds one operand, keeping the first rejected conversion in
+/// `error` so the overlay can report it when it is resolved.
 fn add_converted_resource<P, I, R>(
     overlay: &mut IntCurveOverlay<I>,
     source: &R,
     adapter: &FloatPointAdapter<P, I>,
-    is_subject: bool,
+    shape_type: ShapeType,
+    error: &mut Option<CurveOverlayError>,
 ) -> CurveConversionReport
 where
     P: FloatPointCompatible,
@@ -411,12 +705,12 @@ where
         return report;
     }
 
-    let result = if is_subject {
-        overlay.add_subject(shape)
-    } else {
-        overlay.add_clip(shape)
-    };
-    assert!(result.is_ok(), "float conversion produced invalid curve topology");
+    if let Err(input_error) = overlay.add_shape(shape, shape_type) {
+        error.get_or_insert(CurveOverlayError::Conversion {
+            shape_type,
+            error: input_error,
+        });
+    }
     report
 }
 
@@ -443,6 +737,31 @@ impl<P: FloatPointCompatible> CurveShape<P> {
     {
         FloatCurveOverlay::<P, I>::new(self, clip).overlay(overlay_rule, fill_rule)
     }
+
+    /// Performs a Boolean operation using the standard `i32` engine and
+    /// reports internal failures as [`CurveOverlayError`].
+    pub fn try_overlay(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<Self>, CurveOverlayError> {
+        FloatCurveOverlay::<P, i32>::new(self, clip).try_overlay(overlay_rule, fill_rule)
+    }
+
+    /// Performs a Boolean operation using an explicitly selected integer
+    /// engine and reports internal failures as [`CurveOverlayError`].
+    pub fn try_overlay_as<I>(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<Self>, CurveOverlayError>
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, clip).try_overlay(overlay_rule, fill_rule)
+    }
 }
 
 impl<P: FloatPointCompatible> CurvePath<P> {
@@ -468,6 +787,31 @@ impl<P: FloatPointCompatible> CurvePath<P> {
     {
         FloatCurveOverlay::<P, I>::new(self, clip).overlay(overlay_rule, fill_rule)
     }
+
+    /// Performs a Boolean operation using the standard `i32` engine and
+    /// reports internal failures as [`CurveOverlayError`].
+    pub fn try_overlay(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<CurveShape<P>>, CurveOverlayError> {
+        FloatCurveOverlay::<P, i32>::new(self, clip).try_overlay(overlay_rule, fill_rule)
+    }
+
+    /// Performs a Boolean operation using an explicitly selected integer
+    /// engine and reports internal failures as [`CurveOverlayError`].
+    pub fn try_overlay_as<I>(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<CurveShape<P>>, CurveOverlayError>
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, clip).try_overlay(overlay_rule, fill_rule)
+    }
 }
 
 /// Convenience Boolean operations for arbitrary float curve resources.
@@ -492,6 +836,26 @@ pub trait CurveResourceOverlayExt<P: FloatPointCompatible>: CurveResource<P> {
     ) -> alloc::vec::Vec<CurveShape<P>>
     where
         I: CurveInt;
+
+    /// Uses the standard `i32` engine and reports internal failures as
+    /// [`CurveOverlayError`].
+    fn try_overlay(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<CurveShape<P>>, CurveOverlayError>;
+
+    /// Uses the selected integer engine and reports internal failures as
+    /// [`CurveOverlayError`].
+    fn try_overlay_as<I>(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<CurveShape<P>>, CurveOverlayError>
+    where
+        I: CurveInt;
 }
 
 impl<P, R> CurveResourceOverlayExt<P> for R
@@ -521,6 +885,152 @@ where
     {
         FloatCurveOverlay::<P, I>::new(self, clip).overlay(overlay_rule, fill_rule)
     }
+
+    #[inline]
+    fn try_overlay(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<CurveShape<P>>, CurveOverlayError> {
+        FloatCurveOverlay::<P, i32>::new(self, clip).try_overlay(overlay_rule, fill_rule)
+    }
+
+    #[inline]
+    fn try_overlay_as<I>(
+        &self,
+        clip: &(impl CurveResource<P> + ?Sized),
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<alloc::vec::Vec<CurveShape<P>>, CurveOverlayError>
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, clip).try_overlay(overlay_rule, fill_rule)
+    }
+}
+
+/// Spatial predicates for arbitrary float curve resources.
+///
+/// The predicates share the grid selection and planarization of
+/// [`FloatCurveOverlay`], but answer yes or no without reconstructing result
+/// curves. `self` is the subject and `other` is the clip. They panic when a
+/// resource is rejected during conversion; build a [`FloatCurveOverlay`] and
+/// call its `try_` predicates to handle that error.
+pub trait CurveResourceRelateExt<P: FloatPointCompatible>: CurveResource<P> {
+    /// Returns `true` if both resources share any point, using the `i32` engine.
+    fn intersects(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool;
+
+    /// Same as [`intersects`](Self::intersects) with the selected integer engine.
+    fn intersects_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt;
+
+    /// Returns `true` if the resources share no point, using the `i32` engine.
+    fn disjoint(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool;
+
+    /// Same as [`disjoint`](Self::disjoint) with the selected integer engine.
+    fn disjoint_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt;
+
+    /// Returns `true` if the boundaries meet while the interiors do not
+    /// overlap, using the `i32` engine.
+    fn touches(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool;
+
+    /// Same as [`touches`](Self::touches) with the selected integer engine.
+    fn touches_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt;
+
+    /// Returns `true` if every filled area of `self` is also filled by
+    /// `other`, using the `i32` engine.
+    fn within(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool;
+
+    /// Same as [`within`](Self::within) with the selected integer engine.
+    fn within_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt;
+
+    /// Returns `true` if every filled area of `other` is also filled by
+    /// `self`, using the `i32` engine.
+    fn contains(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool;
+
+    /// Same as [`contains`](Self::contains) with the selected integer engine.
+    fn contains_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt;
+}
+
+impl<P, R> CurveResourceRelateExt<P> for R
+where
+    P: FloatPointCompatible,
+    R: CurveResource<P> + ?Sized,
+{
+    #[inline]
+    fn intersects(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool {
+        FloatCurveOverlay::<P, i32>::new(self, other).intersects(fill_rule)
+    }
+
+    #[inline]
+    fn intersects_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, other).intersects(fill_rule)
+    }
+
+    #[inline]
+    fn disjoint(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool {
+        FloatCurveOverlay::<P, i32>::new(self, other).disjoint(fill_rule)
+    }
+
+    #[inline]
+    fn disjoint_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, other).disjoint(fill_rule)
+    }
+
+    #[inline]
+    fn touches(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool {
+        FloatCurveOverlay::<P, i32>::new(self, other).touches(fill_rule)
+    }
+
+    #[inline]
+    fn touches_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, other).touches(fill_rule)
+    }
+
+    #[inline]
+    fn within(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool {
+        FloatCurveOverlay::<P, i32>::new(self, other).within(fill_rule)
+    }
+
+    #[inline]
+    fn within_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, other).within(fill_rule)
+    }
+
+    #[inline]
+    fn contains(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool {
+        FloatCurveOverlay::<P, i32>::new(self, other).contains(fill_rule)
+    }
+
+    #[inline]
+    fn contains_as<I>(&self, other: &(impl CurveResource<P> + ?Sized), fill_rule: FillRule) -> bool
+    where
+        I: CurveInt,
+    {
+        FloatCurveOverlay::<P, I>::new(self, other).contains(fill_rule)
+    }
 }
 
 fn combined_bounds<P, R0, R1>(subject: &R0, clip: &R1) -> FloatRect<P::Scalar>
@@ -548,6 +1058,7 @@ where
 mod tests {
     use super::*;
     use crate::float::arc::{Ellipse, EllipticArc};
+    use crate::int::CurveInputError;
     use crate::{CurveBuilder, FloatCurveSegment};
 
     fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> CurveShape<[f64; 2]> {
@@ -578,6 +1089,27 @@ mod tests {
         assert!((start[0] - 5.0).abs() < 1.0e-6 || (start[0] - 10.0).abs() < 1.0e-6);
     }
 
+    #[test]
+    fn try_overlay_matches_overlay() {
+        let subject = rectangle(0.0, 0.0, 10.0, 10.0);
+        let clip = rectangle(5.0, 2.0, 12.0, 8.0);
+
+        let expected = subject.overlay(&clip, OverlayRule::Union, FillRule::NonZero);
+        let result = FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+            .try_overlay(OverlayRule::Union, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result, expected);
+
+        let wide = subject
+            .try_overlay_as::<i64>(&clip, OverlayRule::Intersect, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(wide.len(), 1);
+        let path = subject.contours()[0]
+            .try_overlay(&clip, OverlayRule::Difference, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(path.len(), 1);
+    }
+
     #[test]
     fn rational_arcs_are_returned_in_float_coordinates() {
         let circle = EllipticArc {
@@ -657,6 +1189,12 @@ mod tests {
             refinement_subdivision_power: 2,
             refinement_angle_tolerance_power: 6,
             max_refinement_iterations: 1,
+            output_direction: ContourDirection::Clockwise,
+            canonical_output: true,
+            min_contour_area: Some(0.5),
+            min_area_perimeter_ratio: Some(0.01),
+            min_segment_length: Some(0.002),
+            merge_collinear_lines: true,
         };
 
         let overlay = FloatCurveOverlay::<_, i32>::try_with_scale(&subject, &clip, 1_024.0)
@@ -675,6 +1213,13 @@ mod tests {
                 refinement_subdivision_power: 2,
                 refinement_angle_tolerance_power: 6,
                 max_refinement_iterations: 1,
+                certified_intersections: false,
+                output_direction: ContourDirection::Clockwise,
+                canonical_output: true,
+                min_contour_area: 524_288,
+                min_area_perimeter_ratio: 11,
+                min_segment_length: 3,
+                merge_collinear_lines: true,
             }
         );
     }
@@ -717,6 +1262,29 @@ mod tests {
             Some(FloatCurveOverlayOptionsError::AngleToleranceOutOfRange)
         );
 
+        let error = FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+            .try_with_options(FloatCurveOverlayOptions::default().with_min_contour_area(-1.0))
+            .err();
+        assert_eq!(error, Some(FloatCurveOverlayOptionsError::MinContourAreaInvalid));
+
+        let error = FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+            .try_with_options(
+                FloatCurveOverlayOptions::default().with_min_area_perimeter_ratio(f64::INFINITY),
+            )
+            .err();
+        assert_eq!(
+            error,
+            Some(FloatCurveOverlayOptionsError::MinAreaPerimeterRatioInvalid)
+        );
+
+        let error = FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+            .try_with_options(FloatCurveOverlayOptions::default().with_min_segment_length(f64::NAN))
+            .err();
+        assert_eq!(
+            error,
+            Some(FloatCurveOverlayOptionsError::MinSegmentLengthInvalid)
+        );
+
         let error = FloatCurveOverlay::<_, i32>::new(&subject, &clip)
             .try_with_options(
                 FloatCurveOverlayOptions::default()
@@ -767,4 +1335,25 @@ mod tests {
         assert!(intersection.is_empty());
         assert_eq!(union.len(), 1);
     }
+
+    #[test]
+    fn try_predicates_report_conversion_errors() {
+        let subject = rectangle(0.0, 0.0, 10.0, 10.0);
+        let clip = rectangle(5.0, 2.0, 12.0, 8.0);
+        let overlay = || FloatCurveOverlay::<_, i32>::new(&subject, &clip);
+
+        assert_eq!(overlay().try_intersects(FillRule::NonZero), Ok(true));
+        assert_eq!(overlay().try_disjoint(FillRule::NonZero), Ok(false));
+        assert_eq!(overlay().try_touches(FillRule::NonZero), Ok(false));
+        assert_eq!(overlay().try_within(FillRule::NonZero), Ok(false));
+        assert_eq!(overlay().try_contains(FillRule::NonZero), Ok(false));
+
+        let error = CurveOverlayError::Conversion {
+            shape_type: ShapeType::Clip,
+            error: CurveInputError::EmptyShape,
+        };
+        let mut rejected = overlay();
+        rejected.conversion_error = Some(error);
+        assert_eq!(rejected.try_intersects(FillRule::NonZero), Err(error));
+    }
 }
@@ -0,0 +1,329 @@
+use crate::float::curve::clip::clip_path;
+use crate::float::curve::converter::{
+    CurveConversionReport, adapter_contains_bounds, convert_resource, convert_shapes_to_float,
+};
+use crate::float::curve::path::{CurvePath, finite_rect};
+use crate::float::curve::shape::CurveShape;
+use crate::float::overlay::{FloatCurveOverlayOptions, FloatCurveOverlayOptionsError};
+use crate::float::resource::{CurveResource, resource_bounds};
+use crate::int::CURVE_COORDINATE_SAFETY_BITS;
+use crate::int::{
+    CurveInt, CurveShape as IntCurveShape, IntCurveOverlay, PreparedCurveSubject as IntPreparedCurveSubject,
+};
+use crate::{CurveConversionError, FillRule, OverlayRule, Solver};
+use alloc::vec::Vec;
+use i_overlay::i_float::adapter::FloatPointAdapter;
+use i_overlay::i_float::float::compatible::FloatPointCompatible;
+use i_overlay::i_float::float::rect::FloatRect;
+
+/// Float subject prepared once for overlays against many clips.
+///
+/// The subject is converted to a fixed grid, then canonicalized,
+/// approximated, and planarized against itself a single time. Each
+/// [`overlay`](Self::overlay) call converts and approximates only its clip and
+/// re-planarizes only the subject edges whose bounds touch that clip, instead
+/// of repeating the full pipeline as [`FloatCurveOverlay::new`] does.
+///
+/// The grid covers the subject bounds, or the bounds passed to
+/// [`with_bounds`](Self::with_bounds). Clip geometry outside the grid
+/// is cut away for [`OverlayRule::Intersect`], [`OverlayRule::Difference`],
+/// and [`OverlayRule::Subject`], where it cannot affect the result; other
+/// rules reject such a clip.
+///
+/// ```
+/// use i_curve::{CurveBuilder, FillRule, FloatPreparedCurveSubject, OverlayRule};
+///
+/// let site = CurveBuilder::new()
+///     .move_to([0.0_f64, 0.0])?
+///     .line_to([100.0, 0.0])?
+///     .quad_to([120.0, 50.0], [100.0, 100.0])?
+///     .line_to([0.0, 100.0])?
+///     .close_contour()?
+///     .build()?;
+/// let prepared = FloatPreparedCurveSubject::<_, i32>::new(&site);
+///
+/// for x in [10.0, 50.0, 90.0] {
+///     let clip = CurveBuilder::new()
+///         .move_to([x, 40.0])?
+///         .line_to([x + 40.0, 40.0])?
+///         .line_to([x + 40.0, 60.0])?
+///         .line_to([x, 60.0])?
+///         .close_contour()?
+///         .build()?;
+///     let result = prepared
+///         .overlay(&clip, OverlayRule::Intersect, FillRule::NonZero)
+///         .expect("intersection ignores clip geometry outside the site");
+///     assert_eq!(result.len(), 1);
+/// }
+/// # Ok::<(), i_curve::CurveBuildError>(())
+/// ```
+///
+/// [`FloatCurveOverlay::new`]: crate::float::FloatCurveOverlay::new
+pub struct FloatPreparedCurveSubject<P: FloatPointCompatible, I: CurveInt> {
+    adapter: FloatPointAdapter<P, I>,
+    subject: IntCurveShape<I>,
+    prepared: IntPreparedCurveSubject<I>,
+    options: FloatCurveOverlayOptions<P::Scalar>,
+    conversion_report: CurveConversionReport,
+}
+
+impl<P, I> FloatPreparedCurveSubject<P, I>
+where
+    P: FloatPointCompatible,
+    I: CurveInt,
+{
+    const COORDINATE_BITS: u32 = I::BITS - CURVE_COORDINATE_SAFETY_BITS;
+
+    /// Prepares a subject on a grid selected from its bounds.
+    pub fn new<R>(subject: &R) -> Self
+    where
+        R: CurveResource<P> + ?Sized,
+    {
+        let bounds = resource_bounds(subject).unwrap_or_else(FloatRect::zero);
+        let adapter = FloatPointAdapter::with_coordinate_bits(bounds, Self::COORDINATE_BITS);
+        Self::with_adapter(subject, adapter)
+    }
+
+    /// Prepares a subject on a grid that also covers `bounds`.
+    ///
+    /// Reserve the area where clips are expected when they may extend past the
+    /// subject and the overlay rule keeps clip geometry, such as
+    /// [`OverlayRule::Union`]. `bounds` must be finite.
+    pub fn with_bounds<R>(subject: &R, bounds: FloatRect<P::Scalar>) -> Self
+    where
+        R: CurveResource<P> + ?Sized,
+    {
+        assert!(finite_rect(&bounds), "prepared subject bounds must be finite");
+        let bounds = match resource_bounds(subject) {
+            Some(subject) => FloatRect::with_rects(subject, bounds),
+            None => bounds,
+        };
+        let adapter = FloatPointAdapter::with_coordinate_bits(bounds, Self::COORDINATE_BITS);
+        Self::with_adapter(subject, adapter)
+    }
+
+    fn with_adapter<R>(subject: &R, adapter: FloatPointAdapter<P, I>) -> Self
+    where
+        R: CurveResource<P> + ?Sized,
+    {
+        let (subject, conversion_report) = convert_resource(subject, &adapter);
+        let prepared = Self::prepare(&subject, IntCurveOverlay::new());
+        Self {
+            adapter,
+            subject,
+            prepared,
+            options: FloatCurveOverlayOptions::default(),
+            conversion_report,
+        }
+    }
+
+    fn prepare(subject: &IntCurveShape<I>, mut overlay: IntCurveOverlay<I>) -> IntPreparedCurveSubject<I> {
+        if !subject.contours.is_empty() {
+            let result = overlay.add_subject(subject.clone());
+            assert!(result.is_ok(), "float conversion produced invalid curve topology");
+        }
+        overlay.into_prepared_subject()
+    }
+
+    /// Sets the topology solver configuration and prepares the subject again.
+    #[must_use]
+    pub fn with_solver(mut self, solver: Solver) -> Self {
+        let overlay = IntCurveOverlay::new()
+            .with_solver(solver)
+            .try_with_options(self.prepared.options())
+            .expect("prepared options are valid");
+        self.prepared = Self::prepare(&self.subject, overlay);
+        self
+    }
+
+    /// Sets curve approximation options expressed in float input coordinates
+    /// and prepares the subject again.
+    pub fn try_with_options(
+        mut self,
+        options: FloatCurveOverlayOptions<P::Scalar>,
+    ) -> Result<Self, FloatCurveOverlayOptionsError> {
+        let overlay = IntCurveOverlay::new()
+            .with_solver(self.prepared.solver())
+            .try_with_options(options.to_int(&self.adapter)?)?;
+        self.prepared = Self::prepare(&self.subject, overlay);
+        self.options = options;
+        Ok(self)
+    }
+
+    /// Returns the topology solver configuration.
+    #[inline]
+    pub fn solver(&self) -> Solver {
+        self.prepared.solver()
+    }
+
+    /// Returns the curve approximation options in float input coordinates.
+    #[inline]
+    pub fn options(&self) -> FloatCurveOverlayOptions<P::Scalar> {
+        self.options
+    }
+
+    /// Returns the effective float-to-integer conversion scale.
+    #[inline]
+    pub fn scale(&self) -> P::Scalar {
+        self.adapter.dir_scale()
+    }
+
+    /// Returns the float bounds covered by the prepared grid.
+    #[inline]
+    pub fn bounds(&self) -> FloatRect<P::Scalar> {
+        *self.adapter.rect()
+    }
+
+    /// Returns topology changes observed while converting the subject.
+    #[inline]
+    pub fn conversion_report(&self) -> CurveConversionReport {
+        self.conversion_report
+    }
+
+    /// Performs the Boolean operation against `clip` and returns float curve
+    /// shapes.
+    ///
+    /// Returns [`CurveConversionError::ResourceOutsideAdapter`] when the clip
+    /// extends past [`bounds`](Self::bounds) and the overlay rule keeps clip
+    /// geometry there, or when a clip contour cannot be cut to the bounds
+    /// without becoming invalid.
+    pub fn overlay<R>(
+        &self,
+        clip: &R,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<Vec<CurveShape<P>>, CurveConversionError>
+    where
+        R: CurveResource<P> + ?Sized,
+    {
+        let rect = *self.adapter.rect();
+        let is_inside =
+            resource_bounds(clip).is_none_or(|bounds| adapter_contains_bounds(&self.adapter, bounds));
+        let (shape, _) = if is_inside {
+            convert_resource(clip, &self.adapter)
+        } else if matches!(
+            overlay_rule,
+            OverlayRule::Intersect | OverlayRule::Difference | OverlayRule::Subject
+        ) {
+            // These results lie inside the subject, so only the clip
+            // winding inside the grid matters.
+            let mut paths: Vec<CurvePath<P>> = Vec::new();
+            for path in clip.iter_paths() {
+                match clip_path(path, &rect) {
+                    Ok(Some(path)) => paths.push(path),
+                    Ok(None) => {}
+                    // Leaving out a contour would change the clip winding.
+                    Err(_) => return Err(CurveConversionError::ResourceOutsideAdapter),
+                }
+            }
+            convert_resource(&paths, &self.adapter)
+        } else {
+            return Err(CurveConversionError::ResourceOutsideAdapter);
+        };
+
+        let shapes = if shape.contours.is_empty() {
+            self.prepared.resolve(overlay_rule, fill_rule)
+        } else {
+            let result = self.prepared.overlay(shape, overlay_rule, fill_rule);
+            assert!(result.is_ok(), "float conversion produced invalid curve topology");
+            result.unwrap_or_default()
+        };
+        Ok(convert_shapes_to_float(shapes, &self.adapter))
+    }
+
+    /// Resolves the subject alone using the selected fill rule.
+    pub fn resolve_subject(&self, fill_rule: FillRule) -> Vec<CurveShape<P>> {
+        let shapes = self.prepared.resolve(OverlayRule::Subject, fill_rule);
+        convert_shapes_to_float(shapes, &self.adapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurveBuilder;
+    use crate::float::FloatCurveOverlay;
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> CurveShape<[f64; 2]> {
+        CurveBuilder::new()
+            .move_to([x0, y0])
+            .unwrap()
+            .line_to([x1, y0])
+            .unwrap()
+            .quad_to([x1 + 2.0, (y0 + y1) * 0.5], [x1, y1])
+            .unwrap()
+            .line_to([x0, y1])
+            .unwrap()
+            .close_contour()
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn matches_overlay_on_the_same_grid() {
+        let subject = rectangle(0.0, 0.0, 100.0, 100.0);
+        let prepared = FloatPreparedCurveSubject::<_, i32>::new(&subject);
+
+        for clip in [
+            rectangle(10.0, 10.0, 30.0, 40.0),
+            rectangle(80.0, 20.0, 95.0, 60.0),
+        ] {
+            for rule in [
+                OverlayRule::Intersect,
+                OverlayRule::Difference,
+                OverlayRule::Union,
+            ] {
+                let expected = FloatCurveOverlay::<_, i32>::try_with_scale(&subject, &clip, prepared.scale())
+                    .unwrap()
+                    .overlay(rule, FillRule::NonZero);
+                let actual = prepared.overlay(&clip, rule, FillRule::NonZero).unwrap();
+                assert_eq!(actual, expected, "{rule:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn clip_outside_the_grid_is_cut_only_when_it_cannot_matter() {
+        let subject = rectangle(0.0, 0.0, 10.0, 10.0);
+        let clip = rectangle(5.0, 2.0, 20.0, 8.0);
+        let prepared = FloatPreparedCurveSubject::<_, i32>::new(&subject);
+
+        let intersection = prepared
+            .overlay(&clip, OverlayRule::Intersect, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(intersection.len(), 1);
+        let bounds = intersection[0].contours()[0].bounds();
+        assert!((bounds.min_x - 5.0).abs() < 1.0e-6 && bounds.max_x <= 12.0 + 1.0e-6);
+        assert_eq!(
+            prepared.overlay(&clip, OverlayRule::Union, FillRule::NonZero),
+            Err(CurveConversionError::ResourceOutsideAdapter)
+        );
+
+        let widened = FloatPreparedCurveSubject::<_, i32>::with_bounds(&subject, clip.contours()[0].bounds());
+        assert_eq!(
+            widened
+                .overlay(&clip, OverlayRule::Union, FillRule::NonZero)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn settings_prepare_the_subject_again() {
+        let subject = rectangle(0.0, 0.0, 10.0, 10.0);
+        let options = FloatCurveOverlayOptions::default().with_angle_tolerance(0.01);
+        let prepared = FloatPreparedCurveSubject::<_, i32>::new(&subject)
+            .try_with_options(options)
+            .unwrap();
+
+        assert_eq!(prepared.options(), options);
+        assert_eq!(prepared.resolve_subject(FillRule::NonZero).len(), 1);
+        assert!(
+            FloatPreparedCurveSubject::<_, i32>::new(&subject)
+                .try_with_options(FloatCurveOverlayOptions::default().with_angle_tolerance(2.0))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Random curve input for fuzzing and property-based tests.
+//!
+//! This module is available with the `arbitrary` or `proptest` feature. Both
+//! features drive the same generator, configured by [`CurveGeneratorOptions`]:
+//!
+//! - `arbitrary` implements [`arbitrary::Arbitrary`] for integer and float
+//!   curve shapes and rational arcs, and adds `arbitrary_*` methods that use
+//!   explicit options.
+//! - `proptest` implements [`proptest::arbitrary::Arbitrary`] with
+//!   [`CurveGeneratorOptions`] as parameters, and adds `*_strategy` methods.
+//!
+//! Generated shapes always satisfy the invariants checked when they are added
+//! to an overlay: contours are non-empty and exactly closed, and rational arcs
+//! are connected and valid. Every coordinate stays within the safe range of
+//! the requested integer engine. Beyond random lines, Bézier curves, and arcs,
+//! the options control how often exact degenerate configurations appear:
+//! cubic cusps and loops, curves tangent to another contour, and contours
+//! that repeat a segment of an earlier contour.
+//!
+//! Generation consumes a stream of bounded choices in which zero always
+//! selects the simplest alternative. Exhausted input therefore produces small
+//! regular geometry, and proptest shrinks failing shapes toward it.
+
+mod plan;
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "arbitrary")]
+mod unstructured;
+
+/// Controls the size and degeneracies of generated curve input.
+///
+/// Construct this non-exhaustive configuration from [`Default`] and override
+/// only the values your fuzz target needs. Percentages above `100` behave as
+/// `100`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurveGeneratorOptions {
+    /// Maximum number of contours in one shape. The default is `3`.
+    pub max_contours: usize,
+    /// Maximum number of segments in one regular contour. At least two
+    /// segments are always generated. The default is `8`.
+    pub max_segments: usize,
+    /// Coordinates lie within `±2^(coordinate_bits - 1)`. The value is
+    /// clamped to the safe range of the integer engine, or to the mantissa of
+    /// the float type, and to at most `52`. The default is `20`.
+    pub coordinate_bits: u32,
+    /// Float coordinates are integer grid values divided by
+    /// `2^float_fraction_bits`, so exact configurations stay exact. The value
+    /// is clamped to at most `16`. The default is `4`.
+    pub float_fraction_bits: u32,
+    /// Relative weight of line segments. The default is `25`.
+    pub line_weight: u32,
+    /// Relative weight of quadratic Bézier segments. The default is `35`.
+    pub quad_weight: u32,
+    /// Relative weight of cubic Bézier segments. The default is `30`.
+    pub cubic_weight: u32,
+    /// Relative weight of quarter elliptic arcs. The default is `10`.
+    pub arc_weight: u32,
+    /// Percentage of vertices placed within four grid steps of the previous
+    /// vertex. The default is `20`.
+    pub near_percent: u8,
+    /// Percentage of cubic segments generated with an exact cusp. The default
+    /// is `5`.
+    pub cusp_percent: u8,
+    /// Percentage of cubic segments generated with a self-intersecting loop.
+    /// The default is `5`.
+    pub loop_percent: u8,
+    /// Percentage of contour slots filled with a pair of contours whose
+    /// curves touch tangentially at one grid point. The default is `5`.
+    pub tangency_percent: u8,
+    /// Percentage of contours, after the first, that start by repeating a
+    /// segment of an earlier contour, in either direction. The default is
+    /// `10`.
+    pub coincidence_percent: u8,
+}
+
+impl Default for CurveGeneratorOptions {
+    fn default() -> Self {
+        Self {
+            max_contours: 3,
+            max_segments: 8,
+            coordinate_bits: 20,
+            float_fraction_bits: 4,
+            line_weight: 25,
+            quad_weight: 35,
+            cubic_weight: 30,
+            arc_weight: 10,
+            near_percent: 20,
+            cusp_percent: 5,
+            loop_percent: 5,
+            tangency_percent: 5,
+            coincidence_percent: 10,
+        }
+    }
+}
+
+impl CurveGeneratorOptions {
+    /// Returns options with the given maximum number of contours per shape.
+    #[inline]
+    pub const fn with_max_contours(mut self, count: usize) -> Self {
+        self.max_contours = count;
+        self
+    }
+
+    /// Returns options with the given maximum number of segments per contour.
+    #[inline]
+    pub const fn with_max_segments(mut self, count: usize) -> Self {
+        self.max_segments = count;
+        self
+    }
+
+    /// Returns options with the given coordinate range exponent.
+    #[inline]
+    pub const fn with_coordinate_bits(mut self, bits: u32) -> Self {
+        self.coordinate_bits = bits;
+        self
+    }
+
+    /// Returns options with the given number of float fraction bits.
+    #[inline]
+    pub const fn with_float_fraction_bits(mut self, bits: u32) -> Self {
+        self.float_fraction_bits = bits;
+        self
+    }
+
+    /// Returns options with the given relative segment kind weights.
+    ///
+    /// When all weights are zero, only lines are generated.
+    #[inline]
+    pub const fn with_segment_weights(mut self, line: u32, quad: u32, cubic: u32, arc: u32) -> Self {
+        self.line_weight = line;
+        self.quad_weight = quad;
+        self.cubic_weight = cubic;
+        self.arc_weight = arc;
+        self
+    }
+
+    /// Returns options with the given percentage of nearby vertices.
+    #[inline]
+    pub const fn with_near_percent(mut self, percent: u8) -> Self {
+        self.near_percent = percent;
+        self
+    }
+
+    /// Returns options with the given percentage of cubic cusps.
+    #[inline]
+    pub const fn with_cusp_percent(mut self, percent: u8) -> Self {
+        self.cusp_percent = percent;
+        self
+    }
+
+    /// Returns options with the given percentage of cubic loops.
+    #[inline]
+    pub const fn with_loop_percent(mut self, percent: u8) -> Self {
+        self.loop_percent = percent;
+        self
+    }
+
+    /// Returns options with the given percentage of tangent contour pairs.
+    #[inline]
+    pub const fn with_tangency_percent(mut self, percent: u8) -> Self {
+        self.tangency_percent = percent;
+        self
+    }
+
+    /// Returns options with the given percentage of coincident contours.
+    #[inline]
+    pub const fn with_coincidence_percent(mut self, percent: u8) -> Self {
+        self.coincidence_percent = percent;
+        self
+    }
+
+    /// Returns options that generate no nearby vertices, cusps, loops,
+    /// tangencies, or coincident segments on purpose.
+    #[inline]
+    pub const fn without_degeneracies(self) -> Self {
+        self.with_near_percent(0)
+            .with_cusp_percent(0)
+            .with_loop_percent(0)
+            .with_tangency_percent(0)
+            .with_coincidence_percent(0)
+    }
+}
@@ -0,0 +1,930 @@
+use crate::float::arc::{Ellipse, RationalArc as FloatRationalArc};
+use crate::float::{
+    CurvePath as FloatCurvePath, CurveSegment as FloatCurveSegment, CurveShape as FloatCurveShape,
+};
+use crate::generate::CurveGeneratorOptions;
+use crate::int::arc::{ArcDirection, ArcPhase, ArcVector, EllipseFrame, RationalArc};
+use crate::int::{CURVE_COORDINATE_SAFETY_BITS, CurveInt, CurvePath, CurveSegment, CurveShape};
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2};
+use i_overlay::i_float::float::compatible::FloatPointCompatible;
+use i_overlay::i_float::float::number::FloatNumber;
+use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Largest coordinate exponent kept exact through the `f64` conversions.
+const MAX_COORDINATE_BITS: u32 = 52;
+/// Smallest coordinate exponent; the control-point offsets need a few steps.
+const MIN_COORDINATE_BITS: u32 = 5;
+const MAX_FLOAT_FRACTION_BITS: u32 = 16;
+/// Offset of a nearby vertex, in grid steps.
+const NEAR_DISTANCE: i64 = 4;
+/// Offset of a control point from its chord midpoint, in grid steps.
+const MIDPOINT_DISTANCE: i64 = 8;
+
+/// Quarter phases on the unit circle, in counter-clockwise order.
+const QUARTERS: [(i64, i64); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// Source of bounded choices that drives the generator.
+///
+/// An exhausted source returns zero, which always selects the simplest
+/// alternative.
+pub(super) trait ChoiceSource {
+    /// Returns a value in `0..count`; `count` is non-zero.
+    fn choose(&mut self, count: u64) -> u64;
+}
+
+/// Quarter of an axis-aligned ellipse, stored on the generator grid.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PlanArc {
+    center: IntPoint<i64>,
+    radius: IntPoint<i64>,
+    start: usize,
+    counter_clockwise: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum PlanSegment {
+    Line {
+        to: IntPoint<i64>,
+    },
+    Quad {
+        ctrl: IntPoint<i64>,
+        to: IntPoint<i64>,
+    },
+    Cubic {
+        ctrl0: IntPoint<i64>,
+        ctrl1: IntPoint<i64>,
+        to: IntPoint<i64>,
+    },
+    Arc(PlanArc),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct PlanContour {
+    start: IntPoint<i64>,
+    segments: Vec<PlanSegment>,
+}
+
+impl PlanArc {
+    #[inline]
+    fn end(&self) -> usize {
+        if self.counter_clockwise {
+            (self.start + 1) % 4
+        } else {
+            (self.start + 3) % 4
+        }
+    }
+
+    #[inline]
+    fn point(&self, quarter: (i64, i64)) -> IntPoint<i64> {
+        IntPoint::new(
+            self.center.x + quarter.0 * self.radius.x,
+            self.center.y + quarter.1 * self.radius.y,
+        )
+    }
+
+    #[inline]
+    fn start_point(&self) -> IntPoint<i64> {
+        self.point(QUARTERS[self.start])
+    }
+
+    #[inline]
+    fn end_point(&self) -> IntPoint<i64> {
+        self.point(QUARTERS[self.end()])
+    }
+
+    /// Returns the corner of the quarter's bounding box opposite the center.
+    #[inline]
+    fn ctrl_point(&self) -> IntPoint<i64> {
+        let (start, end) = (QUARTERS[self.start], QUARTERS[self.end()]);
+        self.point((start.0 + end.0, start.1 + end.1))
+    }
+
+    #[inline]
+    fn reversed(self) -> Self {
+        Self {
+            start: self.end(),
+            counter_clockwise: !self.counter_clockwise,
+            ..self
+        }
+    }
+}
+
+impl PlanSegment {
+    #[inline]
+    fn end_point(&self) -> IntPoint<i64> {
+        match self {
+            Self::Line { to } | Self::Quad { to, .. } | Self::Cubic { to, .. } => *to,
+            Self::Arc(arc) => arc.end_point(),
+        }
+    }
+
+    /// Returns the same geometry traversed from its end back to `from`.
+    #[inline]
+    fn reversed(self, from: IntPoint<i64>) -> Self {
+        match self {
+            Self::Line { .. } => Self::Line { to: from },
+            Self::Quad { ctrl, .. } => Self::Quad { ctrl, to: from },
+            Self::Cubic { ctrl0, ctrl1, .. } => Self::Cubic {
+                ctrl0: ctrl1,
+                ctrl1: ctrl0,
+                to: from,
+            },
+            Self::Arc(arc) => Self::Arc(arc.reversed()),
+        }
+    }
+}
+
+/// Draws contours on an integer grid from a [`ChoiceSource`].
+///
+/// Vertices stay within `±half`; control points, arc corners, and the
+/// points of exact degenerate configurations stay within `±2 * half`.
+pub(super) struct PlanGenerator<'s, S: ChoiceSource> {
+    source: &'s mut S,
+    options: CurveGeneratorOptions,
+    half: i64,
+}
+
+impl<'s, S: ChoiceSource> PlanGenerator<'s, S> {
+    pub(super) fn for_int<I: CurveInt>(source: &'s mut S, options: CurveGeneratorOptions) -> Self {
+        let engine_bits = I::BITS - CURVE_COORDINATE_SAFETY_BITS;
+        Self::with_bits(source, options, engine_bits)
+    }
+
+    pub(super) fn for_float<P: FloatPointCompatible>(
+        source: &'s mut S,
+        options: CurveGeneratorOptions,
+    ) -> Self {
+        let mantissa_bits = if size_of::<P::Scalar>() <= size_of::<f32>() {
+            f32::MANTISSA_DIGITS
+        } else {
+            f64::MANTISSA_DIGITS
+        };
+        let fraction_bits = float_fraction_bits(&options);
+        Self::with_bits(source, options, mantissa_bits - fraction_bits)
+    }
+
+    fn with_bits(source: &'s mut S, options: CurveGeneratorOptions, max_bits: u32) -> Self {
+        let max_bits = max_bits.min(MAX_COORDINATE_BITS);
+        let bits = options.coordinate_bits.clamp(MIN_COORDINATE_BITS, max_bits);
+        Self {
+            source,
+            options,
+            half: 1 << (bits - 2),
+        }
+    }
+
+    pub(super) fn shape(&mut self) -> Vec<PlanContour> {
+        let count = 1 + self.below(self.options.max_contours.max(1) as u64) as usize;
+        let mut contours = Vec::with_capacity(count + 1);
+        while contours.len() < count {
+            if count - contours.len() >= 2 && self.percent(self.options.tangency_percent) {
+                self.push_tangent_pair(&mut contours);
+            } else if !contours.is_empty() && self.percent(self.options.coincidence_percent) {
+                let contour = self.coincident_contour(&contours);
+                contours.push(contour);
+            } else {
+                let contour = self.contour();
+                contours.push(contour);
+            }
+        }
+        contours
+    }
+
+    pub(super) fn arc(&mut self) -> PlanArc {
+        let from = self.anchor();
+        self.arc_from(from)
+    }
+
+    fn contour(&mut self) -> PlanContour {
+        let count = 2 + self.below(self.options.max_segments.max(2) as u64 - 1) as usize;
+        let start = self.anchor();
+        let mut current = start;
+        let mut segments = Vec::with_capacity(count);
+        for index in 0..count {
+            let closing = index + 1 == count;
+            let segment = self.segment(current, closing.then_some(start));
+            current = segment.end_point();
+            segments.push(segment);
+        }
+        PlanContour { start, segments }
+    }
+
+    /// Starts a contour with a segment of an earlier contour, in either
+    /// direction, so both contours share exactly the same geometry.
+    fn coincident_contour(&mut self, contours: &[PlanContour]) -> PlanContour {
+        let source = &contours[self.below(contours.len() as u64) as usize];
+        let index = self.below(source.segments.len() as u64) as usize;
+        let from = index
+            .checked_sub(1)
+            .map_or(source.start, |previous| source.segments[previous].end_point());
+        let segment = source.segments[index];
+        let (start, shared) = if self.below(2) == 0 {
+            (from, segment)
+        } else {
+            (segment.end_point(), segment.reversed(from))
+        };
+
+        let extra = self.below(3) as usize;
+        let mut segments = Vec::with_capacity(extra + 2);
+        segments.push(shared);
+        let mut current = shared.end_point();
+        for index in 0..=extra {
+            let closing = index == extra;
+            let segment = self.segment(current, closing.then_some(start));
+            current = segment.end_point();
+            segments.push(segment);
+        }
+        PlanContour { start, segments }
+    }
+
+    /// Adds a lens whose quadratic apex touches either a triangle edge or a
+    /// mirrored lens at one grid point with a common tangent.
+    ///
+    /// The quadratic `c - u, c + 2v, c + u` with perpendicular `u` and `v`
+    /// passes through `c + v` with tangent `u`.
+    fn push_tangent_pair(&mut self, contours: &mut Vec<PlanContour>) {
+        let center = self.anchor();
+        let mut u = self.offset();
+        if u == IntPoint::new(0, 0) {
+            u = IntPoint::new(1, 0);
+        }
+        let v = if self.below(2) == 0 {
+            IntPoint::new(-u.y, u.x)
+        } else {
+            IntPoint::new(u.y, -u.x)
+        };
+        let at = |a: i64, b: i64| IntPoint::new(center.x + a * u.x + b * v.x, center.y + a * u.y + b * v.y);
+
+        contours.push(PlanContour {
+            start: at(-1, 0),
+            segments: alloc::vec![
+                PlanSegment::Quad {
+                    ctrl: at(0, 2),
+                    to: at(1, 0),
+                },
+                PlanSegment::Line { to: at(-1, 0) },
+            ],
+        });
+
+        let partner = if self.below(2) == 0 {
+            PlanContour {
+                start: at(-1, 1),
+                segments: alloc::vec![
+                    PlanSegment::Line { to: at(1, 1) },
+                    PlanSegment::Line { to: at(0, 2) },
+                    PlanSegment::Line { to: at(-1, 1) },
+                ],
+            }
+        } else {
+            PlanContour {
+                start: at(-1, 2),
+                segments: alloc::vec![
+                    PlanSegment::Quad {
+                        ctrl: at(0, 0),
+                        to: at(1, 2),
+                    },
+                    PlanSegment::Line { to: at(-1, 2) },
+                ],
+            }
+        };
+        contours.push(partner);
+    }
+
+    /// Generates one segment from `from`, ending at `to` when it is given.
+    fn segment(&mut self, from: IntPoint<i64>, to: Option<IntPoint<i64>>) -> PlanSegment {
+        let options = self.options;
+        let weights = [
+            options.line_weight as u64,
+            options.quad_weight as u64,
+            options.cubic_weight as u64,
+            options.arc_weight as u64,
+        ];
+        let total: u64 = weights.iter().sum();
+        let mut pick = if total == 0 { 0 } else { self.below(total) };
+        let mut kind = 0;
+        while kind + 1 < weights.len() && pick >= weights[kind] {
+            pick -= weights[kind];
+            kind += 1;
+        }
+
+        match kind {
+            1 => {
+                let to = self.vertex(from, to);
+                PlanSegment::Quad {
+                    ctrl: self.control(from, to),
+                    to,
+                }
+            }
+            2 => self.cubic(from, to),
+            // An arc's endpoint is fixed by its ellipse and cannot close a contour.
+            3 if to.is_none() => PlanSegment::Arc(self.arc_from(from)),
+            _ => PlanSegment::Line {
+                to: self.vertex(from, to),
+            },
+        }
+    }
+
+    /// Generates a regular cubic or, on request, one with an exact cusp or
+    /// loop.
+    ///
+    /// With chord `d` and any offset `n`, the cubic `0, d + n, n, d` has a
+    /// zero derivative at `t = 1/2`. Pulling the controls further apart,
+    /// `0, 2d + n, n - d, d`, crosses the control polygon and forms a loop.
+    fn cubic(&mut self, from: IntPoint<i64>, to: Option<IntPoint<i64>>) -> PlanSegment {
+        if self.percent(self.options.cusp_percent) {
+            let to = self.vertex(from, to);
+            let normal = self.offset();
+            return PlanSegment::Cubic {
+                ctrl0: add(to, normal),
+                ctrl1: add(from, normal),
+                to,
+            };
+        }
+
+        if self.percent(self.options.loop_percent) {
+            let to = to.unwrap_or_else(|| {
+                let distance = self.limited_magnitude();
+                self.step(from, distance)
+            });
+            let chord = IntPoint::new(to.x - from.x, to.y - from.y);
+            let normal = self.offset();
+            let limit = self.half - self.half / 4;
+            if chord.x.abs() <= limit && chord.y.abs() <= limit {
+                return PlanSegment::Cubic {
+                    ctrl0: add(add(to, chord), normal),
+                    ctrl1: add(IntPoint::new(from.x - chord.x, from.y - chord.y), normal),
+                    to,
+                };
+            }
+            return PlanSegment::Cubic {
+                ctrl0: add(to, normal),
+                ctrl1: add(from, normal),
+                to,
+            };
+        }
+
+        let to = self.vertex(from, to);
+        PlanSegment::Cubic {
+            ctrl0: self.control(from, to),
+            ctrl1: self.control(from, to),
+            to,
+        }
+    }
+
+    /// Generates a quarter arc starting at `from` whose end stays within the
+    /// vertex range.
+    fn arc_from(&mut self, from: IntPoint<i64>) -> PlanArc {
+        let magnitude = self.limited_magnitude();
+        let radius = IntPoint::new(1 + self.below(magnitude as u64) as i64, {
+            if self.below(2) == 0 {
+                1 + self.below(magnitude as u64) as i64
+            } else {
+                0
+            }
+        });
+        // A zero radius requests a circle.
+        let radius = if radius.y == 0 {
+            IntPoint::new(radius.x, radius.x)
+        } else {
+            radius
+        };
+
+        let mut sign_x = if self.below(2) == 0 { 1 } else { -1 };
+        let mut sign_y = if self.below(2) == 0 { 1 } else { -1 };
+        if (from.x + sign_x * radius.x).abs() > self.half {
+            sign_x = -sign_x;
+        }
+        if (from.y + sign_y * radius.y).abs() > self.half {
+            sign_y = -sign_y;
+        }
+
+        // Each displacement sign pair is produced by exactly one
+        // counter-clockwise and one clockwise quarter.
+        let counter_clockwise_start = match (sign_x, sign_y) {
+            (-1, 1) => 0,
+            (-1, -1) => 1,
+            (1, -1) => 2,
+            _ => 3,
+        };
+        let counter_clockwise = self.below(2) == 0;
+        let start = if counter_clockwise {
+            counter_clockwise_start
+        } else {
+            (counter_clockwise_start + 3) % 4
+        };
+        let phase = QUARTERS[start];
+        PlanArc {
+            center: IntPoint::new(from.x - phase.0 * radius.x, from.y - phase.1 * radius.y),
+            radius,
+            start,
+            counter_clockwise,
+        }
+    }
+
+    fn vertex(&mut self, from: IntPoint<i64>, to: Option<IntPoint<i64>>) -> IntPoint<i64> {
+        if let Some(to) = to {
+            to
+        } else if self.percent(self.options.near_percent) {
+            self.step(from, NEAR_DISTANCE)
+        } else {
+            self.anchor()
+        }
+    }
+
+    fn control(&mut self, from: IntPoint<i64>, to: IntPoint<i64>) -> IntPoint<i64> {
+        match self.below(4) {
+            0 => from,
+            1 => to,
+            2 => {
+                let x = self.signed(MIDPOINT_DISTANCE);
+                let y = self.signed(MIDPOINT_DISTANCE);
+                IntPoint::new((from.x + to.x) / 2 + x, (from.y + to.y) / 2 + y)
+            }
+            _ => self.anchor(),
+        }
+    }
+
+    /// Returns a vertex at one of several coordinate scales.
+    fn anchor(&mut self) -> IntPoint<i64> {
+        let magnitude = self.magnitude();
+        IntPoint::new(self.signed(magnitude), self.signed(magnitude))
+    }
+
+    /// Returns a vector whose components are at most a quarter of the vertex
+    /// range.
+    fn offset(&mut self) -> IntPoint<i64> {
+        let magnitude = self.limited_magnitude();
+        IntPoint::new(self.signed(magnitude), self.signed(magnitude))
+    }
+
+    /// Moves `from` by at most `distance` per axis, reflecting the move when
+    /// it would leave the vertex range.
+    fn step(&mut self, from: IntPoint<i64>, distance: i64) -> IntPoint<i64> {
+        let dx = self.signed(distance);
+        let dy = self.signed(distance);
+        IntPoint::new(self.fold(from.x, dx), self.fold(from.y, dy))
+    }
+
+    #[inline]
+    fn fold(&self, value: i64, delta: i64) -> i64 {
+        if (value + delta).abs() <= self.half {
+            value + delta
+        } else {
+            value - delta
+        }
+    }
+
+    fn magnitude(&mut self) -> i64 {
+        let magnitude = match self.below(4) {
+            0 => 8,
+            1 => 256,
+            2 => 4096,
+            _ => self.half,
+        };
+        magnitude.min(self.half)
+    }
+
+    #[inline]
+    fn limited_magnitude(&mut self) -> i64 {
+        self.magnitude().min(self.half / 4).max(1)
+    }
+
+    /// Returns a value in `-magnitude..=magnitude`, starting from zero.
+    #[inline]
+    fn signed(&mut self, magnitude: i64) -> i64 {
+        let choice = self.below(2 * magnitude as u64 + 1) as i64;
+        if choice % 2 == 1 {
+            (choice + 1) / 2
+        } else {
+            -choice / 2
+        }
+    }
+
+    #[inline]
+    fn percent(&mut self, percent: u8) -> bool {
+        let percent = percent.min(100) as u64;
+        self.below(100) >= 100 - percent
+    }
+
+    #[inline]
+    fn below(&mut self, count: u64) -> u64 {
+        self.source.choose(count)
+    }
+}
+
+pub(super) fn to_int_shape<I: CurveInt>(contours: &[PlanContour]) -> CurveShape<I> {
+    CurveShape::new(
+        contours
+            .iter()
+            .map(|contour| {
+                let segments = contour
+                    .segments
+                    .iter()
+                    .map(|segment| match *segment {
+                        PlanSegment::Line { to } => CurveSegment::Line { to: int_point(to) },
+                        PlanSegment::Quad { ctrl, to } => CurveSegment::Quad {
+                            ctrl: int_point(ctrl),
+                            to: int_point(to),
+                        },
+                        PlanSegment::Cubic { ctrl0, ctrl1, to } => CurveSegment::Cubic {
+                            ctrl0: int_point(ctrl0),
+                            ctrl1: int_point(ctrl1),
+                            to: int_point(to),
+                        },
+                        PlanSegment::Arc(arc) => CurveSegment::Arc { arc: to_int_arc(arc) },
+                    })
+                    .collect();
+                CurvePath::new(int_point(contour.start), segments)
+            })
+            .collect(),
+    )
+}
+
+pub(super) fn to_int_arc<I: CurveInt>(arc: PlanArc) -> RationalArc<I> {
+    let one = FixedScale::<I>::DENOMINATOR;
+    let unit = I::from_wide(one);
+    let middle = I::from_rounded_float(f64::from_wide_int(one) * FRAC_1_SQRT_2);
+    let phase = |(cos, sin): (i64, i64)| ArcPhase {
+        cos: unit_component(cos, unit),
+        sin: unit_component(sin, unit),
+    };
+    RationalArc {
+        ellipse: EllipseFrame {
+            center: int_point(arc.center),
+            axis_x: ArcVector {
+                x: int_value(arc.radius.x),
+                y: I::ZERO,
+            },
+            axis_y: ArcVector {
+                x: I::ZERO,
+                y: int_value(arc.radius.y),
+            },
+        },
+        control_points: [
+            int_point(arc.start_point()),
+            int_point(arc.ctrl_point()),
+            int_point(arc.end_point()),
+        ],
+        weights: [unit, middle, unit],
+        start_phase: phase(QUARTERS[arc.start]),
+        end_phase: phase(QUARTERS[arc.end()]),
+        direction: if arc.counter_clockwise {
+            ArcDirection::CounterClockwise
+        } else {
+            ArcDirection::Clockwise
+        },
+    }
+}
+
+pub(super) fn to_float_shape<P: FloatPointCompatible>(
+    contours: &[PlanContour],
+    options: &CurveGeneratorOptions,
+) -> FloatCurveShape<P> {
+    let scale = float_scale(options);
+    FloatCurveShape::from_validated_contours(
+        contours
+            .iter()
+            .map(|contour| {
+                let segments = contour
+                    .segments
+                    .iter()
+                    .map(|segment| match *segment {
+                        PlanSegment::Line { to } => FloatCurveSegment::Line {
+                            to: float_point(to, scale),
+                        },
+                        PlanSegment::Quad { ctrl, to } => FloatCurveSegment::Quad {
+                            ctrl: float_point(ctrl, scale),
+                            to: float_point(to, scale),
+                        },
+                        PlanSegment::Cubic { ctrl0, ctrl1, to } => FloatCurveSegment::Cubic {
+                            ctrl0: float_point(ctrl0, scale),
+                            ctrl1: float_point(ctrl1, scale),
+                            to: float_point(to, scale),
+                        },
+                        PlanSegment::Arc(arc) => FloatCurveSegment::Arc {
+                            arc: float_arc(arc, scale),
+                        },
+                    })
+                    .collect();
+                FloatCurvePath::from_validated_parts(float_point(contour.start, scale), segments)
+            })
+            .collect(),
+    )
+}
+
+pub(super) fn to_float_arc<P: FloatPointCompatible>(
+    arc: PlanArc,
+    options: &CurveGeneratorOptions,
+) -> FloatRationalArc<P> {
+    float_arc(arc, float_scale(options))
+}
+
+fn float_arc<P: FloatPointCompatible>(arc: PlanArc, scale: f64) -> FloatRationalArc<P> {
+    let sweep = if arc.counter_clockwise {
+        FRAC_PI_2
+    } else {
+        -FRAC_PI_2
+    };
+    FloatRationalArc {
+        ellipse: Ellipse {
+            center: float_point(arc.center, scale),
+            radius_x: P::Scalar::from_float(arc.radius.x as f64 * scale),
+            radius_y: P::Scalar::from_float(arc.radius.y as f64 * scale),
+            rotation: P::Scalar::ZERO,
+        },
+        control_points: [
+            float_point(arc.start_point(), scale),
+            float_point(arc.ctrl_point(), scale),
+            float_point(arc.end_point(), scale),
+        ],
+        weights: [
+            P::Scalar::ONE,
+            P::Scalar::from_float(FRAC_1_SQRT_2),
+            P::Scalar::ONE,
+        ],
+        start_angle: P::Scalar::from_float(arc.start as f64 * FRAC_PI_2),
+        sweep_angle: P::Scalar::from_float(sweep),
+    }
+}
+
+#[inline]
+fn float_fraction_bits(options: &CurveGeneratorOptions) -> u32 {
+    options.float_fraction_bits.min(MAX_FLOAT_FRACTION_BITS)
+}
+
+#[inline]
+fn float_scale(options: &CurveGeneratorOptions) -> f64 {
+    1.0 / (1u64 << float_fraction_bits(options)) as f64
+}
+
+#[inline]
+fn float_point<P: FloatPointCompatible>(point: IntPoint<i64>, scale: f64) -> P {
+    P::from_xy(
+        P::Scalar::from_float(point.x as f64 * scale),
+        P::Scalar::from_float(point.y as f64 * scale),
+    )
+}
+
+#[inline]
+fn int_point<I: CurveInt>(point: IntPoint<i64>) -> IntPoint<I> {
+    IntPoint::new(int_value(point.x), int_value(point.y))
+}
+
+/// Converts a grid value; the generator keeps values exact in `f64`.
+#[inline]
+fn int_value<I: CurveInt>(value: i64) -> I {
+    I::from_float(value as f64)
+}
+
+#[inline]
+fn unit_component<I: CurveInt>(value: i64, unit: I) -> I {
+    match value {
+        1 => unit,
+        -1 => -unit,
+        _ => I::ZERO,
+    }
+}
+
+#[inline]
+fn add(a: IntPoint<i64>, b: IntPoint<i64>) -> IntPoint<i64> {
+    IntPoint::new(a.x + b.x, a.y + b.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::{IntCurveOverlay, ShapeType, validate_shape};
+    use crate::{FillRule, OverlayRule};
+
+    struct SplitMix(u64);
+
+    impl ChoiceSource for SplitMix {
+        fn choose(&mut self, count: u64) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut value = self.0;
+            value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            (value ^ (value >> 31)) % count
+        }
+    }
+
+    struct Exhausted;
+
+    impl ChoiceSource for Exhausted {
+        fn choose(&mut self, _count: u64) -> u64 {
+            0
+        }
+    }
+
+    fn plan(seed: u64, options: CurveGeneratorOptions) -> Vec<PlanContour> {
+        PlanGenerator::for_int::<i32>(&mut SplitMix(seed), options).shape()
+    }
+
+    fn assert_valid_int<I: CurveInt>(options: CurveGeneratorOptions) {
+        let limit = 1i64 << (I::BITS - CURVE_COORDINATE_SAFETY_BITS - 1);
+        for seed in 0..500 {
+            let contours = PlanGenerator::for_int::<I>(&mut SplitMix(seed), options).shape();
+            for contour in &contours {
+                let mut points = alloc::vec![contour.start];
+                for segment in &contour.segments {
+                    match *segment {
+                        PlanSegment::Line { to } => points.push(to),
+                        PlanSegment::Quad { ctrl, to } => points.extend([ctrl, to]),
+                        PlanSegment::Cubic { ctrl0, ctrl1, to } => points.extend([ctrl0, ctrl1, to]),
+                        PlanSegment::Arc(arc) => {
+                            points.extend([arc.start_point(), arc.ctrl_point(), arc.end_point()])
+                        }
+                    }
+                }
+                assert!(
+                    points
+                        .iter()
+                        .all(|point| point.x.abs() <= limit && point.y.abs() <= limit)
+                );
+            }
+            let shape = to_int_shape::<I>(&contours);
+            assert!(validate_shape(&shape).is_ok(), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn generated_shapes_are_valid_for_every_engine() {
+        let degenerate = CurveGeneratorOptions::default()
+            .with_cusp_percent(30)
+            .with_loop_percent(30)
+            .with_tangency_percent(30)
+            .with_coincidence_percent(50)
+            .with_coordinate_bits(64);
+        for options in [CurveGeneratorOptions::default(), degenerate] {
+            assert_valid_int::<i16>(options);
+            assert_valid_int::<i32>(options);
+            assert_valid_int::<i64>(options);
+        }
+    }
+
+    #[test]
+    fn exhausted_source_produces_a_minimal_shape() {
+        let contours =
+            PlanGenerator::for_int::<i32>(&mut Exhausted, CurveGeneratorOptions::default()).shape();
+        let shape = to_int_shape::<i32>(&contours);
+
+        assert_eq!(shape.contours.len(), 1);
+        assert_eq!(shape.contours[0].segments.len(), 2);
+        assert_eq!(validate_shape(&shape), Ok(()));
+    }
+
+    #[test]
+    fn generated_shapes_overlay_without_panicking() {
+        let options = CurveGeneratorOptions::default()
+            .with_tangency_percent(30)
+            .with_coincidence_percent(50);
+        for seed in 0..40 {
+            let subject = to_int_shape::<i32>(&plan(2 * seed, options));
+            let clip = to_int_shape::<i32>(&plan(2 * seed + 1, options));
+            let mut overlay = IntCurveOverlay::new();
+            overlay.add_shape(subject, ShapeType::Subject).unwrap();
+            overlay.add_shape(clip, ShapeType::Clip).unwrap();
+            overlay.overlay(OverlayRule::Xor, FillRule::NonZero);
+        }
+    }
+
+    #[test]
+    fn cusp_cubics_stop_at_their_midpoint() {
+        let options = CurveGeneratorOptions::default()
+            .without_degeneracies()
+            .with_segment_weights(0, 0, 1, 0)
+            .with_cusp_percent(100);
+        for seed in 0..100 {
+            let contours = plan(seed, options);
+            let mut from = contours[0].start;
+            for segment in &contours[0].segments {
+                let PlanSegment::Cubic { ctrl0, ctrl1, to } = *segment else {
+                    panic!("expected a cubic segment");
+                };
+                assert_eq!(
+                    (ctrl0.x - to.x, ctrl0.y - to.y),
+                    (ctrl1.x - from.x, ctrl1.y - from.y)
+                );
+                from = to;
+            }
+        }
+    }
+
+    #[test]
+    fn loop_cubics_cross_their_control_polygon() {
+        let options = CurveGeneratorOptions::default()
+            .without_degeneracies()
+            .with_segment_weights(0, 0, 1, 0)
+            .with_loop_percent(100);
+        let mut loops = 0;
+        for seed in 0..100 {
+            let contours = plan(seed, options);
+            let mut from = contours[0].start;
+            for segment in &contours[0].segments {
+                let PlanSegment::Cubic { ctrl0, ctrl1, to } = *segment else {
+                    panic!("expected a cubic segment");
+                };
+                let chord = (to.x - from.x, to.y - from.y);
+                if chord != (0, 0) && (ctrl0.x - ctrl1.x, ctrl0.y - ctrl1.y) == (3 * chord.0, 3 * chord.1) {
+                    loops += 1;
+                }
+                from = to;
+            }
+        }
+        assert!(loops > 100);
+    }
+
+    #[test]
+    fn tangent_pairs_touch_at_the_lens_apex() {
+        let options = CurveGeneratorOptions::default()
+            .with_max_contours(2)
+            .with_tangency_percent(100);
+        let mut pairs = 0;
+        for seed in 0..100 {
+            let contours = plan(seed, options);
+            if contours.len() != 2 {
+                continue;
+            }
+            pairs += 1;
+            let PlanSegment::Quad { ctrl, to } = contours[0].segments[0] else {
+                panic!("expected a lens");
+            };
+            let start = contours[0].start;
+            let apex = (
+                (start.x + 2 * ctrl.x + to.x) / 4,
+                (start.y + 2 * ctrl.y + to.y) / 4,
+            );
+            let partner = &contours[1];
+            let touch = match partner.segments[0] {
+                PlanSegment::Line { to } => ((partner.start.x + to.x) / 2, (partner.start.y + to.y) / 2),
+                PlanSegment::Quad { ctrl, to } => (
+                    (partner.start.x + 2 * ctrl.x + to.x) / 4,
+                    (partner.start.y + 2 * ctrl.y + to.y) / 4,
+                ),
+                _ => panic!("expected a triangle or a mirrored lens"),
+            };
+            assert_eq!(apex, touch);
+        }
+        assert!(pairs > 0);
+    }
+
+    #[test]
+    fn coincident_contours_repeat_an_earlier_segment() {
+        let options = CurveGeneratorOptions::default()
+            .without_degeneracies()
+            .with_max_contours(2)
+            .with_coincidence_percent(100);
+        for seed in 0..100 {
+            let contours = plan(seed, options);
+            if contours.len() != 2 {
+                continue;
+            }
+            let shape = to_int_shape::<i32>(&contours);
+            let first = &shape.contours[0];
+            let second = &shape.contours[1];
+            let shared = (second.start, second.segments[0].clone());
+            let reversed = first.reversed();
+            let matches = |path: &CurvePath<i32>| {
+                let mut from = path.start;
+                path.segments.iter().any(|segment| {
+                    let found = (from, segment.clone()) == shared;
+                    from = segment.end_point();
+                    found
+                })
+            };
+            assert!(matches(first) || matches(&reversed), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn generated_float_shapes_are_valid() {
+        let options = CurveGeneratorOptions::default()
+            .with_tangency_percent(30)
+            .with_coincidence_percent(50);
+        for seed in 0..200 {
+            let contours = PlanGenerator::for_float::<[f64; 2]>(&mut SplitMix(seed), options).shape();
+            let shape = to_float_shape::<[f64; 2]>(&contours, &options);
+            assert_eq!(FloatCurveShape::validate_contours(shape.contours()), Ok(()));
+
+            let contours = PlanGenerator::for_float::<[f32; 2]>(&mut SplitMix(seed), options).shape();
+            let shape = to_float_shape::<[f32; 2]>(&contours, &options);
+            assert_eq!(FloatCurveShape::validate_contours(shape.contours()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn generated_arcs_are_valid() {
+        for seed in 0..200 {
+            let arc =
+                PlanGenerator::for_int::<i32>(&mut SplitMix(seed), CurveGeneratorOptions::default()).arc();
+            assert_eq!(to_int_arc::<i32>(arc).validate(), Ok(()));
+            assert_eq!(to_int_arc::<i64>(arc).validate(), Ok(()));
+            assert_eq!(
+                to_float_arc::<[f64; 2]>(arc, &CurveGeneratorOptions::default()).validate(),
+                Ok(())
+            );
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::float::CurveShape as FloatCurveShape;
+use crate::float::arc::RationalArc as FloatRationalArc;
+use crate::generate::CurveGeneratorOptions;
+use crate::generate::plan::{
+    ChoiceSource, PlanGenerator, to_float_arc, to_float_shape, to_int_arc, to_int_shape,
+};
+use crate::int::CurveInt;
+use crate::int::CurveShape;
+use crate::int::arc::RationalArc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use i_overlay::i_float::float::compatible::FloatPointCompatible;
+use proptest::arbitrary::{Arbitrary, any};
+use proptest::collection::vec;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+/// Upper bound on the choices consumed by one segment.
+const SEGMENT_CHOICES: usize = 24;
+/// Upper bound on the choices consumed outside segments by one contour.
+const CONTOUR_CHOICES: usize = 16;
+
+/// Replays a shrinkable list of choices; missing choices are zero.
+struct ChoiceStream {
+    choices: Vec<u64>,
+    next: usize,
+}
+
+impl ChoiceSource for ChoiceStream {
+    #[inline]
+    fn choose(&mut self, count: u64) -> u64 {
+        let choice = self.choices.get(self.next).map_or(0, |choice| choice % count);
+        self.next += 1;
+        choice
+    }
+}
+
+impl CurveGeneratorOptions {
+    /// Returns a strategy for integer curve shapes of engine `I`.
+    ///
+    /// Shrinking removes and lowers the underlying choices, which moves
+    /// toward fewer contours, fewer segments, smaller coordinates, and
+    /// regular geometry.
+    pub fn int_shape_strategy<I>(&self) -> impl Strategy<Value = CurveShape<I>> + use<I>
+    where
+        I: CurveInt,
+        CurveShape<I>: Debug,
+    {
+        let options = *self;
+        self.choices().prop_map(move |choices| {
+            let mut source = ChoiceStream { choices, next: 0 };
+            to_int_shape(&PlanGenerator::for_int::<I>(&mut source, options).shape())
+        })
+    }
+
+    /// Returns a strategy for validated float curve shapes.
+    pub fn float_shape_strategy<P>(&self) -> impl Strategy<Value = FloatCurveShape<P>> + use<P>
+    where
+        P: FloatPointCompatible + 'static,
+        FloatCurveShape<P>: Debug,
+    {
+        let options = *self;
+        self.choices().prop_map(move |choices| {
+            let mut source = ChoiceStream { choices, next: 0 };
+            let plan = PlanGenerator::for_float::<P>(&mut source, options).shape();
+            to_float_shape(&plan, &options)
+        })
+    }
+
+    /// Returns a strategy for valid integer rational arcs of engine `I`.
+    pub fn int_arc_strategy<I>(&self) -> impl Strategy<Value = RationalArc<I>> + use<I>
+    where
+        I: CurveInt,
+        RationalArc<I>: Debug,
+    {
+        let options = *self;
+        vec(any::<u64>(), 0..=SEGMENT_CHOICES).prop_map(move |choices| {
+            let mut source = ChoiceStream { choices, next: 0 };
+            to_int_arc(PlanGenerator::for_int::<I>(&mut source, options).arc())
+        })
+    }
+
+    /// Returns a strategy for valid float rational arcs.
+    pub fn float_arc_strategy<P>(&self) -> impl Strategy<Value = FloatRationalArc<P>> + use<P>
+    where
+        P: FloatPointCompatible + 'static,
+        FloatRationalArc<P>: Debug,
+    {
+        let options = *self;
+        vec(any::<u64>(), 0..=SEGMENT_CHOICES).prop_map(move |choices| {
+            let mut source = ChoiceStream { choices, next: 0 };
+            to_float_arc(
+                PlanGenerator::for_float::<P>(&mut source, options).arc(),
+                &options,
+            )
+        })
+    }
+
+    fn choices(&self) -> impl Strategy<Value = Vec<u64>> + use<> {
+        let contours = self.max_contours.max(1);
+        let segments = self.max_segments.max(2);
+        let budget = contours.saturating_mul(CONTOUR_CHOICES + segments.saturating_mul(SEGMENT_CHOICES));
+        vec(any::<u64>(), 0..=budget)
+    }
+}
+
+impl<I> Arbitrary for CurveShape<I>
+where
+    I: CurveInt + 'static,
+    CurveShape<I>: Debug,
+{
+    type Parameters = CurveGeneratorOptions;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        options.int_shape_strategy().boxed()
+    }
+}
+
+impl<P> Arbitrary for FloatCurveShape<P>
+where
+    P: FloatPointCompatible + 'static,
+    FloatCurveShape<P>: Debug,
+{
+    type Parameters = CurveGeneratorOptions;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        options.float_shape_strategy().boxed()
+    }
+}
+
+impl<I> Arbitrary for RationalArc<I>
+where
+    I: CurveInt + 'static,
+    RationalArc<I>: Debug,
+{
+    type Parameters = CurveGeneratorOptions;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        options.int_arc_strategy().boxed()
+    }
+}
+
+impl<P> Arbitrary for FloatRationalArc<P>
+where
+    P: FloatPointCompatible + 'static,
+    FloatRationalArc<P>: Debug,
+{
+    type Parameters = CurveGeneratorOptions;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        options.float_arc_strategy().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::validate_shape;
+    use proptest::arbitrary::any_with;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn strategies_generate_valid_input() {
+        let options = CurveGeneratorOptions::default().with_tangency_percent(30);
+        let mut runner = TestRunner::deterministic();
+        runner
+            .run(&any_with::<CurveShape<i32>>(options), |shape| {
+                assert!(validate_shape(&shape).is_ok());
+                Ok(())
+            })
+            .unwrap();
+        runner
+            .run(&any::<FloatCurveShape<[f64; 2]>>(), |shape| {
+                assert_eq!(FloatCurveShape::validate_contours(shape.contours()), Ok(()));
+                Ok(())
+            })
+            .unwrap();
+        runner
+            .run(&any::<RationalArc<i64>>(), |arc| {
+                assert_eq!(arc.validate(), Ok(()));
+                Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -0,0 +1,132 @@
+use crate::float::CurveShape as FloatCurveShape;
+use crate::float::arc::RationalArc as FloatRationalArc;
+use crate::generate::CurveGeneratorOptions;
+use crate::generate::plan::{
+    ChoiceSource, PlanGenerator, to_float_arc, to_float_shape, to_int_arc, to_int_shape,
+};
+use crate::int::CurveInt;
+use crate::int::CurveShape;
+use crate::int::arc::RationalArc;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use i_overlay::i_float::float::compatible::FloatPointCompatible;
+
+/// Reads bounded choices from fuzzer input; exhausted input yields zero.
+struct UnstructuredChoices<'u, 'a> {
+    data: &'u mut Unstructured<'a>,
+}
+
+impl ChoiceSource for UnstructuredChoices<'_, '_> {
+    #[inline]
+    fn choose(&mut self, count: u64) -> u64 {
+        self.data.int_in_range(0..=count - 1).unwrap_or(0)
+    }
+}
+
+impl CurveGeneratorOptions {
+    /// Generates an integer curve shape for engine `I` from fuzzer input.
+    pub fn arbitrary_int_shape<I: CurveInt>(&self, data: &mut Unstructured<'_>) -> Result<CurveShape<I>> {
+        let mut source = UnstructuredChoices { data };
+        let plan = PlanGenerator::for_int::<I>(&mut source, *self).shape();
+        Ok(to_int_shape(&plan))
+    }
+
+    /// Generates a validated float curve shape from fuzzer input.
+    pub fn arbitrary_float_shape<P: FloatPointCompatible>(
+        &self,
+        data: &mut Unstructured<'_>,
+    ) -> Result<FloatCurveShape<P>> {
+        let mut source = UnstructuredChoices { data };
+        let plan = PlanGenerator::for_float::<P>(&mut source, *self).shape();
+        Ok(to_float_shape(&plan, self))
+    }
+
+    /// Generates a valid integer rational arc for engine `I` from fuzzer
+    /// input.
+    pub fn arbitrary_int_arc<I: CurveInt>(&self, data: &mut Unstructured<'_>) -> Result<RationalArc<I>> {
+        let mut source = UnstructuredChoices { data };
+        let arc = PlanGenerator::for_int::<I>(&mut source, *self).arc();
+        Ok(to_int_arc(arc))
+    }
+
+    /// Generates a valid float rational arc from fuzzer input.
+    pub fn arbitrary_float_arc<P: FloatPointCompatible>(
+        &self,
+        data: &mut Unstructured<'_>,
+    ) -> Result<FloatRationalArc<P>> {
+        let mut source = UnstructuredChoices { data };
+        let arc = PlanGenerator::for_float::<P>(&mut source, *self).arc();
+        Ok(to_float_arc(arc, self))
+    }
+}
+
+impl<'a, I: CurveInt> Arbitrary<'a> for CurveShape<I> {
+    fn arbitrary(data: &mut Unstructured<'a>) -> Result<Self> {
+        CurveGeneratorOptions::default().arbitrary_int_shape(data)
+    }
+}
+
+impl<'a, P: FloatPointCompatible> Arbitrary<'a> for FloatCurveShape<P> {
+    fn arbitrary(data: &mut Unstructured<'a>) -> Result<Self> {
+        CurveGeneratorOptions::default().arbitrary_float_shape(data)
+    }
+}
+
+impl<'a, I: CurveInt> Arbitrary<'a> for RationalArc<I> {
+    fn arbitrary(data: &mut Unstructured<'a>) -> Result<Self> {
+        CurveGeneratorOptions::default().arbitrary_int_arc(data)
+    }
+}
+
+impl<'a, P: FloatPointCompatible> Arbitrary<'a> for FloatRationalArc<P> {
+    fn arbitrary(data: &mut Unstructured<'a>) -> Result<Self> {
+        CurveGeneratorOptions::default().arbitrary_float_arc(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::validate_shape;
+    use alloc::vec::Vec;
+
+    fn bytes(seed: u8, len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|index| {
+                (index as u8)
+                    .wrapping_mul(37)
+                    .wrapping_add(seed.wrapping_mul(101))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn arbitrary_shapes_are_valid() {
+        for seed in 0..64 {
+            let data = bytes(seed, 512);
+            let mut unstructured = Unstructured::new(&data);
+            let shape = CurveShape::<i32>::arbitrary(&mut unstructured).unwrap();
+            assert!(validate_shape(&shape).is_ok());
+
+            let mut unstructured = Unstructured::new(&data);
+            let shape = FloatCurveShape::<[f64; 2]>::arbitrary(&mut unstructured).unwrap();
+            assert_eq!(FloatCurveShape::validate_contours(shape.contours()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn arbitrary_arcs_are_valid() {
+        for seed in 0..64 {
+            let data = bytes(seed, 64);
+            let arc = RationalArc::<i64>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert_eq!(arc.validate(), Ok(()));
+            let arc = FloatRationalArc::<[f32; 2]>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert_eq!(arc.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn empty_input_still_produces_a_shape() {
+        let shape = CurveShape::<i16>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert!(validate_shape(&shape).is_ok());
+    }
+}
@@ -4,6 +4,7 @@ use crate::int::bool::overlay::CurveOverlayOptions;
 use crate::kernel::int::curve::chord::Chord;
 use crate::kernel::int::curve::param::SegmentParam;
 use crate::kernel::int::curve::point_at::PointAt;
+use crate::kernel::int::curve::segment::Segment;
 use alloc::vec::Vec;
 use i_overlay::i_float::int::number::wide_int::WideIntNumber;
 
@@ -36,6 +37,12 @@ impl<I: CurveInt> CurveApproximator<I> {
         self.output.reserve(edges.len());
 
         for &edge in edges.iter() {
+            if let Segment::Line(_) = edge.curve {
+                // A line is its own chord.
+                self.output.push(edge);
+                continue;
+            }
+
             self.stack.push(ApproximationItem { edge, depth: 0 });
 
             while let Some(item) = self.stack.pop() {
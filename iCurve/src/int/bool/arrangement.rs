@@ -0,0 +1,1038 @@
+use crate::collections::disjoint_set::DisjointSet;
+use crate::int::CurveInt;
+use crate::int::bool::bounds::CurveBoundsBuffer;
+use crate::int::bool::data::CurveSourceSpan;
+use crate::int::bool::edge::CurveEdge;
+use crate::int::bool::overlay::{
+    CurveInputError, CurveOverlayOptions, CurveOverlayOptionsError, IntCurveOverlay, validate_path,
+    validate_shape,
+};
+use crate::int::bool::planarize::CurvePlanarizer;
+use crate::int::bool::recompose::CurveRun;
+use crate::int::bool::source::{CurveContourSource, CurveSource};
+use crate::int::curve::path::CurvePath;
+use crate::int::curve::segment::CurveSegment;
+use crate::int::curve::shape::CurveShape;
+use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::line::LineSegment;
+use crate::kernel::int::curve::segment::Segment;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+use i_overlay::core::overlay::ShapeType;
+use i_overlay::core::solver::Solver;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_float::int::rect::IntRect;
+use i_overlay::i_float::int::vector::IntVector;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Upper bound on chord planarization passes. Each pass rounds new crossings
+/// to the grid, which can create further crossings nearby.
+const MAX_CHORD_PASSES: usize = 8;
+
+/// Input segment that contributes to a [`CurveArrangementEdge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub struct CurveArrangementSource {
+    /// Zero-based index of the path, in the order paths were added.
+    pub path: usize,
+    /// Zero-based segment index within the path.
+    pub segment: usize,
+    /// Whether the path traverses the edge from `to` to `from`.
+    pub is_reversed: bool,
+}
+
+/// Edge of a [`CurveArrangement`] traversed in one direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct CurveArrangementHalfEdge {
+    /// Index of the edge in [`CurveArrangement::edges`].
+    pub edge: usize,
+    /// Whether the half-edge runs from the edge's `to` vertex to its `from`
+    /// vertex.
+    pub is_reversed: bool,
+}
+
+/// Curve piece between two vertices of a [`CurveArrangement`].
+///
+/// The piece lies on one or more input segments and touches other pieces
+/// only at its end vertices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurveArrangementEdge<I: CurveInt> {
+    /// Index of the start vertex in [`CurveArrangement::vertices`].
+    pub from: usize,
+    /// Index of the end vertex in [`CurveArrangement::vertices`].
+    pub to: usize,
+    /// Geometry from the `from` vertex to the `to` vertex.
+    pub segment: CurveSegment<I>,
+    /// Input segments that run along the piece, sorted, once per traversal.
+    /// Overlapping input segments share one edge.
+    pub sources: Vec<CurveArrangementSource>,
+    /// Face to the left of the edge when moving from `from` to `to`.
+    pub left_face: usize,
+    /// Face to the right of the edge when moving from `from` to `to`.
+    pub right_face: usize,
+}
+
+/// Face of a [`CurveArrangement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurveArrangementFace {
+    /// Counterclockwise outer boundary. It is empty for the unbounded face.
+    pub boundary: Vec<CurveArrangementHalfEdge>,
+    /// Clockwise outer boundaries of the connected pieces of the
+    /// arrangement that lie directly inside the face.
+    pub holes: Vec<Vec<CurveArrangementHalfEdge>>,
+    /// Winding number of each input path around the face, indexed like the
+    /// paths. Open paths enclose nothing and always report `0`.
+    pub winding: Vec<i32>,
+}
+
+/// Planar subdivision formed by a set of curve paths.
+///
+/// Every input curve is split at its intersections and overlaps with all
+/// curves, including itself. Pieces that meet snap to one shared grid vertex,
+/// so two edges touch only at their end vertices. Each edge keeps its curve
+/// kind and records the input segments it came from.
+///
+/// Face `0` is the unbounded face. Every face lies to the left of its
+/// boundary half-edges and reports the winding number of each input path.
+#[derive(Debug, Clone)]
+pub struct CurveArrangement<I: CurveInt> {
+    vertices: Vec<IntPoint<I>>,
+    edges: Vec<CurveArrangementEdge<I>>,
+    faces: Vec<CurveArrangementFace>,
+}
+
+impl<I: CurveInt> CurveArrangement<I> {
+    /// Index of the unbounded face.
+    pub const UNBOUNDED_FACE: usize = 0;
+
+    /// Returns the vertices in ascending point order.
+    #[inline]
+    pub fn vertices(&self) -> &[IntPoint<I>] {
+        &self.vertices
+    }
+
+    /// Returns the curve pieces.
+    #[inline]
+    pub fn edges(&self) -> &[CurveArrangementEdge<I>] {
+        &self.edges
+    }
+
+    /// Returns the faces, starting with the unbounded face.
+    #[inline]
+    pub fn faces(&self) -> &[CurveArrangementFace] {
+        &self.faces
+    }
+
+    /// Returns the index of the vertex at which `half_edge` starts.
+    #[inline]
+    pub fn origin(&self, half_edge: CurveArrangementHalfEdge) -> usize {
+        let edge = &self.edges[half_edge.edge];
+        if half_edge.is_reversed { edge.to } else { edge.from }
+    }
+
+    /// Returns the geometry of `half_edge` in its direction of travel.
+    pub fn half_edge_segment(&self, half_edge: CurveArrangementHalfEdge) -> CurveSegment<I> {
+        let edge = &self.edges[half_edge.edge];
+        if !half_edge.is_reversed {
+            return edge.segment.clone();
+        }
+
+        let forward = CurvePath::new(self.vertices[edge.from], vec![edge.segment.clone()]);
+        forward.reversed().segments.remove(0)
+    }
+
+    /// Returns a closed path along `cycle`, such as a face boundary or hole.
+    pub fn cycle_path(&self, cycle: &[CurveArrangementHalfEdge]) -> Option<CurvePath<I>> {
+        let start = self.vertices[self.origin(*cycle.first()?)];
+        let segments = cycle
+            .iter()
+            .map(|&half_edge| self.half_edge_segment(half_edge))
+            .collect();
+        Some(CurvePath::new(start, segments))
+    }
+
+    /// Returns the region of a bounded face as a shape whose first contour
+    /// is the outer boundary, followed by its holes. The unbounded face has
+    /// no outer boundary and returns `None`.
+    pub fn face_shape(&self, face: usize) -> Option<CurveShape<I>> {
+        let face = &self.faces[face];
+        let boundary = self.cycle_path(&face.boundary)?;
+        let mut contours = Vec::with_capacity(face.holes.len() + 1);
+        contours.push(boundary);
+        contours.extend(face.holes.iter().filter_map(|hole| self.cycle_path(hole)));
+        Some(CurveShape::new(contours))
+    }
+}
+
+/// Builds the [`CurveArrangement`] of open and closed curve paths.
+///
+/// Paths are identified by the order in which they are added. The builder
+/// uses the approximation and planarization stages of [`IntCurveOverlay`],
+/// so its solver precision and [`CurveOverlayOptions`] have the same meaning.
+pub struct IntCurveArrangement<I: CurveInt> {
+    overlay: IntCurveOverlay<I>,
+    planarizer: CurvePlanarizer<I>,
+    bounds: CurveBoundsBuffer<I>,
+}
+
+impl<I: CurveInt> IntCurveArrangement<I> {
+    /// Creates an empty arrangement builder.
+    pub fn new() -> Self {
+        Self {
+            overlay: IntCurveOverlay::new(),
+            planarizer: CurvePlanarizer::new(),
+            bounds: CurveBoundsBuffer::new(),
+        }
+    }
+
+    /// Sets the polygon solver strategy and precision.
+    #[must_use]
+    pub fn with_solver(mut self, solver: Solver) -> Self {
+        self.overlay = self.overlay.with_solver(solver);
+        self
+    }
+
+    /// Validates and sets the curve approximation options.
+    pub fn try_with_options(
+        mut self,
+        options: CurveOverlayOptions,
+    ) -> Result<Self, CurveOverlayOptionsError> {
+        self.overlay = self.overlay.try_with_options(options)?;
+        Ok(self)
+    }
+
+    /// Returns the number of paths added so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.overlay.contours.len()
+    }
+
+    /// Returns whether no path has been added.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.overlay.contours.is_empty()
+    }
+
+    /// Validates and adds an open or closed path, and returns its index.
+    ///
+    /// Errors report the path as contour `0`.
+    pub fn add_path(&mut self, path: CurvePath<I>) -> Result<usize, CurveInputError> {
+        validate_path(&path, 0)?;
+        let index = self.len();
+        self.overlay.push_path(path, ShapeType::Subject);
+        Ok(index)
+    }
+
+    /// Validates and adds every closed contour of `shape`, and returns their
+    /// path indices.
+    pub fn add_shape(&mut self, shape: CurveShape<I>) -> Result<Range<usize>, CurveInputError> {
+        validate_shape(&shape)?;
+        let first = self.len();
+        for path in shape.contours {
+            self.overlay.push_path(path, ShapeType::Subject);
+        }
+        Ok(first..self.len())
+    }
+
+    /// Splits every path at all intersections and returns the arrangement.
+    pub fn build(mut self) -> CurveArrangement<I> {
+        // Curves are split at their intersections by the overlay stages.
+        // Their chords can still cross near tangencies, so the chords are
+        // planarized as lines until every crossing is a shared vertex.
+        self.overlay.prepare();
+        let cross_radius = self.overlay.initial_snap_radius();
+
+        let mut chords: Vec<CurveEdge<I>> = self
+            .overlay
+            .curve_edges
+            .iter()
+            .filter_map(|edge| {
+                let chord = edge.curve.chord();
+                let line = LineSegment {
+                    control_points: [chord.a, chord.b],
+                };
+                (!chord.is_zero_length()).then(|| {
+                    CurveEdge::new(
+                        Segment::Line(line),
+                        edge.curve_id,
+                        edge.start_param,
+                        edge.end_param,
+                    )
+                })
+            })
+            .collect();
+
+        for _ in 0..MAX_CHORD_PASSES {
+            let count = chords.len();
+            self.planarizer
+                .planarize(&mut chords, cross_radius, &mut self.bounds);
+            if chords.len() == count {
+                break;
+            }
+        }
+
+        ArrangementGraph::new(&chords).into_arrangement(&self.overlay.curve_sources, &self.overlay.contours)
+    }
+}
+
+impl<I: CurveInt> Default for IntCurveArrangement<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Source span of a chord with the direction in which its path runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct ChordSpan {
+    span: CurveSourceSpan,
+    is_reversed: bool,
+}
+
+impl ChordSpan {
+    #[inline]
+    fn reversed(self) -> Self {
+        Self {
+            span: self.span.reversed(),
+            is_reversed: !self.is_reversed,
+        }
+    }
+}
+
+/// Unique chord between two grid vertices, stored from the smaller vertex.
+struct ChordLink {
+    a: usize,
+    b: usize,
+    spans: Vec<ChordSpan>,
+}
+
+impl ChordLink {
+    fn oriented_from(&self, vertex: usize) -> (usize, Vec<ChordSpan>) {
+        if vertex == self.a {
+            (self.b, self.spans.clone())
+        } else {
+            (self.a, self.spans.iter().map(|span| span.reversed()).collect())
+        }
+    }
+}
+
+/// Chain of chords that forms one arrangement edge.
+struct ChordChain {
+    vertices: Vec<usize>,
+    spans: Vec<ChordSpan>,
+}
+
+impl ChordChain {
+    /// Appends the next chord if it continues every span of the chain, so
+    /// the joined piece keeps its provenance.
+    fn try_extend(&mut self, end: usize, spans: &[ChordSpan]) -> bool {
+        if end == self.vertices[0] || spans.len() != self.spans.len() {
+            return false;
+        }
+
+        let mut is_used = vec![false; spans.len()];
+        let mut joined = Vec::with_capacity(spans.len());
+        for left in &self.spans {
+            let found = spans.iter().enumerate().position(|(index, right)| {
+                !is_used[index]
+                    && !left.span.is_collapsed()
+                    && !right.span.is_collapsed()
+                    && left.span.curve_id == right.span.curve_id
+                    && left.is_reversed == right.is_reversed
+                    && left.span.end == right.span.start
+            });
+            let Some(index) = found else {
+                return false;
+            };
+            is_used[index] = true;
+            let mut span = *left;
+            span.span.end = spans[index].span.end;
+            joined.push(span);
+        }
+
+        self.vertices.push(end);
+        self.spans = joined;
+        true
+    }
+}
+
+/// Closed walk of half-edges with the grid vertices it passes through.
+struct FaceCycle<I: CurveInt> {
+    half_edges: Vec<usize>,
+    vertices: Vec<usize>,
+    rect: IntRect<I>,
+    is_outer: bool,
+}
+
+struct ArrangementGraph<I: CurveInt> {
+    points: Vec<IntPoint<I>>,
+    links: Vec<ChordLink>,
+    incident: Vec<Vec<usize>>,
+}
+
+impl<I: CurveInt> ArrangementGraph<I> {
+    fn new(chords: &[CurveEdge<I>]) -> Self {
+        let mut points: Vec<IntPoint<I>> = chords
+            .iter()
+            .flat_map(|edge| {
+                let chord = edge.curve.chord();
+                [chord.a, chord.b]
+            })
+            .collect();
+        points.sort_unstable();
+        points.dedup();
+
+        let mut keyed: Vec<(usize, usize, ChordSpan)> = chords
+            .iter()
+            .map(|edge| {
+                let chord = edge.curve.chord();
+                let a = points.binary_search(&chord.a).unwrap_or_default();
+                let b = points.binary_search(&chord.b).unwrap_or_default();
+                let span = ChordSpan {
+                    span: CurveSourceSpan::from_edge(*edge),
+                    is_reversed: false,
+                };
+                if a < b {
+                    (a, b, span)
+                } else {
+                    (b, a, span.reversed())
+                }
+            })
+            .collect();
+        keyed.sort_unstable();
+        keyed.dedup();
+
+        let mut links: Vec<ChordLink> = Vec::new();
+        let mut incident = vec![Vec::new(); points.len()];
+        for (a, b, span) in keyed {
+            if let Some(link) = links.last_mut().filter(|link| link.a == a && link.b == b) {
+                link.spans.push(span);
+                continue;
+            }
+            incident[a].push(links.len());
+            incident[b].push(links.len());
+            links.push(ChordLink {
+                a,
+                b,
+                spans: vec![span],
+            });
+        }
+
+        Self {
+            points,
+            links,
+            incident,
+        }
+    }
+
+    fn into_arrangement(
+        self,
+        sources: &[CurveSource<I>],
+        paths: &[CurveContourSource<I>],
+    ) -> CurveArrangement<I> {
+        let (chains, is_kept) = self.collect_chains();
+
+        let mut vertex_index = vec![usize::MAX; self.points.len()];
+        let mut vertices = Vec::new();
+        for (index, &point) in self.points.iter().enumerate() {
+            if is_kept[index] {
+                vertex_index[index] = vertices.len();
+                vertices.push(point);
+            }
+        }
+
+        let mut edges = Vec::with_capacity(chains.len());
+        let mut deltas = Vec::with_capacity(chains.len());
+        for chain in &chains {
+            let first = chain.vertices[0];
+            let last = *chain.vertices.last().unwrap_or(&first);
+            let run = CurveRun {
+                start: self.points[first],
+                end: self.points[last],
+                candidates: chain.spans.iter().map(|span| span.span).collect(),
+            };
+            let segment = run.into_curve_segment(sources).unwrap_or_else(|line| line);
+
+            let mut edge_sources: Vec<CurveArrangementSource> = chain
+                .spans
+                .iter()
+                .map(|span| {
+                    let origin = sources[span.span.curve_id.0].origin;
+                    CurveArrangementSource {
+                        path: origin.contour,
+                        segment: origin.segment,
+                        is_reversed: span.is_reversed,
+                    }
+                })
+                .collect();
+            edge_sources.sort_unstable();
+
+            // Crossing a closed path from right to left increments its winding.
+            let delta: Vec<(usize, i32)> = chain
+                .spans
+                .iter()
+                .filter_map(|span| {
+                    let path = sources[span.span.curve_id.0].origin.contour;
+                    paths[path]
+                        .path
+                        .is_closed()
+                        .then_some((path, if span.is_reversed { -1 } else { 1 }))
+                })
+                .collect();
+            deltas.push(delta);
+
+            edges.push(CurveArrangementEdge {
+                from: vertex_index[first],
+                to: vertex_index[last],
+                segment,
+                sources: edge_sources,
+                left_face: CurveArrangement::<I>::UNBOUNDED_FACE,
+                right_face: CurveArrangement::<I>::UNBOUNDED_FACE,
+            });
+        }
+
+        let cycles = self.trace_cycles(&chains, &vertex_index, vertices.len());
+        let parents = self.nest_cycles(&cycles, &edges, vertices.len());
+
+        let mut faces = vec![CurveArrangementFace {
+            boundary: Vec::new(),
+            holes: Vec::new(),
+            winding: vec![0; paths.len()],
+        }];
+        let mut cycle_face = vec![CurveArrangement::<I>::UNBOUNDED_FACE; cycles.len()];
+        for (index, cycle) in cycles.iter().enumerate() {
+            if !cycle.is_outer {
+                cycle_face[index] = faces.len();
+                faces.push(CurveArrangementFace {
+                    boundary: Self::public_cycle(&cycle.half_edges),
+                    holes: Vec::new(),
+                    winding: vec![0; paths.len()],
+                });
+            }
+        }
+        for (index, cycle) in cycles.iter().enumerate() {
+            if cycle.is_outer {
+                let face =
+                    parents[index].map_or(CurveArrangement::<I>::UNBOUNDED_FACE, |parent| cycle_face[parent]);
+                cycle_face[index] = face;
+                faces[face].holes.push(Self::public_cycle(&cycle.half_edges));
+            }
+        }
+        for (index, cycle) in cycles.iter().enumerate() {
+            for &half_edge in &cycle.half_edges {
+                let edge = &mut edges[half_edge >> 1];
+                if half_edge & 1 == 0 {
+                    edge.left_face = cycle_face[index];
+                } else {
+                    edge.right_face = cycle_face[index];
+                }
+            }
+        }
+
+        Self::propagate_windings(&mut faces, &edges, &deltas);
+
+        CurveArrangement {
+            vertices,
+            edges,
+            faces,
+        }
+    }
+
+    /// Joins chords through grid vertices that only continue one piece of
+    /// curve. Returns the chains and the grid vertices that stay vertices.
+    fn collect_chains(&self) -> (Vec<ChordChain>, Vec<bool>) {
+        let mut is_kept: Vec<bool> = self.incident.iter().map(|links| links.len() != 2).collect();
+        let mut is_visited = vec![false; self.links.len()];
+        let mut chains = Vec::new();
+        let mut stack: Vec<usize> = (0..self.points.len())
+            .rev()
+            .filter(|&index| is_kept[index])
+            .collect();
+        let mut next_unvisited = 0;
+
+        loop {
+            while let Some(vertex) = stack.pop() {
+                for &link in &self.incident[vertex] {
+                    if !is_visited[link] {
+                        chains.push(self.walk_chain(vertex, link, &mut is_kept, &mut is_visited, &mut stack));
+                    }
+                }
+            }
+
+            // Only closed loops of continuing chords remain.
+            while next_unvisited < self.links.len() && is_visited[next_unvisited] {
+                next_unvisited += 1;
+            }
+            let Some(link) = self.links.get(next_unvisited) else {
+                break;
+            };
+            is_kept[link.a] = true;
+            stack.push(link.a);
+        }
+
+        (chains, is_kept)
+    }
+
+    fn walk_chain(
+        &self,
+        start: usize,
+        link: usize,
+        is_kept: &mut [bool],
+        is_visited: &mut [bool],
+        stack: &mut Vec<usize>,
+    ) -> ChordChain {
+        is_visited[link] = true;
+        let (mut vertex, spans) = self.links[link].oriented_from(start);
+        let mut chain = ChordChain {
+            vertices: vec![start, vertex],
+            spans,
+        };
+        let mut current = link;
+
+        while !is_kept[vertex] {
+            let incident = &self.incident[vertex];
+            let next = if incident[0] == current {
+                incident[1]
+            } else {
+                incident[0]
+            };
+            let (end, spans) = self.links[next].oriented_from(vertex);
+            if is_visited[next] || !chain.try_extend(end, &spans) {
+                is_kept[vertex] = true;
+                stack.push(vertex);
+                break;
+            }
+            is_visited[next] = true;
+            current = next;
+            vertex = end;
+        }
+
+        chain
+    }
+
+    /// Walks every face boundary, keeping the face on the left.
+    fn trace_cycles(
+        &self,
+        chains: &[ChordChain],
+        vertex_index: &[usize],
+        vertex_count: usize,
+    ) -> Vec<FaceCycle<I>> {
+        let half_edge_count = 2 * chains.len();
+        let origin_of = |half_edge: usize| {
+            let vertices = &chains[half_edge >> 1].vertices;
+            if half_edge & 1 == 0 {
+                (vertices[0], vertices[1])
+            } else {
+                (vertices[vertices.len() - 1], vertices[vertices.len() - 2])
+            }
+        };
+
+        let mut outgoing = vec![Vec::new(); vertex_count];
+        for half_edge in 0..half_edge_count {
+            outgoing[vertex_index[origin_of(half_edge).0]].push(half_edge);
+        }
+        let mut position = vec![0; half_edge_count];
+        for list in &mut outgoing {
+            list.sort_unstable_by(|&lhs, &rhs| {
+                let (lhs_origin, lhs_next) = origin_of(lhs);
+                let (rhs_origin, rhs_next) = origin_of(rhs);
+                compare_directions(
+                    self.points[lhs_next] - self.points[lhs_origin],
+                    self.points[rhs_next] - self.points[rhs_origin],
+                )
+                .then(lhs.cmp(&rhs))
+            });
+            for (index, &half_edge) in list.iter().enumerate() {
+                position[half_edge] = index;
+            }
+        }
+
+        let mut is_visited = vec![false; half_edge_count];
+        let mut cycles = Vec::new();
+        for first in 0..half_edge_count {
+            if is_visited[first] {
+                continue;
+            }
+
+            let mut half_edges = Vec::new();
+            let mut vertices = Vec::new();
+            let mut half_edge = first;
+            while !is_visited[half_edge] {
+                is_visited[half_edge] = true;
+                half_edges.push(half_edge);
+                let chain = &chains[half_edge >> 1].vertices;
+                if half_edge & 1 == 0 {
+                    vertices.extend_from_slice(&chain[..chain.len() - 1]);
+                } else {
+                    vertices.extend(chain[1..].iter().rev());
+                }
+
+                // The next half-edge leaves the end vertex clockwise after
+                // the twin.
+                let twin = half_edge ^ 1;
+                let list = &outgoing[vertex_index[origin_of(twin).0]];
+                half_edge = list[(position[twin] + list.len() - 1) % list.len()];
+            }
+
+            let rect = IntRect::with_iter(vertices.iter().map(|&vertex| &self.points[vertex]))
+                .unwrap_or_else(|| IntRect::with_point(self.points[0]));
+            let is_outer = self.is_outer_cycle(&vertices);
+            cycles.push(FaceCycle {
+                half_edges,
+                vertices,
+                rect,
+                is_outer,
+            });
+        }
+
+        cycles
+    }
+
+    /// A cycle bounds a face from outside when its turn at the smallest
+    /// vertex is reflex or a spike.
+    fn is_outer_cycle(&self, vertices: &[usize]) -> bool {
+        let Some(&min) = vertices.iter().min() else {
+            return true;
+        };
+        let len = vertices.len();
+        let point = self.points[min];
+        (0..len).filter(|&index| vertices[index] == min).any(|index| {
+            let prev = self.points[vertices[(index + len - 1) % len]];
+            let next = self.points[vertices[(index + 1) % len]];
+            (next - point).cross_product(prev - point) <= I::Wide::ZERO
+        })
+    }
+
+    /// Returns the bounded cycle that directly contains each outer cycle.
+    fn nest_cycles(
+        &self,
+        cycles: &[FaceCycle<I>],
+        edges: &[CurveArrangementEdge<I>],
+        vertex_count: usize,
+    ) -> Vec<Option<usize>> {
+        let mut components = DisjointSet::new();
+        components.reset(vertex_count);
+        for edge in edges {
+            components.union(edge.from, edge.to);
+        }
+        let mut component_of = |cycle: &FaceCycle<I>| {
+            let edge = &edges[cycle.half_edges[0] >> 1];
+            components.find(edge.from)
+        };
+        let component_ids: Vec<usize> = cycles.iter().map(&mut component_of).collect();
+
+        cycles
+            .iter()
+            .enumerate()
+            .map(|(index, cycle)| {
+                if !cycle.is_outer {
+                    return None;
+                }
+                let probe = self.points[*cycle.vertices.iter().min()?];
+                let mut parent: Option<usize> = None;
+                for (candidate_index, candidate) in cycles.iter().enumerate() {
+                    if candidate.is_outer
+                        || component_ids[candidate_index] == component_ids[index]
+                        || !candidate.rect.contains(probe)
+                        || !self.contains_point(candidate, probe)
+                    {
+                        continue;
+                    }
+                    // Containing cycles of other components are nested, so
+                    // the inner one contains a vertex of the other.
+                    let is_inner = parent.is_none_or(|parent| {
+                        let inner = self.points[candidate.vertices[0]];
+                        self.contains_point(&cycles[parent], inner)
+                    });
+                    if is_inner {
+                        parent = Some(candidate_index);
+                    }
+                }
+                parent
+            })
+            .collect()
+    }
+
+    /// Tests a point that does not lie on the cycle against its chords.
+    fn contains_point(&self, cycle: &FaceCycle<I>, point: IntPoint<I>) -> bool {
+        let len = cycle.vertices.len();
+        let mut winding = 0;
+        for index in 0..len {
+            let a = self.points[cycle.vertices[index]];
+            let b = self.points[cycle.vertices[(index + 1) % len]];
+            let side = (b - a).cross_product(point - a);
+            if a.y <= point.y {
+                if b.y > point.y && side > I::Wide::ZERO {
+                    winding += 1;
+                }
+            } else if b.y <= point.y && side < I::Wide::ZERO {
+                winding -= 1;
+            }
+        }
+        winding != 0
+    }
+
+    fn propagate_windings(
+        faces: &mut [CurveArrangementFace],
+        edges: &[CurveArrangementEdge<I>],
+        deltas: &[Vec<(usize, i32)>],
+    ) {
+        let mut face_edges = vec![Vec::new(); faces.len()];
+        for (index, edge) in edges.iter().enumerate() {
+            face_edges[edge.left_face].push(index);
+            if edge.right_face != edge.left_face {
+                face_edges[edge.right_face].push(index);
+            }
+        }
+
+        let mut is_known = vec![false; faces.len()];
+        is_known[CurveArrangement::<I>::UNBOUNDED_FACE] = true;
+        let mut queue = VecDeque::from([CurveArrangement::<I>::UNBOUNDED_FACE]);
+        while let Some(face) = queue.pop_front() {
+            for &index in &face_edges[face] {
+                let edge = &edges[index];
+                let (next, sign) = if edge.right_face == face {
+                    (edge.left_face, 1)
+                } else {
+                    (edge.right_face, -1)
+                };
+                if is_known[next] {
+                    continue;
+                }
+                let mut winding = faces[face].winding.clone();
+                for &(path, delta) in &deltas[index] {
+                    winding[path] += sign * delta;
+                }
+                faces[next].winding = winding;
+                is_known[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    fn public_cycle(half_edges: &[usize]) -> Vec<CurveArrangementHalfEdge> {
+        half_edges
+            .iter()
+            .map(|&half_edge| CurveArrangementHalfEdge {
+                edge: half_edge >> 1,
+                is_reversed: half_edge & 1 == 1,
+            })
+            .collect()
+    }
+}
+
+/// Orders directions counterclockwise, starting from the positive x axis.
+fn compare_directions<I: CurveInt>(lhs: IntVector<I>, rhs: IntVector<I>) -> Ordering {
+    let half = |vector: IntVector<I>| {
+        let zero = I::Wide::ZERO;
+        !(vector.y > zero || (vector.y == zero && vector.x > zero))
+    };
+    half(lhs)
+        .cmp(&half(rhs))
+        .then_with(|| I::Wide::ZERO.cmp(&lhs.cross_product(rhs)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: i32, y: i32, size: i32) -> CurvePath<i32> {
+        CurvePath::new(
+            IntPoint::new(x, y),
+            vec![
+                CurveSegment::Line {
+                    to: IntPoint::new(x + size, y),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x + size, y + size),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x, y + size),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x, y),
+                },
+            ],
+        )
+    }
+
+    fn bounded_windings(arrangement: &CurveArrangement<i32>) -> Vec<Vec<i32>> {
+        let mut windings: Vec<Vec<i32>> = arrangement.faces()[1..]
+            .iter()
+            .map(|face| face.winding.clone())
+            .collect();
+        windings.sort_unstable();
+        windings
+    }
+
+    #[test]
+    fn overlapping_squares_form_three_faces() {
+        let mut builder = IntCurveArrangement::new();
+        assert_eq!(builder.add_path(square(0, 0, 20)), Ok(0));
+        assert_eq!(builder.add_path(square(10, 10, 20)), Ok(1));
+
+        let arrangement = builder.build();
+
+        assert_eq!(arrangement.vertices().len(), 10);
+        assert_eq!(arrangement.edges().len(), 12);
+        assert_eq!(arrangement.faces()[0].winding, vec![0, 0]);
+        assert!(arrangement.faces()[0].boundary.is_empty());
+        assert_eq!(arrangement.faces()[0].holes.len(), 1);
+        assert_eq!(
+            bounded_windings(&arrangement),
+            vec![vec![0, 1], vec![1, 0], vec![1, 1]]
+        );
+    }
+
+    #[test]
+    fn open_path_splits_a_curved_face() {
+        let lens = CurvePath::new(
+            IntPoint::new(100, 0),
+            vec![
+                CurveSegment::Quad {
+                    ctrl: IntPoint::new(0, 200),
+                    to: IntPoint::new(-100, 0),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(100, 0),
+                },
+            ],
+        );
+        let cut = CurvePath::new(
+            IntPoint::new(0, -50),
+            vec![CurveSegment::Line {
+                to: IntPoint::new(0, 300),
+            }],
+        );
+        let mut builder = IntCurveArrangement::new();
+        builder.add_path(lens).unwrap();
+        builder.add_path(cut).unwrap();
+
+        let arrangement = builder.build();
+
+        assert_eq!(arrangement.vertices().len(), 6);
+        assert_eq!(arrangement.edges().len(), 7);
+        let quads: Vec<_> = arrangement
+            .edges()
+            .iter()
+            .filter(|edge| matches!(edge.segment, CurveSegment::Quad { .. }))
+            .collect();
+        assert_eq!(quads.len(), 2);
+        assert!(quads.iter().all(|edge| {
+            edge.sources.len() == 1 && edge.sources[0].path == 0 && edge.sources[0].segment == 0
+        }));
+        assert!(
+            arrangement
+                .vertices()
+                .iter()
+                .any(|&vertex| vertex == IntPoint::new(0, 100))
+        );
+        assert_eq!(bounded_windings(&arrangement), vec![vec![1, 0], vec![1, 0]]);
+
+        for face in 1..arrangement.faces().len() {
+            let shape = arrangement.face_shape(face).unwrap();
+            assert!(validate_shape(&shape).is_ok());
+        }
+        assert_eq!(
+            arrangement.face_shape(CurveArrangement::<i32>::UNBOUNDED_FACE),
+            None
+        );
+    }
+
+    #[test]
+    fn nested_component_becomes_a_hole() {
+        let mut builder = IntCurveArrangement::new();
+        builder.add_path(square(0, 0, 100)).unwrap();
+        builder.add_path(square(40, 40, 20)).unwrap();
+
+        let arrangement = builder.build();
+
+        assert_eq!(arrangement.faces().len(), 3);
+        let outer = arrangement
+            .faces()
+            .iter()
+            .find(|face| face.winding == vec![1, 0])
+            .unwrap();
+        assert_eq!(outer.holes.len(), 1);
+        assert!(arrangement.faces().iter().any(|face| face.winding == vec![1, 1]));
+    }
+
+    #[test]
+    fn coincident_boundaries_share_one_edge() {
+        let mut builder = IntCurveArrangement::new();
+        builder.add_path(square(0, 0, 10)).unwrap();
+        builder.add_path(square(10, 0, 10)).unwrap();
+
+        let arrangement = builder.build();
+
+        let shared: Vec<_> = arrangement
+            .edges()
+            .iter()
+            .filter(|edge| edge.sources.len() == 2)
+            .collect();
+        assert_eq!(shared.len(), 1);
+        let sources = &shared[0].sources;
+        assert_ne!(sources[0].is_reversed, sources[1].is_reversed);
+        assert_ne!(shared[0].left_face, shared[0].right_face);
+        assert_eq!(bounded_windings(&arrangement), vec![vec![0, 1], vec![1, 0]]);
+    }
+
+    #[test]
+    fn add_path_rejects_an_empty_path() {
+        let mut builder = IntCurveArrangement::<i32>::new();
+        let path = CurvePath::new(IntPoint::new(0, 0), Vec::new());
+
+        assert_eq!(
+            builder.add_path(path),
+            Err(CurveInputError::EmptyContour { contour: 0 })
+        );
+        assert!(builder.is_empty());
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn generated_shapes_form_consistent_arrangements() {
+        use crate::generate::CurveGeneratorOptions;
+        use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+
+        let options = CurveGeneratorOptions::default()
+            .with_tangency_percent(30)
+            .with_coincidence_percent(50);
+        let shapes = (
+            options.int_shape_strategy::<i32>(),
+            options.int_shape_strategy::<i32>(),
+        );
+        let rng = TestRng::deterministic_rng(RngAlgorithm::ChaCha);
+        let mut runner = TestRunner::new_with_rng(Config::with_cases(40), rng);
+        runner
+            .run(&shapes, |(subject, clip)| {
+                let mut builder = IntCurveArrangement::new();
+                builder.add_shape(subject).unwrap();
+                builder.add_shape(clip).unwrap();
+                let arrangement = builder.build();
+
+                let mut uses = vec![0; 2 * arrangement.edges().len()];
+                for face in arrangement.faces() {
+                    for half_edge in face.boundary.iter().chain(face.holes.iter().flatten()) {
+                        uses[2 * half_edge.edge + usize::from(half_edge.is_reversed)] += 1;
+                    }
+                }
+                assert!(uses.iter().all(|&count| count == 1));
+
+                for edge in arrangement.edges() {
+                    let mut winding = arrangement.faces()[edge.right_face].winding.clone();
+                    for source in &edge.sources {
+                        winding[source.path] += if source.is_reversed { -1 } else { 1 };
+                    }
+                    assert_eq!(winding, arrangement.faces()[edge.left_face].winding);
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -1,6 +1,8 @@
+use crate::collections::interval_tree::IntervalTree;
 use crate::int::CurveInt;
 use crate::int::bool::edge::CurveEdge;
 use alloc::vec::Vec;
+use i_key_sort::sort::one_key::OneKeySort;
 use i_key_sort::sort::one_key_cmp::OneKeyAndCmpSort;
 use i_overlay::i_float::int::rect::IntRect;
 
@@ -13,7 +15,11 @@ pub(super) struct CurveEdgeBounds<I: CurveInt> {
 pub(super) struct CurveBoundsBuffer<I: CurveInt> {
     pub(super) bounds: Vec<CurveEdgeBounds<I>>,
     bounds_buffer: Vec<CurveEdgeBounds<I>>,
-    pub(super) active: Vec<CurveEdgeBounds<I>>,
+    y_values: Vec<I>,
+    y_ranges: Vec<[usize; 2]>,
+    exits: Vec<usize>,
+    exits_buffer: Vec<usize>,
+    active: IntervalTree,
 }
 
 impl<I: CurveInt + i_key_sort::sort::key::SortKey> CurveBoundsBuffer<I> {
@@ -21,18 +27,28 @@ impl<I: CurveInt + i_key_sort::sort::key::SortKey> CurveBoundsBuffer<I> {
         Self {
             bounds: Vec::new(),
             bounds_buffer: Vec::new(),
-            active: Vec::new(),
+            y_values: Vec::new(),
+            y_ranges: Vec::new(),
+            exits: Vec::new(),
+            exits_buffer: Vec::new(),
+            active: IntervalTree::new(),
         }
     }
 
     pub(super) fn build(&mut self, edges: &[CurveEdge<I>]) {
+        self.build_rects(edges.iter().map(|edge| {
+            let hull = edge.curve.convex_hull();
+            IntRect::with_points(hull.as_slice()).unwrap()
+        }));
+    }
+
+    /// Indexes arbitrary bounds; overlap pairs report positions in `rects`.
+    pub(super) fn build_rects(&mut self, rects: impl ExactSizeIterator<Item = IntRect<I>>) {
         self.bounds.clear();
         self.bounds
-            .reserve(edges.len().saturating_sub(self.bounds.capacity()));
+            .reserve(rects.len().saturating_sub(self.bounds.capacity()));
 
-        for (edge_index, edge) in edges.iter().enumerate() {
-            let hull = edge.curve.convex_hull();
-            let rect = IntRect::with_points(hull.as_slice()).unwrap();
+        for (edge_index, rect) in rects.enumerate() {
             self.bounds.push(CurveEdgeBounds { edge_index, rect });
         }
 
@@ -43,4 +59,141 @@ impl<I: CurveInt + i_key_sort::sort::key::SortKey> CurveBoundsBuffer<I> {
             |first, second| first.rect.min_y.cmp(&second.rect.min_y),
         );
     }
+
+    /// Visits every pair of edges whose bounds intersect, borders included.
+    ///
+    /// The sweep advances over `min_x` and keeps the bounds crossing the
+    /// sweep line in an interval tree over ranked `y` values. Leaving bounds
+    /// are retired in `max_x` order, so candidate generation costs
+    /// `O((n + k) log n)` for `n` edges and `k` reported pairs instead of
+    /// testing every bound that shares an `x` range. The first index of each
+    /// pair belongs to the bound that entered the sweep earlier.
+    pub(super) fn for_each_overlap(&mut self, mut visit: impl FnMut(usize, usize)) {
+        let count = self.bounds.len();
+        if count < 2 {
+            return;
+        }
+
+        self.y_values.clear();
+        for item in &self.bounds {
+            self.y_values.push(item.rect.min_y);
+            self.y_values.push(item.rect.max_y);
+        }
+        self.y_values.sort_unstable();
+        self.y_values.dedup();
+
+        self.y_ranges.clear();
+        for item in &self.bounds {
+            let min = self.y_values.binary_search(&item.rect.min_y).unwrap();
+            let max = self.y_values.binary_search(&item.rect.max_y).unwrap();
+            self.y_ranges.push([min, max]);
+        }
+
+        self.exits.clear();
+        self.exits.extend(0..count);
+        let bounds = &self.bounds;
+        self.exits
+            .sort_by_one_key_and_buffer(false, &mut self.exits_buffer, |&index| bounds[index].rect.max_x);
+
+        self.active.reset(self.y_values.len(), count);
+        let mut exit_cursor = 0;
+
+        for index in 0..count {
+            let current = self.bounds[index];
+
+            while exit_cursor < count {
+                let exit = self.exits[exit_cursor];
+                if self.bounds[exit].rect.max_x >= current.rect.min_x {
+                    break;
+                }
+                // A bound ending before `current.min_x` also starts before
+                // it, so it was inserted by an earlier sweep step.
+                debug_assert!(exit < index);
+                self.active.remove(exit, self.y_ranges[exit]);
+                exit_cursor += 1;
+            }
+
+            let bounds = &self.bounds;
+            self.active.for_each_overlap(self.y_ranges[index], |other| {
+                visit(bounds[other].edge_index, current.edge_index);
+            });
+            self.active.insert(index, self.y_ranges[index]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::bool::source::CurveId;
+    use crate::kernel::int::curve::line::LineSegment;
+    use crate::kernel::int::curve::segment::Segment;
+    use alloc::vec;
+
+    fn line(a: [i32; 2], b: [i32; 2]) -> CurveEdge<i32> {
+        CurveEdge::full(
+            Segment::Line(LineSegment {
+                control_points: [a.into(), b.into()],
+            }),
+            CurveId(0),
+        )
+    }
+
+    fn sweep_pairs(edges: &[CurveEdge<i32>]) -> Vec<(usize, usize)> {
+        let mut bounds = CurveBoundsBuffer::new();
+        bounds.build(edges);
+        let mut pairs = Vec::new();
+        bounds.for_each_overlap(|first, second| pairs.push((first.min(second), first.max(second))));
+        pairs.sort_unstable();
+        pairs
+    }
+
+    fn brute_force_pairs(edges: &[CurveEdge<i32>]) -> Vec<(usize, usize)> {
+        let rects: Vec<_> = edges
+            .iter()
+            .map(|edge| IntRect::with_points(edge.curve.convex_hull().as_slice()).unwrap())
+            .collect();
+        let mut pairs = Vec::new();
+        for first in 0..rects.len() {
+            for second in first + 1..rects.len() {
+                if rects[first].is_intersect_border_include(&rects[second]) {
+                    pairs.push((first, second));
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn reports_touching_and_overlapping_bounds_once() {
+        let edges = vec![
+            line([0, 0], [10, 10]),
+            line([10, 10], [20, 0]),
+            line([0, 20], [30, 20]),
+            line([5, 30], [6, 40]),
+        ];
+
+        assert_eq!(sweep_pairs(&edges), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn matches_brute_force_candidate_pairs() {
+        let mut state = 0x2545_f491_u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state % 200) as i32
+        };
+
+        let edges: Vec<_> = (0..300)
+            .map(|_| {
+                let a = [next(), next()];
+                let b = [a[0] + next() / 8, a[1] + next() / 8 - 12];
+                line(a, b)
+            })
+            .collect();
+
+        assert_eq!(sweep_pairs(&edges), brute_force_pairs(&edges));
+    }
 }
@@ -0,0 +1,501 @@
+use crate::collections::interval_tree::IntervalTree;
+use crate::int::CurveInt;
+use crate::int::bool::overlay::CurveOverlayOptions;
+use crate::int::curve::path::CurvePath;
+use crate::int::curve::segment::CurveSegment;
+use crate::int::curve::shape::CurveShape;
+use crate::kernel::int::cross::intersector::{SegmentIntersectionBuffer, SegmentIntersector, SplitOptions};
+use crate::kernel::int::curve::segment::Segment;
+use crate::kernel::int::normalization::canonical::{
+    ParametricSegment, PushCanonicalSimpleParametricSegment, PushSimpleSegment,
+};
+use alloc::vec::Vec;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_float::int::rect::IntRect;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Removes micro segments and redundant vertices from result contours as
+/// requested by `options`.
+///
+/// A segment whose control points all lie closer than
+/// [`min_segment_length`](CurveOverlayOptions::min_segment_length) to its
+/// start is removed, and the vertex it shared with a neighbour moves to its
+/// other end. Line neighbours are preferred; arcs keep their authoritative
+/// endpoints and never absorb a segment. A removal is skipped when the moved
+/// neighbour would touch any result segment away from its own endpoints, or
+/// when the contour would no longer bound an area, so contours stay closed
+/// and the nesting of the result does not change.
+///
+/// With [`merge_collinear_lines`](CurveOverlayOptions::merge_collinear_lines),
+/// consecutive lines that continue in the same direction are then joined.
+/// That only removes vertices and leaves the covered area unchanged.
+pub(crate) fn clean_up_segments<I: CurveInt>(shapes: &mut [CurveShape<I>], options: &CurveOverlayOptions) {
+    if options.min_segment_length == 0 && !options.merge_collinear_lines {
+        return;
+    }
+
+    let mut cleaner = SegmentCleaner {
+        min_length: f64::from(options.min_segment_length),
+        pieces: Vec::new(),
+        segment_pieces: Vec::new(),
+        x_values: Vec::new(),
+        index: IntervalTree::new(),
+        candidates: Vec::new(),
+        ids: Vec::new(),
+        moved: Vec::new(),
+        split_pieces: Vec::new(),
+        simple_curves: Vec::new(),
+        canonical_curves: Vec::new(),
+        buffer: SegmentIntersectionBuffer::default(),
+    };
+    if options.min_segment_length > 0 {
+        cleaner.build_index(shapes);
+    }
+
+    let mut first_id = 0;
+    for shape in shapes.iter_mut() {
+        for path in shape.contours.iter_mut() {
+            let len = path.segments.len();
+            if options.min_segment_length > 0 {
+                cleaner.absorb_short_segments(path, first_id);
+            }
+            // Merging keeps the covered points, so the index stays valid.
+            if options.merge_collinear_lines {
+                merge_collinear_lines(path);
+            }
+            first_id += len;
+        }
+    }
+}
+
+/// A simple piece of a result segment, owned by the segment with id `owner`.
+struct IndexedPiece<I: CurveInt> {
+    curve: Segment<I>,
+    rect: IntRect<I>,
+    owner: usize,
+}
+
+struct SegmentCleaner<I: CurveInt> {
+    min_length: f64,
+    /// Pieces of every result segment; replaced segments leave theirs behind
+    /// but drop out of `index`.
+    pieces: Vec<IndexedPiece<I>>,
+    /// The range in `pieces` of each segment id.
+    segment_pieces: Vec<[usize; 2]>,
+    /// Ranked `x` bounds of the initial pieces.
+    x_values: Vec<I>,
+    /// Live pieces by the ranks their `x` bounds cover.
+    index: IntervalTree,
+    candidates: Vec<usize>,
+    /// Segment ids of the contour being cleaned.
+    ids: Vec<usize>,
+    moved: Vec<(Segment<I>, IntRect<I>)>,
+    split_pieces: Vec<Segment<I>>,
+    simple_curves: Vec<Segment<I>>,
+    canonical_curves: Vec<ParametricSegment<I>>,
+    buffer: SegmentIntersectionBuffer<I>,
+}
+
+impl<I: CurveInt> SegmentCleaner<I> {
+    /// Splits every result segment once and indexes the pieces, numbering
+    /// segments in contour order.
+    fn build_index(&mut self, shapes: &[CurveShape<I>]) {
+        for path in shapes.iter().flat_map(|shape| &shape.contours) {
+            for (index, segment) in path.segments.iter().enumerate() {
+                self.push_segment(start_of(path, index), segment);
+            }
+        }
+
+        self.x_values.clear();
+        for piece in &self.pieces {
+            self.x_values.push(piece.rect.min_x);
+            self.x_values.push(piece.rect.max_x);
+        }
+        self.x_values.sort_unstable();
+        self.x_values.dedup();
+
+        self.index.reset(self.x_values.len(), self.pieces.len());
+        for item in 0..self.pieces.len() {
+            let range = self.x_range(&self.pieces[item].rect);
+            self.index.insert(item, range);
+        }
+    }
+
+    /// Appends the pieces of a segment under a new id and returns that id.
+    fn push_segment(&mut self, start: IntPoint<I>, segment: &CurveSegment<I>) -> usize {
+        self.split(start, segment);
+        let owner = self.segment_pieces.len();
+        let first = self.pieces.len();
+        self.pieces
+            .extend(self.split_pieces.iter().map(|&curve| IndexedPiece {
+                curve,
+                rect: IntRect::with_points(curve.convex_hull().as_slice()).unwrap(),
+                owner,
+            }));
+        self.segment_pieces.push([first, self.pieces.len()]);
+        owner
+    }
+
+    /// Returns ranks that cover the `x` extent of `rect`.
+    ///
+    /// Bounds between ranked values widen to the neighbouring ranks, so any
+    /// two overlapping extents map to overlapping rank ranges.
+    fn x_range(&self, rect: &IntRect<I>) -> [usize; 2] {
+        let last = self.x_values.len() - 1;
+        let min = self
+            .x_values
+            .partition_point(|&x| x <= rect.min_x)
+            .saturating_sub(1);
+        let max = self.x_values.partition_point(|&x| x < rect.max_x).min(last);
+        [min, max]
+    }
+
+    fn absorb_short_segments(&mut self, path: &mut CurvePath<I>, first_id: usize) {
+        self.ids.clear();
+        self.ids.extend(first_id..first_id + path.segments.len());
+        let mut index = 0;
+        while index < path.segments.len() {
+            if self.is_short(path, index) && self.try_absorb(path, index) {
+                // The next segment now sits at `index`.
+                continue;
+            }
+            index += 1;
+        }
+    }
+
+    fn is_short(&self, path: &CurvePath<I>, index: usize) -> bool {
+        let start = start_of(path, index);
+        let is_near = |point: &IntPoint<I>| {
+            let dx = point.x.to_f64() - start.x.to_f64();
+            let dy = point.y.to_f64() - start.y.to_f64();
+            dx * dx + dy * dy < self.min_length * self.min_length
+        };
+        match &path.segments[index] {
+            CurveSegment::Line { to } => is_near(to),
+            CurveSegment::Quad { ctrl, to } => is_near(ctrl) && is_near(to),
+            CurveSegment::Cubic { ctrl0, ctrl1, to } => is_near(ctrl0) && is_near(ctrl1) && is_near(to),
+            CurveSegment::Arc { arc } => arc.control_points.iter().all(is_near),
+        }
+    }
+
+    /// Removes the segment at `index` into one of its neighbours if the
+    /// result stays free of new contacts.
+    fn try_absorb(&mut self, path: &mut CurvePath<I>, index: usize) -> bool {
+        let len = path.segments.len();
+        let next = (index + 1) % len;
+        let previous = (index + len - 1) % len;
+
+        // Moving a line endpoint distorts less than moving a curve endpoint.
+        let mut neighbours = [(next, true), (previous, false)];
+        if !is_line(&path.segments[next]) && is_line(&path.segments[previous]) {
+            neighbours.swap(0, 1);
+        }
+
+        for (neighbour, is_next) in neighbours {
+            if matches!(path.segments[neighbour], CurveSegment::Arc { .. }) {
+                continue;
+            }
+            let (trial, moved) = if is_next {
+                Self::absorb_into_next(path, index)
+            } else {
+                Self::absorb_into_previous(path, index)
+            };
+            let replaced = [self.ids[index], self.ids[neighbour]];
+            if bounds_area(&trial) && self.is_untangled(&trial, moved, replaced) {
+                self.replace_segments(&trial, index, moved, replaced);
+                *path = trial;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// The next segment starts where the removed segment started.
+    fn absorb_into_next(path: &CurvePath<I>, index: usize) -> (CurvePath<I>, usize) {
+        let mut trial = path.clone();
+        let start = start_of(path, index);
+        trial.segments.remove(index);
+        if index == trial.segments.len() {
+            trial.start = start;
+            (trial, 0)
+        } else {
+            (trial, index)
+        }
+    }
+
+    /// The previous segment ends where the removed segment ended.
+    fn absorb_into_previous(path: &CurvePath<I>, index: usize) -> (CurvePath<I>, usize) {
+        let mut trial = path.clone();
+        let end = path.segments[index].end_point();
+        let previous = (index + path.segments.len() - 1) % path.segments.len();
+        if let CurveSegment::Line { to } | CurveSegment::Quad { to, .. } | CurveSegment::Cubic { to, .. } =
+            &mut trial.segments[previous]
+        {
+            *to = end;
+        }
+        trial.segments.remove(index);
+        if index == 0 {
+            trial.start = end;
+            let last = trial.segments.len() - 1;
+            (trial, last)
+        } else {
+            (trial, index - 1)
+        }
+    }
+
+    /// Returns `true` if the segment at `moved` meets no other result
+    /// segment except at its endpoints.
+    ///
+    /// The segments in `replaced` are the ones the moved segment stands in
+    /// for, so their pieces are skipped.
+    fn is_untangled(&mut self, path: &CurvePath<I>, moved: usize, replaced: [usize; 2]) -> bool {
+        let start = start_of(path, moved);
+        let end = path.segments[moved].end_point();
+        self.split(start, &path.segments[moved]);
+        self.moved.clear();
+        self.moved.extend(self.split_pieces.iter().map(|&piece| {
+            let rect = IntRect::with_points(piece.convex_hull().as_slice()).unwrap();
+            (piece, rect)
+        }));
+        let Some(bounds) = self
+            .moved
+            .iter()
+            .map(|&(_, rect)| rect)
+            .reduce(|a, b| IntRect::with_rects(&a, &b))
+        else {
+            return true;
+        };
+
+        let range = self.x_range(&bounds);
+        self.candidates.clear();
+        let pieces = &self.pieces;
+        let candidates = &mut self.candidates;
+        self.index.for_each_overlap(range, |item| {
+            let piece = &pieces[item];
+            if !replaced.contains(&piece.owner) && bounds.is_intersect_border_include(&piece.rect) {
+                candidates.push(item);
+            }
+        });
+
+        for &item in &self.candidates {
+            let piece = &self.pieces[item];
+            for &(moved_piece, moved_rect) in &self.moved {
+                if !moved_rect.is_intersect_border_include(&piece.rect) {
+                    continue;
+                }
+                SegmentIntersector::new(moved_piece, piece.curve, SplitOptions::default())
+                    .intersect_with_buffer(&mut self.buffer);
+                if self.buffer.is_overlap()
+                    || self
+                        .buffer
+                        .contacts()
+                        .iter()
+                        .any(|contact| contact.point != start && contact.point != end)
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Swaps the pieces of the `replaced` segments in the index for those of
+    /// the segment at `moved` after the segment at `removed` was absorbed.
+    fn replace_segments(&mut self, path: &CurvePath<I>, removed: usize, moved: usize, replaced: [usize; 2]) {
+        for id in replaced {
+            let [first, last] = self.segment_pieces[id];
+            for item in first..last {
+                let range = self.x_range(&self.pieces[item].rect);
+                self.index.remove(item, range);
+            }
+        }
+
+        let id = self.push_segment(start_of(path, moved), &path.segments[moved]);
+        let [first, last] = self.segment_pieces[id];
+        self.index.extend_items(last);
+        for item in first..last {
+            let range = self.x_range(&self.pieces[item].rect);
+            self.index.insert(item, range);
+        }
+
+        self.ids.remove(removed);
+        self.ids[moved] = id;
+    }
+
+    /// Replaces `split_pieces` with the canonical simple pieces of a segment.
+    fn split(&mut self, start: IntPoint<I>, segment: &CurveSegment<I>) {
+        let (curve, _) = segment.clone().into_kernel_segment(start);
+        self.split_pieces.clear();
+        self.simple_curves.push_simple(curve);
+        for simple_curve in self.simple_curves.drain(..) {
+            self.canonical_curves
+                .push_canonical_simple_parametric(simple_curve);
+        }
+        self.split_pieces
+            .extend(self.canonical_curves.drain(..).map(|canonical| canonical.curve));
+    }
+}
+
+/// Joins consecutive lines that continue in the same direction.
+fn merge_collinear_lines<I: CurveInt>(path: &mut CurvePath<I>) {
+    let mut index = 0;
+    while index < path.segments.len() {
+        let len = path.segments.len();
+        let next = (index + 1) % len;
+        let is_removable = len > 3 || (len == 3 && !path.segments.iter().all(is_line));
+        if !is_removable {
+            return;
+        }
+        let (&CurveSegment::Line { to: middle }, &CurveSegment::Line { to: end }) =
+            (&path.segments[index], &path.segments[next])
+        else {
+            index += 1;
+            continue;
+        };
+        let start = start_of(path, index);
+        let (incoming, outgoing) = (middle - start, end - middle);
+        if incoming.cross_product(outgoing) != I::Wide::ZERO
+            || incoming.dot_product(outgoing) <= I::Wide::ZERO
+        {
+            index += 1;
+            continue;
+        }
+
+        if next == 0 {
+            // The contour start is the redundant vertex.
+            path.start = start;
+            path.segments.pop();
+        } else {
+            path.segments[index] = CurveSegment::Line { to: end };
+            path.segments.remove(next);
+        }
+    }
+}
+
+/// Returns `true` if a closed contour can still enclose an area.
+fn bounds_area<I: CurveInt>(path: &CurvePath<I>) -> bool {
+    path.segments.len() >= 3 || (path.segments.len() == 2 && !path.segments.iter().all(is_line))
+}
+
+#[inline]
+fn is_line<I: CurveInt>(segment: &CurveSegment<I>) -> bool {
+    matches!(segment, CurveSegment::Line { .. })
+}
+
+#[inline]
+fn start_of<I: CurveInt>(path: &CurvePath<I>, index: usize) -> IntPoint<I> {
+    match index {
+        0 => path.start,
+        _ => path.segments[index - 1].end_point(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn polygon(points: &[[i32; 2]]) -> CurvePath<i32> {
+        let start = IntPoint::new(points[0][0], points[0][1]);
+        let segments = points[1..]
+            .iter()
+            .chain(core::iter::once(&points[0]))
+            .map(|&[x, y]| CurveSegment::Line {
+                to: IntPoint::new(x, y),
+            })
+            .collect();
+        CurvePath::new(start, segments)
+    }
+
+    fn vertices(path: &CurvePath<i32>) -> Vec<[i32; 2]> {
+        core::iter::once(path.start)
+            .chain(path.segments.iter().map(CurveSegment::end_point))
+            .map(|point| [point.x, point.y])
+            .collect()
+    }
+
+    #[test]
+    fn short_segments_move_their_vertex_into_a_neighbour() {
+        let mut shapes = vec![CurveShape::new(vec![polygon(&[
+            [0, 0],
+            [100, 0],
+            [101, 1],
+            [100, 100],
+            [0, 100],
+        ])])];
+        let options = CurveOverlayOptions::default().with_min_segment_length(3);
+
+        clean_up_segments(&mut shapes, &options);
+
+        assert_eq!(
+            vertices(&shapes[0].contours[0]),
+            vec![[0, 0], [100, 0], [100, 100], [0, 100], [0, 0]]
+        );
+    }
+
+    #[test]
+    fn consecutive_short_segments_are_absorbed_in_every_shape() {
+        let mut shapes = vec![
+            CurveShape::new(vec![polygon(&[
+                [0, 0],
+                [100, 0],
+                [101, 1],
+                [102, 2],
+                [100, 100],
+                [0, 100],
+            ])]),
+            CurveShape::new(vec![polygon(&[
+                [200, 0],
+                [300, 0],
+                [301, 1],
+                [300, 100],
+                [200, 100],
+            ])]),
+        ];
+        let options = CurveOverlayOptions::default().with_min_segment_length(3);
+
+        clean_up_segments(&mut shapes, &options);
+
+        assert_eq!(
+            vertices(&shapes[0].contours[0]),
+            vec![[0, 0], [100, 0], [100, 100], [0, 100], [0, 0]]
+        );
+        assert_eq!(
+            vertices(&shapes[1].contours[0]),
+            vec![[200, 0], [300, 0], [300, 100], [200, 100], [200, 0]]
+        );
+    }
+
+    #[test]
+    fn collinear_lines_merge_across_the_contour_start() {
+        let mut shapes = vec![CurveShape::new(vec![polygon(&[
+            [50, 0],
+            [100, 0],
+            [100, 50],
+            [100, 100],
+            [0, 100],
+            [0, 0],
+        ])])];
+        let options = CurveOverlayOptions::default().with_merge_collinear_lines(true);
+
+        clean_up_segments(&mut shapes, &options);
+
+        assert_eq!(
+            vertices(&shapes[0].contours[0]),
+            vec![[0, 0], [100, 0], [100, 100], [0, 100], [0, 0]]
+        );
+    }
+
+    #[test]
+    fn short_segment_stays_when_moving_it_would_touch_a_hole() {
+        // Either way of absorbing the short edge would cut the hole.
+        let outer = polygon(&[[0, 0], [10, 0], [10, 2], [0, 10]]);
+        let hole = polygon(&[[8, 1], [9, 2], [9, 1]]);
+        let mut shapes = vec![CurveShape::new(vec![outer.clone(), hole.clone()])];
+        let options = CurveOverlayOptions::default().with_min_segment_length(3);
+
+        clean_up_segments(&mut shapes, &options);
+
+        assert_eq!(shapes[0].contours, vec![outer, hole]);
+    }
+}
@@ -138,6 +138,10 @@ pub(crate) struct CurveEdgeDataStore {
 }
 
 impl CurveEdgeDataStore {
+    pub(crate) fn clear(&mut self) {
+        self.sets.clear();
+    }
+
     pub(crate) fn spans(&self, data: CurveEdgeData, buffer: &mut Vec<CurveSourceSpan>) {
         buffer.clear();
         self.append(data, buffer);
@@ -0,0 +1,213 @@
+use crate::int::CurveInt;
+use crate::int::bool::planarize::CurveContact;
+use crate::int::bool::source::CurveSource;
+use crate::kernel::int::curve::param::SegmentParam;
+use crate::kernel::int::math::interval::Interval;
+use alloc::vec::Vec;
+use i_overlay::core::overlay::ShapeType;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Certification outcome of one curve intersection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CurveIntersectionStatus {
+    /// Exactly one crossing of the two curves lies within the reported
+    /// parameter intervals, within the grid tolerance of the split point.
+    Certified,
+    /// The contact lies at an end of one curve, so whether the curves meet
+    /// depends on rounding below the grid.
+    Endpoint,
+    /// No unique crossing could be proven within the grid tolerance. This
+    /// covers tangencies, overlaps, clustered crossings, and split points
+    /// snapped farther from the exact crossing than the tolerance.
+    Uncertified,
+}
+
+/// One of the two curves of a [`CurveIntersection`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CurveIntersectionSide<I: CurveInt> {
+    /// Operand role of the curve.
+    pub shape_type: ShapeType,
+    /// Zero-based index of the contour among all contours added to the
+    /// overlay, in the order they were added.
+    pub contour: usize,
+    /// Zero-based segment index within the contour.
+    pub segment: usize,
+    /// Zero-based simple piece of the segment. Segments with cusps or
+    /// self-intersections enter the overlay as several simple pieces; other
+    /// segments consist of piece `0`.
+    pub piece: usize,
+    /// Start of the parameter interval on the piece.
+    pub start: SegmentParam<I>,
+    /// End of the parameter interval on the piece.
+    pub end: SegmentParam<I>,
+}
+
+/// Contact found by the cross kernel between two input curves.
+///
+/// A [`Certified`](CurveIntersectionStatus::Certified) intersection has
+/// parameter intervals rounded outward to the parameter grid that are
+/// proven to enclose the crossing. Other intersections report the
+/// parameters the overlay split at as empty intervals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CurveIntersection<I: CurveInt> {
+    /// Grid point at which the overlay splits both curves.
+    pub point: IntPoint<I>,
+    /// The two intersecting curves.
+    pub curves: [CurveIntersectionSide<I>; 2],
+    /// Certification outcome.
+    pub status: CurveIntersectionStatus,
+}
+
+/// Diagnostics of one overlay run.
+///
+/// Intersections are gathered only when
+/// [`CurveOverlayOptions::certified_intersections`](crate::int::CurveOverlayOptions::certified_intersections)
+/// is set.
+#[derive(Debug, Clone)]
+pub struct CurveOverlayDiagnostics<I: CurveInt> {
+    intersections: Vec<CurveIntersection<I>>,
+}
+
+impl<I: CurveInt> CurveOverlayDiagnostics<I> {
+    /// Returns every intersection that split an input curve, ordered by
+    /// the input positions of both curves.
+    #[inline]
+    pub fn intersections(&self) -> &[CurveIntersection<I>] {
+        &self.intersections
+    }
+
+    /// Returns whether every reported intersection is certified.
+    #[inline]
+    pub fn is_certified(&self) -> bool {
+        self.intersections
+            .iter()
+            .all(|intersection| intersection.status == CurveIntersectionStatus::Certified)
+    }
+
+    /// Returns the intersections that could not be certified.
+    #[inline]
+    pub fn uncertified(&self) -> impl Iterator<Item = &CurveIntersection<I>> {
+        self.intersections
+            .iter()
+            .filter(|intersection| intersection.status != CurveIntersectionStatus::Certified)
+    }
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.intersections.clear();
+    }
+
+    /// Certifies recorded contacts on their source curves.
+    pub(crate) fn certify(
+        &mut self,
+        contacts: &[CurveContact<I>],
+        sources: &[CurveSource<I>],
+        tolerance: f64,
+    ) {
+        self.intersections.clear();
+        self.intersections.reserve(contacts.len());
+
+        let denominator = SegmentParam::<I>::DENOMINATOR.to_f64();
+        for contact in contacts {
+            let [source0, source1] = contact.curves.map(|id| &sources[id.0]);
+            let [t0, t1] = contact.params.map(|param| param.value().to_f64() / denominator);
+            let enclosure = source0
+                .curve
+                .certify_contact(&source1.curve, t0, t1, contact.point, tolerance)
+                .filter(|enclosure| enclosure.iter().all(|interval| interval.is_within(0.0, 1.0)));
+
+            let (status, intervals) = match enclosure {
+                Some(enclosure) => (
+                    CurveIntersectionStatus::Certified,
+                    enclosure.map(|interval| grid_interval::<I>(interval, denominator)),
+                ),
+                None => {
+                    let status = if contact.params.iter().any(|&param| is_end(param)) {
+                        CurveIntersectionStatus::Endpoint
+                    } else {
+                        CurveIntersectionStatus::Uncertified
+                    };
+                    (status, contact.params.map(|param| [param, param]))
+                }
+            };
+
+            let side = |source: &CurveSource<I>, [start, end]: [SegmentParam<I>; 2]| CurveIntersectionSide {
+                shape_type: source.shape_type,
+                contour: source.origin.contour,
+                segment: source.origin.segment,
+                piece: source.origin.piece,
+                start,
+                end,
+            };
+            let mut curves = [side(source0, intervals[0]), side(source1, intervals[1])];
+            if position(&curves[1]) < position(&curves[0]) {
+                curves.swap(0, 1);
+            }
+
+            self.intersections.push(CurveIntersection {
+                point: contact.point,
+                curves,
+                status,
+            });
+        }
+
+        // Neighbouring edges of one curve can report the same contact.
+        self.intersections.sort_by(|a, b| {
+            (position(&a.curves[0]), position(&a.curves[1]), a.point).cmp(&(
+                position(&b.curves[0]),
+                position(&b.curves[1]),
+                b.point,
+            ))
+        });
+        self.intersections.dedup_by(|a, b| {
+            a.point == b.point
+                && position(&a.curves[0]) == position(&b.curves[0])
+                && position(&a.curves[1]) == position(&b.curves[1])
+        });
+    }
+}
+
+impl<I: CurveInt> Default for CurveOverlayDiagnostics<I> {
+    fn default() -> Self {
+        Self {
+            intersections: Vec::new(),
+        }
+    }
+}
+
+#[inline]
+fn position<I: CurveInt>(side: &CurveIntersectionSide<I>) -> (usize, usize, usize) {
+    (side.contour, side.segment, side.piece)
+}
+
+#[inline]
+fn is_end<I: CurveInt>(param: SegmentParam<I>) -> bool {
+    param.value() == I::Wide::ZERO || param.value() == SegmentParam::<I>::DENOMINATOR
+}
+
+/// Rounds a unit interval outward to the parameter grid.
+fn grid_interval<I: CurveInt>(interval: Interval, denominator: f64) -> [SegmentParam<I>; 2] {
+    let lo = interval.lo * denominator;
+    let hi = interval.hi * denominator;
+
+    // `round(x - 1)` is `round(x) - 1`, which turns rounding into floor
+    // and ceiling without `std`.
+    let rounded_lo = I::from_rounded_float(lo);
+    let start = if rounded_lo.to_f64() > lo {
+        I::from_rounded_float(lo - 1.0)
+    } else {
+        rounded_lo
+    };
+    let rounded_hi = I::from_rounded_float(hi);
+    let end = if rounded_hi.to_f64() < hi {
+        I::from_rounded_float(hi + 1.0)
+    } else {
+        rounded_hi
+    };
+
+    [SegmentParam::new(start), SegmentParam::new(end)]
+}
@@ -0,0 +1,284 @@
+use crate::int::CurveInt;
+use crate::int::bool::hierarchy::CurveContourTree;
+use crate::int::bool::overlay::CurveOverlayOptions;
+use crate::int::bool::verify::{flatten_path, length, signed_area};
+use crate::int::curve::path::CurvePath;
+use crate::int::curve::shape::CurveShape;
+use alloc::vec::Vec;
+use i_overlay::i_shape::int::shape::IntShapes;
+
+/// Largest distance between a curve and the polyline its area and perimeter
+/// are measured on.
+const FLATTENING_TOLERANCE: f64 = 0.5;
+
+/// Drops result contours below the minimum area or area-to-perimeter ratio
+/// of `options`.
+///
+/// Contours are measured on their own, so a hole is compared by the area it
+/// removes rather than by the area of its shape. A failing outer contour
+/// drops its whole shape together with its holes; a failing hole is removed
+/// from its shape, which fills it, and every island nested inside it is
+/// dropped as the filled area now covers it. Islands inside the hole of a
+/// dropped shape are separate shapes and are measured independently.
+///
+/// When `nesting` holds the polygons the shapes were traced from, islands
+/// are found from it and the same contours are removed from it. Otherwise
+/// they are found from the flattened contours.
+pub(crate) fn drop_small_contours<I: CurveInt>(
+    shapes: &mut Vec<CurveShape<I>>,
+    mut nesting: Option<&mut IntShapes<I>>,
+    options: &CurveOverlayOptions,
+) {
+    if options.min_contour_area == 0 && options.min_area_perimeter_ratio == 0 {
+        return;
+    }
+
+    let min_area = options.min_contour_area as f64;
+    let min_ratio = f64::from(options.min_area_perimeter_ratio);
+    let mut points = Vec::new();
+    let mut is_kept = |path: &CurvePath<I>| {
+        flatten_path(path, FLATTENING_TOLERANCE, &mut points);
+        let area = signed_area(&points).abs();
+        area >= min_area && area >= min_ratio * length(&points)
+    };
+
+    // Contour flags stay empty for shapes that keep every contour.
+    let mut kept_shapes = Vec::with_capacity(shapes.len());
+    let mut kept_contours = Vec::with_capacity(shapes.len());
+    for shape in shapes.iter() {
+        let is_outer_kept = shape.contours.first().is_some_and(&mut is_kept);
+        kept_shapes.push(is_outer_kept);
+        let mut flags = Vec::new();
+        if is_outer_kept {
+            flags.extend(core::iter::once(true).chain(shape.contours[1..].iter().map(&mut is_kept)));
+            if flags.iter().all(|&is_kept| is_kept) {
+                flags.clear();
+            }
+        }
+        kept_contours.push(flags);
+    }
+
+    if kept_contours.iter().any(|flags| !flags.is_empty()) {
+        let tree = match nesting.as_deref() {
+            Some(nesting) => CurveContourTree::from_polygons(nesting),
+            None => CurveContourTree::from_polygons(&flatten_shapes(shapes)),
+        };
+        drop_filled_islands(&tree, &kept_contours, &mut kept_shapes);
+    }
+
+    for (index, flags) in kept_contours.iter().enumerate() {
+        if flags.is_empty() || !kept_shapes[index] {
+            continue;
+        }
+        retain_flagged(&mut shapes[index].contours, flags);
+        if let Some(nesting) = nesting.as_deref_mut() {
+            retain_flagged(&mut nesting[index], flags);
+        }
+    }
+
+    retain_flagged(shapes, &kept_shapes);
+    if let Some(nesting) = nesting {
+        retain_flagged(nesting, &kept_shapes);
+    }
+}
+
+/// Clears the flags of the shapes nested inside dropped holes.
+///
+/// The nodes of a hole's subtree follow it in depth-first order until the
+/// depth returns to the hole's own.
+fn drop_filled_islands(tree: &CurveContourTree, kept_contours: &[Vec<bool>], kept_shapes: &mut [bool]) {
+    let mut filled_depth = None;
+    for node in tree.nodes() {
+        if let Some(depth) = filled_depth {
+            if node.depth > depth {
+                if !node.is_hole() {
+                    kept_shapes[node.shape] = false;
+                }
+                continue;
+            }
+            filled_depth = None;
+        }
+        let is_dropped = kept_contours[node.shape]
+            .get(node.contour)
+            .is_some_and(|&is_kept| !is_kept);
+        if is_dropped {
+            filled_depth = Some(node.depth);
+        }
+    }
+}
+
+/// Flattens every contour into the polygons a nesting tree is built from.
+fn flatten_shapes<I: CurveInt>(shapes: &[CurveShape<I>]) -> IntShapes<I> {
+    shapes
+        .iter()
+        .map(|shape| {
+            shape
+                .contours
+                .iter()
+                .map(|path| {
+                    let mut points = Vec::new();
+                    flatten_path(path, FLATTENING_TOLERANCE, &mut points);
+                    points
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Keeps the items whose flag at the same position is set.
+fn retain_flagged<T>(items: &mut Vec<T>, flags: &[bool]) {
+    let mut flags = flags.iter();
+    items.retain(|_| flags.next().copied().unwrap_or(true));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::curve::segment::CurveSegment;
+    use alloc::vec;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn rectangle(x0: i32, y0: i32, x1: i32, y1: i32) -> CurvePath<i32> {
+        let segments = [[x1, y0], [x1, y1], [x0, y1], [x0, y0]]
+            .into_iter()
+            .map(|[x, y]| CurveSegment::Line {
+                to: IntPoint::new(x, y),
+            })
+            .collect();
+        CurvePath::new(IntPoint::new(x0, y0), segments)
+    }
+
+    #[test]
+    fn disabled_filter_keeps_every_contour() {
+        let mut shapes = vec![CurveShape::new(vec![rectangle(0, 0, 1, 1)])];
+
+        drop_small_contours(&mut shapes, None, &CurveOverlayOptions::default());
+
+        assert_eq!(shapes.len(), 1);
+    }
+
+    #[test]
+    fn small_holes_are_filled_and_small_shapes_dropped() {
+        let mut shapes = vec![
+            CurveShape::new(vec![
+                rectangle(0, 0, 100, 100),
+                rectangle(10, 10, 12, 12).reversed(),
+                rectangle(40, 40, 60, 60).reversed(),
+            ]),
+            CurveShape::new(vec![
+                rectangle(200, 0, 203, 3),
+                rectangle(201, 1, 202, 2).reversed(),
+            ]),
+        ];
+        let mut nesting: IntShapes<i32> = vec![
+            vec![
+                vec![IntPoint::new(0, 0)],
+                vec![IntPoint::new(10, 10)],
+                vec![IntPoint::new(40, 40)],
+            ],
+            vec![vec![IntPoint::new(200, 0)], vec![IntPoint::new(201, 1)]],
+        ];
+        let options = CurveOverlayOptions::default().with_min_contour_area(16);
+
+        drop_small_contours(&mut shapes, Some(&mut nesting), &options);
+
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0].contours.len(), 2);
+        assert_eq!(shapes[0].holes()[0].start, IntPoint::new(40, 40));
+        assert_eq!(
+            nesting,
+            vec![vec![vec![IntPoint::new(0, 0)], vec![IntPoint::new(40, 40)]]]
+        );
+    }
+
+    #[test]
+    fn islands_inside_filled_holes_are_dropped() {
+        // The hole's long arm keeps its ratio below 2 while the compact
+        // island inside it passes on its own.
+        let hole = [
+            [10, 10],
+            [10, 30],
+            [30, 30],
+            [30, 20],
+            [230, 20],
+            [230, 19],
+            [30, 19],
+            [30, 10],
+        ]
+        .map(|[x, y]| IntPoint::new(x, y));
+        let hole_path = CurvePath::new(
+            hole[0],
+            hole[1..]
+                .iter()
+                .chain(&hole[..1])
+                .map(|&to| CurveSegment::Line { to })
+                .collect(),
+        );
+        let shapes = vec![
+            CurveShape::new(vec![rectangle(0, 0, 300, 100), hole_path]),
+            CurveShape::new(vec![rectangle(12, 12, 28, 28)]),
+            CurveShape::new(vec![rectangle(400, 0, 420, 20)]),
+        ];
+        let options = CurveOverlayOptions::default().with_min_area_perimeter_ratio(2);
+        let polygon = |x0, y0, x1, y1| {
+            vec![
+                IntPoint::new(x0, y0),
+                IntPoint::new(x1, y0),
+                IntPoint::new(x1, y1),
+                IntPoint::new(x0, y1),
+            ]
+        };
+
+        for has_nesting in [false, true] {
+            let mut shapes = shapes.clone();
+            let mut nesting: IntShapes<i32> = vec![
+                vec![polygon(0, 0, 300, 100), hole.to_vec()],
+                vec![polygon(12, 12, 28, 28)],
+                vec![polygon(400, 0, 420, 20)],
+            ];
+
+            drop_small_contours(&mut shapes, has_nesting.then_some(&mut nesting), &options);
+
+            assert_eq!(shapes.len(), 2);
+            assert_eq!(shapes[0].contours.len(), 1);
+            assert_eq!(shapes[1].contours[0].start, IntPoint::new(400, 0));
+            if has_nesting {
+                assert_eq!(
+                    nesting,
+                    vec![vec![polygon(0, 0, 300, 100)], vec![polygon(400, 0, 420, 20)]]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn slivers_are_dropped_by_their_area_to_perimeter_ratio() {
+        // Both contours enclose 400 square units; the strip is 2 units wide.
+        let mut shapes = vec![
+            CurveShape::new(vec![rectangle(0, 0, 200, 2)]),
+            CurveShape::new(vec![rectangle(0, 10, 20, 30)]),
+        ];
+        let options = CurveOverlayOptions::default().with_min_area_perimeter_ratio(2);
+
+        drop_small_contours(&mut shapes, None, &options);
+
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0].contours[0].start, IntPoint::new(0, 10));
+    }
+
+    #[test]
+    fn curved_contours_are_measured_with_their_curves() {
+        // The bulge adds about 1333 square units to a 20 by 20 square.
+        let mut path = rectangle(0, 0, 20, 20);
+        path.segments[0] = CurveSegment::Quad {
+            ctrl: IntPoint::new(10, -200),
+            to: IntPoint::new(20, 0),
+        };
+        let mut shapes = vec![CurveShape::new(vec![path])];
+        let options = CurveOverlayOptions::default().with_min_contour_area(1000);
+
+        drop_small_contours(&mut shapes, None, &options);
+
+        assert_eq!(shapes.len(), 1);
+    }
+}
@@ -0,0 +1,334 @@
+use crate::collections::interval_tree::IntervalTree;
+use crate::int::CurveInt;
+use alloc::vec::Vec;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_float::int::rect::IntRect;
+use i_overlay::i_shape::int::IntPoint;
+use i_overlay::i_shape::int::shape::IntShape;
+
+/// One contour of a Boolean result placed in its nesting tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct CurveContourNode {
+    /// Index of the shape in the result.
+    pub shape: usize,
+    /// Index of the contour within its shape. Contour `0` is the outer
+    /// contour; every other contour is a hole.
+    pub contour: usize,
+    /// Node index of the enclosing contour, or `None` for a top-level outer
+    /// contour. The parent of a hole is the outer contour of its shape; the
+    /// parent of an island is the hole that surrounds it.
+    pub parent: Option<usize>,
+    /// Number of contours enclosing this one. Outer contours have even
+    /// depths and holes have odd depths.
+    pub depth: usize,
+}
+
+impl CurveContourNode {
+    /// Returns `true` if the contour bounds a hole of its shape.
+    #[inline]
+    pub fn is_hole(&self) -> bool {
+        self.contour != 0
+    }
+}
+
+/// Explicit nesting of the contours of a Boolean result.
+///
+/// Nodes are stored in depth-first order: every outer contour is followed by
+/// its holes, and every hole by the islands inside it, so the order suits
+/// inside-out or outside-in processing without further sorting. Top-level
+/// shapes and the islands of a hole appear in result order.
+///
+/// Holes belong to the shape the polygon overlay traced them with. Each
+/// outer contour is then placed in the smallest hole that contains it, found
+/// by exact point-in-polygon tests on the traced polygons rather than on the
+/// reconstructed curves. An interval index over the `x` extents of the holes
+/// limits those tests to holes that overlap the contour.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CurveContourTree {
+    nodes: Vec<CurveContourNode>,
+}
+
+impl CurveContourTree {
+    /// Returns every node in depth-first order.
+    #[inline]
+    pub fn nodes(&self) -> &[CurveContourNode] {
+        &self.nodes
+    }
+
+    /// Returns the number of contours in the tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the result has no contours.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the node indices of the top-level outer contours.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.children_of(None)
+    }
+
+    /// Returns the node indices of the contours directly inside `node`.
+    pub fn children(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.children_of(Some(node))
+    }
+
+    /// Returns the node index of a contour of the result.
+    pub fn find(&self, shape: usize, contour: usize) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.shape == shape && node.contour == contour)
+    }
+
+    fn children_of(&self, parent: Option<usize>) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(move |(_, node)| node.parent == parent)
+            .map(|(index, _)| index)
+    }
+
+    /// Builds the tree from the polygons a result was traced from, one shape
+    /// per result shape with its outer polygon first.
+    ///
+    /// Result boundaries never cross, so an outer polygon lies inside a hole
+    /// exactly when any of its vertices off the hole boundary does. Holes are
+    /// indexed by their ranked `x` extent, so each outer polygon is only
+    /// tested against the holes that span its leftmost `x`.
+    pub(crate) fn from_polygons<I: CurveInt>(shapes: &[IntShape<I>]) -> Self {
+        let holes: Vec<(usize, usize, IntRect<I>)> = shapes
+            .iter()
+            .enumerate()
+            .flat_map(|(shape_index, shape)| {
+                shape.iter().enumerate().skip(1).map(move |(hole_index, hole)| {
+                    (shape_index, hole_index, IntRect::with_points(hole).unwrap())
+                })
+            })
+            .collect();
+
+        let mut x_values: Vec<I> = holes
+            .iter()
+            .flat_map(|(_, _, rect)| [rect.min_x, rect.max_x])
+            .collect();
+        x_values.sort_unstable();
+        x_values.dedup();
+        let rank = |x: I| x_values.partition_point(|&value| value < x);
+
+        let mut index = IntervalTree::new();
+        index.reset(x_values.len(), holes.len());
+        for (item, (_, _, rect)) in holes.iter().enumerate() {
+            index.insert(item, [rank(rect.min_x), rank(rect.max_x)]);
+        }
+
+        // The innermost of several enclosing holes has the smallest area.
+        let mut islands = alloc::vec![Vec::new(); shapes.len()];
+        let mut roots = Vec::new();
+        let mut candidates = Vec::new();
+        for (shape_index, shape) in shapes.iter().enumerate() {
+            let outer = &shape[0];
+            let rect = IntRect::with_points(outer).unwrap();
+            let min_x = rank(rect.min_x);
+            candidates.clear();
+            if min_x < x_values.len() {
+                index.for_each_overlap([min_x, min_x], |item| candidates.push(item));
+            }
+            // Visit holes in result order so equal areas resolve to the first.
+            candidates.sort_unstable();
+
+            let mut parent: Option<((usize, usize), f64)> = None;
+            for &item in &candidates {
+                let (candidate, hole_index, hole_rect) = holes[item];
+                if candidate == shape_index || !hole_rect.contains_rect(&rect) {
+                    continue;
+                }
+                let hole = &shapes[candidate][hole_index];
+                let probe = outer.iter().find(|&&point| !is_on_boundary(hole, point));
+                if !probe.is_some_and(|&point| contains_point(hole, point)) {
+                    continue;
+                }
+                let area = twice_area(hole);
+                if parent.is_none_or(|(_, parent_area)| area < parent_area) {
+                    parent = Some(((candidate, hole_index), area));
+                }
+            }
+            match parent {
+                Some((hole, _)) => islands[hole.0].push((hole.1, shape_index)),
+                None => roots.push(shape_index),
+            }
+        }
+
+        // Islands stay in result order within each hole.
+        for shape_islands in &mut islands {
+            shape_islands.sort_by_key(|&(hole, _)| hole);
+        }
+
+        let mut tree = Self {
+            nodes: Vec::with_capacity(shapes.iter().map(Vec::len).sum()),
+        };
+        for root in roots {
+            tree.push_shape(shapes, &islands, root, None, 0);
+        }
+        tree
+    }
+
+    fn push_shape<I: CurveInt>(
+        &mut self,
+        shapes: &[IntShape<I>],
+        islands: &[Vec<(usize, usize)>],
+        shape: usize,
+        parent: Option<usize>,
+        depth: usize,
+    ) {
+        let outer = self.nodes.len();
+        self.nodes.push(CurveContourNode {
+            shape,
+            contour: 0,
+            parent,
+            depth,
+        });
+        let mut shape_islands = islands[shape].iter().peekable();
+        for contour in 1..shapes[shape].len() {
+            let hole = self.nodes.len();
+            self.nodes.push(CurveContourNode {
+                shape,
+                contour,
+                parent: Some(outer),
+                depth: depth + 1,
+            });
+            while let Some(&(_, island)) = shape_islands.next_if(|(index, _)| *index == contour) {
+                self.push_shape(shapes, islands, island, Some(hole), depth + 2);
+            }
+        }
+    }
+}
+
+fn edges<I: CurveInt>(contour: &[IntPoint<I>]) -> impl Iterator<Item = (IntPoint<I>, IntPoint<I>)> + '_ {
+    contour
+        .iter()
+        .zip(contour.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+fn is_on_boundary<I: CurveInt>(contour: &[IntPoint<I>], point: IntPoint<I>) -> bool {
+    edges(contour).any(|(a, b)| {
+        (b - a).cross_product(point - a) == I::Wide::ZERO && IntRect::with_ab(a, b).contains(point)
+    })
+}
+
+/// Tests a point that does not lie on the contour.
+fn contains_point<I: CurveInt>(contour: &[IntPoint<I>], point: IntPoint<I>) -> bool {
+    let mut winding = 0;
+    for (a, b) in edges(contour) {
+        let side = (b - a).cross_product(point - a);
+        if a.y <= point.y {
+            if b.y > point.y && side > I::Wide::ZERO {
+                winding += 1;
+            }
+        } else if b.y <= point.y && side < I::Wide::ZERO {
+            winding -= 1;
+        }
+    }
+    winding != 0
+}
+
+fn twice_area<I: CurveInt>(contour: &[IntPoint<I>]) -> f64 {
+    let origin = contour[0];
+    edges(contour)
+        .map(|(a, b)| (a - origin).cross_product(b - origin).to_f64())
+        .sum::<f64>()
+        .abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn square(x0: i32, y0: i32, x1: i32, y1: i32, is_hole: bool) -> Vec<IntPoint<i32>> {
+        let mut points = vec![
+            IntPoint::new(x0, y0),
+            IntPoint::new(x1, y0),
+            IntPoint::new(x1, y1),
+            IntPoint::new(x0, y1),
+        ];
+        if is_hole {
+            points.reverse();
+        }
+        points
+    }
+
+    #[test]
+    fn islands_nest_inside_the_innermost_hole() {
+        let shapes = vec![
+            vec![square(40, 40, 60, 60, false)],
+            vec![square(0, 0, 100, 100, false), square(10, 10, 90, 90, true)],
+            vec![square(20, 20, 80, 80, false), square(30, 30, 70, 70, true)],
+            vec![square(200, 0, 210, 10, false)],
+        ];
+
+        let tree = CurveContourTree::from_polygons(&shapes);
+        let order: Vec<_> = tree
+            .nodes()
+            .iter()
+            .map(|node| (node.shape, node.contour, node.depth))
+            .collect();
+
+        assert_eq!(
+            order,
+            vec![(1, 0, 0), (1, 1, 1), (2, 0, 2), (2, 1, 3), (0, 0, 4), (3, 0, 0)]
+        );
+        assert_eq!(tree.roots().collect::<Vec<_>>(), vec![0, 5]);
+        assert_eq!(tree.children(1).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(tree.nodes()[4].parent, tree.find(2, 1));
+    }
+
+    #[test]
+    fn islands_follow_the_hole_that_holds_them() {
+        let shapes = vec![
+            vec![
+                square(0, 0, 100, 50, false),
+                square(10, 10, 40, 40, true),
+                square(60, 10, 90, 40, true),
+            ],
+            vec![square(70, 20, 80, 30, false)],
+            vec![square(20, 20, 30, 30, false)],
+        ];
+
+        let tree = CurveContourTree::from_polygons(&shapes);
+        let order: Vec<_> = tree
+            .nodes()
+            .iter()
+            .map(|node| (node.shape, node.contour, node.parent))
+            .collect();
+
+        assert_eq!(
+            order,
+            vec![
+                (0, 0, None),
+                (0, 1, Some(0)),
+                (2, 0, Some(1)),
+                (0, 2, Some(0)),
+                (1, 0, Some(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn island_touching_its_hole_is_nested() {
+        let shapes = vec![
+            vec![square(0, 0, 100, 100, false), square(10, 10, 90, 90, true)],
+            vec![square(10, 10, 50, 50, false)],
+        ];
+
+        let tree = CurveContourTree::from_polygons(&shapes);
+
+        assert_eq!(tree.nodes()[2].shape, 1);
+        assert_eq!(tree.nodes()[2].parent, Some(1));
+        assert!(tree.nodes()[1].is_hole());
+    }
+}
@@ -0,0 +1,654 @@
+use crate::collections::disjoint_set::DisjointSet;
+use crate::int::CurveInt;
+use crate::int::bool::bounds::CurveBoundsBuffer;
+use crate::int::bool::edge::CurveEdge;
+use crate::int::bool::overlay::{
+    CurveInputError, CurveOverlayOptions, CurveOverlayOptionsError, IntCurveOverlay, validate_shape,
+};
+use crate::int::bool::split::CurveSplitMark;
+use crate::int::bool::workspace::CurveOverlayWorkspace;
+use crate::int::curve::path::CurvePath;
+use crate::int::curve::segment::CurveSegment;
+use crate::int::curve::shape::CurveShape;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay::ShapeType;
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::core::solver::Solver;
+use i_overlay::i_float::int::rect::IntRect;
+
+/// Stable handle of a contour in an [`IncrementalCurveOverlay`].
+///
+/// Handles are never reused, so a handle of a removed contour stays invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CurveContourId(usize);
+
+struct IncrementalContour<I: CurveInt> {
+    path: CurvePath<I>,
+    shape_type: ShapeType,
+    revision: usize,
+    rect: IntRect<I>,
+    planar: CurvePlanarContour<I>,
+}
+
+/// Split mark on an approximated edge of a contour, found against the
+/// contour with key `partner`.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CurveContourMark<I: CurveInt> {
+    pub(super) mark: CurveSplitMark<I>,
+    pub(super) partner: usize,
+}
+
+/// Approximated edge `edge` of a contour that overlaps edge `partner_edge`
+/// of the contour with key `partner`.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CurveContourOverlap {
+    pub(super) edge: usize,
+    pub(super) partner: usize,
+    pub(super) partner_edge: usize,
+}
+
+/// Approximated edges of one contour, the contacts found on them, and the
+/// pieces they were split into, kept between resolutions.
+///
+/// The contacts between any two planarized contours are cached, so a
+/// later pass intersects only edge pairs that involve a new contour, and
+/// splits again only the curves near a change.
+pub(super) struct CurvePlanarContour<I: CurveInt> {
+    /// Identifies the contour as a contact partner; unique per revision.
+    pub(super) key: usize,
+    /// Approximated edges with curve ids relative to the first source of the
+    /// contour.
+    pub(super) edges: Option<Vec<CurveEdge<I>>>,
+    pub(super) marks: Vec<CurveContourMark<I>>,
+    pub(super) overlaps: Vec<CurveContourOverlap>,
+    /// Split and refined edges with relative curve ids, in source order.
+    pub(super) pieces: Vec<CurveEdge<I>>,
+    pub(super) is_planarized: bool,
+}
+
+impl<I: CurveInt> CurvePlanarContour<I> {
+    fn new(key: usize) -> Self {
+        Self {
+            key,
+            edges: None,
+            marks: Vec::new(),
+            overlaps: Vec::new(),
+            pieces: Vec::new(),
+            is_planarized: false,
+        }
+    }
+
+    /// Forgets every contact and piece; the approximated edges stay valid.
+    pub(super) fn reset(&mut self) {
+        self.marks.clear();
+        self.overlaps.clear();
+        self.pieces.clear();
+        self.is_planarized = false;
+    }
+}
+
+/// Planar caches of the contours of one cluster, one entry per input
+/// contour, and the edge bounds of planarized contours removed since the
+/// last pass.
+pub(super) struct CurvePlanarCluster<'a, I: CurveInt> {
+    pub(super) contours: &'a mut [CurvePlanarContour<I>],
+    pub(super) released: &'a [IntRect<I>],
+}
+
+type ClusterKey = Vec<(CurveContourId, usize)>;
+
+/// Boolean overlay that keeps its result up to date while individual
+/// contours are added, removed, or replaced.
+///
+/// Contours whose bounds overlap, directly or through other contours, form a
+/// cluster. A cluster is the only boundary inside its area, so its result
+/// faces do not depend on any other contour. Each [`overlay`](Self::overlay)
+/// call resolves only the clusters that changed since the previous call and
+/// reuses the stored faces of the others.
+///
+/// Every contour keeps its approximated edges, the contacts found on them,
+/// and the pieces they were split into. A changed cluster intersects only
+/// edge pairs that involve an added or replaced contour, and drops the
+/// contacts of removed ones. Splitting and containment refinement run again
+/// only for the curves whose edges touch the bounds of a changed contour,
+/// and face resolution runs over the whole changed cluster.
+///
+/// The result contains exactly the shapes of an [`IntCurveOverlay`] that
+/// receives every contour in the order their handles were created, although
+/// shapes are grouped by cluster rather than in that overlay's output order.
+pub struct IncrementalCurveOverlay<I: CurveInt> {
+    overlay_rule: OverlayRule,
+    fill_rule: FillRule,
+    solver: Solver,
+    options: CurveOverlayOptions,
+    contours: BTreeMap<CurveContourId, IncrementalContour<I>>,
+    next_id: usize,
+    next_revision: usize,
+    results: BTreeMap<ClusterKey, Vec<CurveShape<I>>>,
+    /// Edge bounds of planarized contours removed or replaced since the last
+    /// [`overlay`](Self::overlay) call.
+    released: Vec<IntRect<I>>,
+    bounds: CurveBoundsBuffer<I>,
+    clusters: DisjointSet,
+    workspace: Option<CurveOverlayWorkspace<I>>,
+}
+
+impl<I: CurveInt> IncrementalCurveOverlay<I> {
+    /// Creates an empty overlay that resolves `overlay_rule` with `fill_rule`.
+    pub fn new(overlay_rule: OverlayRule, fill_rule: FillRule) -> Self {
+        Self {
+            overlay_rule,
+            fill_rule,
+            solver: Solver::default(),
+            options: CurveOverlayOptions::default(),
+            contours: BTreeMap::new(),
+            next_id: 0,
+            next_revision: 0,
+            results: BTreeMap::new(),
+            released: Vec::new(),
+            bounds: CurveBoundsBuffer::new(),
+            clusters: DisjointSet::new(),
+            workspace: Some(CurveOverlayWorkspace::new()),
+        }
+    }
+
+    /// Sets the polygon solver strategy and precision.
+    #[must_use]
+    pub fn with_solver(mut self, solver: Solver) -> Self {
+        self.solver = solver;
+        self.clear_caches();
+        self
+    }
+
+    /// Validates and sets the curve approximation options.
+    pub fn try_with_options(
+        mut self,
+        options: CurveOverlayOptions,
+    ) -> Result<Self, CurveOverlayOptionsError> {
+        options.validate()?;
+        self.options = options;
+        self.clear_caches();
+        Ok(self)
+    }
+
+    /// Returns the Boolean operation resolved by this overlay.
+    #[inline]
+    pub fn overlay_rule(&self) -> OverlayRule {
+        self.overlay_rule
+    }
+
+    /// Returns the fill rule resolved by this overlay.
+    #[inline]
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Returns the number of contours in the overlay.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.contours.len()
+    }
+
+    /// Returns whether the overlay contains no contours.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.contours.is_empty()
+    }
+
+    /// Returns the contour with handle `id`, if it is present.
+    pub fn contour(&self, id: CurveContourId) -> Option<(&CurvePath<I>, ShapeType)> {
+        self.contours
+            .get(&id)
+            .map(|contour| (&contour.path, contour.shape_type))
+    }
+
+    /// Validates and adds a contour as part of the subject or clip operand.
+    pub fn add_contour(
+        &mut self,
+        path: CurvePath<I>,
+        shape_type: ShapeType,
+    ) -> Result<CurveContourId, CurveInputError> {
+        let contour = self.new_contour(path, shape_type)?;
+        let id = CurveContourId(self.next_id);
+        self.next_id += 1;
+        self.contours.insert(id, contour);
+        Ok(id)
+    }
+
+    /// Removes a contour and returns it, or `None` when `id` is not present.
+    pub fn remove_contour(&mut self, id: CurveContourId) -> Option<CurvePath<I>> {
+        let contour = self.contours.remove(&id)?;
+        self.release(&contour);
+        Some(contour.path)
+    }
+
+    /// Validates `path` and replaces the geometry of contour `id`, keeping its
+    /// handle and operand.
+    ///
+    /// Returns the previous geometry, or `None` without changing anything
+    /// when `id` is not present.
+    pub fn replace_contour(
+        &mut self,
+        id: CurveContourId,
+        path: CurvePath<I>,
+    ) -> Result<Option<CurvePath<I>>, CurveInputError> {
+        let Some(shape_type) = self.contours.get(&id).map(|contour| contour.shape_type) else {
+            return Ok(None);
+        };
+        let contour = self.new_contour(path, shape_type)?;
+        let previous = self.contours.insert(id, contour).unwrap();
+        self.release(&previous);
+        Ok(Some(previous.path))
+    }
+
+    /// Keeps the edge bounds of a planarized contour that leaves the overlay,
+    /// so the next pass splits the curves it touched again.
+    fn release(&mut self, contour: &IncrementalContour<I>) {
+        let planar = &contour.planar;
+        if let (true, Some(edges)) = (planar.is_planarized, &planar.edges) {
+            self.released.extend(
+                edges
+                    .iter()
+                    .map(|edge| IntRect::with_points(edge.curve.convex_hull().as_slice()).unwrap()),
+            );
+        }
+    }
+
+    fn new_contour(
+        &mut self,
+        path: CurvePath<I>,
+        shape_type: ShapeType,
+    ) -> Result<IncrementalContour<I>, CurveInputError> {
+        let shape = CurveShape::from_path(path);
+        validate_shape(&shape)?;
+        let path = shape.contours.into_iter().next().unwrap();
+
+        let revision = self.next_revision;
+        self.next_revision += 1;
+        Ok(IncrementalContour {
+            rect: control_rect(&path),
+            path,
+            shape_type,
+            revision,
+            planar: CurvePlanarContour::new(revision),
+        })
+    }
+
+    fn clear_caches(&mut self) {
+        self.results.clear();
+        self.released.clear();
+        for contour in self.contours.values_mut() {
+            contour.planar = CurvePlanarContour::new(contour.revision);
+        }
+    }
+
+    /// Returns the result for the current contours.
+    ///
+    /// Only clusters that gained, lost, or changed a contour since the
+    /// previous call are resolved again. The shapes are borrowed from the
+    /// cached cluster results.
+    pub fn overlay(&mut self) -> impl Iterator<Item = &CurveShape<I>> {
+        let keys = self.cluster_keys();
+
+        // Clusters that no longer exist are dropped from the cache.
+        let mut results = core::mem::take(&mut self.results);
+        for key in keys {
+            let shapes = match results.remove(&key) {
+                Some(shapes) => shapes,
+                None => self.resolve_cluster(&key),
+            };
+            self.results.insert(key, shapes);
+        }
+        self.released.clear();
+        self.results.values().flatten()
+    }
+
+    /// Groups the contours into clusters ordered by their first handle.
+    fn cluster_keys(&mut self) -> Vec<ClusterKey> {
+        let contours: Vec<_> = self.contours.iter().collect();
+        self.clusters.reset(contours.len());
+        self.bounds
+            .build_rects(contours.iter().map(|(_, contour)| contour.rect));
+        self.bounds
+            .for_each_overlap(|first, second| self.clusters.union(first, second));
+
+        let mut slots = BTreeMap::new();
+        let mut keys: Vec<ClusterKey> = Vec::new();
+        for (index, &(&id, contour)) in contours.iter().enumerate() {
+            let root = self.clusters.find(index);
+            let slot = *slots.entry(root).or_insert_with(|| {
+                keys.push(Vec::new());
+                keys.len() - 1
+            });
+            keys[slot].push((id, contour.revision));
+        }
+        keys
+    }
+
+    fn resolve_cluster(&mut self, key: &ClusterKey) -> Vec<CurveShape<I>> {
+        let workspace = self.workspace.take().unwrap_or_default();
+        let mut overlay = IntCurveOverlay::with_workspace(workspace)
+            .with_solver(self.solver)
+            .try_with_options(self.options)
+            .expect("options were validated");
+        let mut planar = Vec::with_capacity(key.len());
+        for (id, _) in key {
+            let contour = self.contours.get_mut(id).unwrap();
+            let shape = CurveShape::from_path(contour.path.clone());
+            let result = overlay.add_shape(shape, contour.shape_type);
+            debug_assert!(result.is_ok(), "contours are validated when added");
+            let empty = CurvePlanarContour::new(contour.revision);
+            planar.push(core::mem::replace(&mut contour.planar, empty));
+        }
+
+        let cluster = CurvePlanarCluster {
+            contours: &mut planar,
+            released: &self.released,
+        };
+        let shapes = overlay.overlay_and_clear_with(self.overlay_rule, self.fill_rule, Some(cluster));
+        self.workspace = Some(overlay.into_workspace());
+        for ((id, _), cache) in key.iter().zip(planar) {
+            self.contours.get_mut(id).unwrap().planar = cache;
+        }
+        shapes
+    }
+}
+
+/// Returns bounds containing every control point, and therefore the whole
+/// contour.
+fn control_rect<I: CurveInt>(path: &CurvePath<I>) -> IntRect<I> {
+    let mut rect = IntRect::with_point(path.start);
+    for segment in &path.segments {
+        match segment {
+            CurveSegment::Line { to } => rect.add_point(to),
+            CurveSegment::Quad { ctrl, to } => {
+                rect.add_point(ctrl);
+                rect.add_point(to);
+            }
+            CurveSegment::Cubic { ctrl0, ctrl1, to } => {
+                rect.add_point(ctrl0);
+                rect.add_point(ctrl1);
+                rect.add_point(to);
+            }
+            CurveSegment::Arc { arc } => {
+                for point in &arc.control_points {
+                    rect.add_point(point);
+                }
+            }
+        }
+    }
+    rect
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::int::curve::segment::Segment;
+    use alloc::vec;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn contour(x: i32, y: i32, size: i32, is_curved: bool) -> CurvePath<i32> {
+        let right = if is_curved {
+            CurveSegment::Quad {
+                ctrl: IntPoint::new(x + 2 * size, y + size / 2),
+                to: IntPoint::new(x + size, y + size),
+            }
+        } else {
+            CurveSegment::Line {
+                to: IntPoint::new(x + size, y + size),
+            }
+        };
+        CurvePath::new(
+            IntPoint::new(x, y),
+            vec![
+                CurveSegment::Line {
+                    to: IntPoint::new(x + size, y),
+                },
+                right,
+                CurveSegment::Line {
+                    to: IntPoint::new(x, y + size),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x, y),
+                },
+            ],
+        )
+    }
+
+    fn resolve(overlay: &mut IncrementalCurveOverlay<i32>) -> Vec<CurveShape<i32>> {
+        overlay.overlay().cloned().collect()
+    }
+
+    fn full_overlay(overlay: &IncrementalCurveOverlay<i32>) -> Vec<CurveShape<i32>> {
+        let mut full = IntCurveOverlay::new();
+        for contour in overlay.contours.values() {
+            full.add_shape(CurveShape::from_path(contour.path.clone()), contour.shape_type)
+                .unwrap();
+        }
+        full.overlay(overlay.overlay_rule, overlay.fill_rule)
+    }
+
+    fn assert_same_shapes(mut actual: Vec<CurveShape<i32>>, expected: Vec<CurveShape<i32>>) {
+        assert_eq!(actual.len(), expected.len());
+        for shape in expected {
+            let index = actual.iter().position(|candidate| *candidate == shape);
+            actual.swap_remove(index.expect("shape of the full overlay"));
+        }
+    }
+
+    #[test]
+    fn edits_match_full_recompute() {
+        for rule in [
+            OverlayRule::Union,
+            OverlayRule::Intersect,
+            OverlayRule::Difference,
+        ] {
+            let mut overlay = IncrementalCurveOverlay::new(rule, FillRule::NonZero);
+            let mut ids = Vec::new();
+            for index in 0..6 {
+                let shape_type = if index % 2 == 0 {
+                    ShapeType::Subject
+                } else {
+                    ShapeType::Clip
+                };
+                let path = contour(index * 700, (index % 3) * 300, 1_000, index % 3 == 0);
+                ids.push(overlay.add_contour(path, shape_type).unwrap());
+            }
+            assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+
+            for step in 0..8 {
+                let id = ids[step % ids.len()];
+                let path = contour(step as i32 * 450, 200, 800, step % 2 == 1);
+                overlay.replace_contour(id, path).unwrap().unwrap();
+                assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+            }
+
+            let removed = ids.remove(1);
+            assert!(overlay.remove_contour(removed).is_some());
+            assert!(overlay.remove_contour(removed).is_none());
+            assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+        }
+    }
+
+    #[test]
+    fn unchanged_clusters_reuse_their_results() {
+        let mut overlay = IncrementalCurveOverlay::new(OverlayRule::Union, FillRule::NonZero);
+        overlay
+            .add_contour(contour(0, 0, 1_000, true), ShapeType::Subject)
+            .unwrap();
+        overlay
+            .add_contour(contour(500, 500, 1_000, false), ShapeType::Clip)
+            .unwrap();
+        let moving = overlay
+            .add_contour(contour(10_000, 0, 1_000, true), ShapeType::Clip)
+            .unwrap();
+        resolve(&mut overlay);
+        let cached: Vec<_> = overlay.results.keys().cloned().collect();
+
+        overlay
+            .replace_contour(moving, contour(12_000, 0, 1_000, true))
+            .unwrap();
+        assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+
+        assert_eq!(overlay.results.len(), 2);
+        assert!(overlay.results.contains_key(&cached[0]));
+        assert!(!overlay.results.contains_key(&cached[1]));
+    }
+
+    #[test]
+    fn line_only_clusters_follow_the_document_pipeline() {
+        let mut overlay = IncrementalCurveOverlay::new(OverlayRule::Union, FillRule::NonZero);
+        overlay
+            .add_contour(contour(0, 0, 1_000, false), ShapeType::Subject)
+            .unwrap();
+        overlay
+            .add_contour(contour(500, 500, 1_000, false), ShapeType::Clip)
+            .unwrap();
+        let curved = overlay
+            .add_contour(contour(10_000, 0, 1_000, true), ShapeType::Subject)
+            .unwrap();
+        overlay
+            .add_contour(contour(10_500, 0, 1_000, false), ShapeType::Clip)
+            .unwrap();
+        assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+
+        overlay
+            .replace_contour(curved, contour(10_000, 0, 1_000, false))
+            .unwrap();
+        assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+    }
+
+    #[test]
+    fn rejects_invalid_contours_and_unknown_handles() {
+        let mut overlay = IncrementalCurveOverlay::<i32>::new(OverlayRule::Union, FillRule::NonZero);
+        let open = CurvePath::new(
+            IntPoint::new(0, 0),
+            vec![CurveSegment::Line {
+                to: IntPoint::new(1, 0),
+            }],
+        );
+        assert_eq!(
+            overlay.add_contour(open.clone(), ShapeType::Subject),
+            Err(CurveInputError::UnclosedContour { contour: 0 })
+        );
+
+        let id = overlay
+            .add_contour(contour(0, 0, 10, false), ShapeType::Subject)
+            .unwrap();
+        assert!(overlay.replace_contour(id, open).is_err());
+        assert_eq!(overlay.contour(id).unwrap().0, &contour(0, 0, 10, false));
+        overlay.remove_contour(id);
+        assert_eq!(overlay.replace_contour(id, contour(0, 0, 10, false)), Ok(None));
+        assert!(overlay.is_empty());
+        assert!(overlay.overlay().next().is_none());
+    }
+
+    #[test]
+    fn edits_inside_one_cluster_reuse_unchanged_contacts() {
+        let mut state = 0x9e37_79b9_u32;
+        let mut next = |bound: i32| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state % bound as u32) as i32
+        };
+        for rule in [OverlayRule::Union, OverlayRule::Xor, OverlayRule::Difference] {
+            let mut overlay = IncrementalCurveOverlay::new(rule, FillRule::EvenOdd);
+            let mut ids = Vec::new();
+            for index in 0..5 {
+                let path = contour(next(1_500), next(1_500), 1_000 + next(800), index % 2 == 0);
+                let shape_type = if index % 2 == 0 {
+                    ShapeType::Subject
+                } else {
+                    ShapeType::Clip
+                };
+                ids.push(overlay.add_contour(path, shape_type).unwrap());
+            }
+            assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+
+            for step in 0..12 {
+                let path = contour(next(1_500), next(1_500), 1_000 + next(800), step % 3 != 0);
+                match step % 4 {
+                    0 => ids.push(overlay.add_contour(path, ShapeType::Clip).unwrap()),
+                    1 => {
+                        let id = ids.remove(next(ids.len() as i32) as usize);
+                        overlay.remove_contour(id).unwrap();
+                    }
+                    _ => {
+                        let id = ids[next(ids.len() as i32) as usize];
+                        overlay.replace_contour(id, path).unwrap().unwrap();
+                    }
+                }
+                assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+            }
+        }
+    }
+
+    #[test]
+    fn replacing_a_contour_keeps_contacts_between_the_others() {
+        let mut overlay = IncrementalCurveOverlay::new(OverlayRule::Union, FillRule::NonZero);
+        let first = overlay
+            .add_contour(contour(0, 0, 1_000, true), ShapeType::Subject)
+            .unwrap();
+        let second = overlay
+            .add_contour(contour(500, 300, 1_000, true), ShapeType::Clip)
+            .unwrap();
+        let moving = overlay
+            .add_contour(contour(900, 0, 1_000, true), ShapeType::Clip)
+            .unwrap();
+        resolve(&mut overlay);
+
+        let second_key = overlay.contours[&second].planar.key;
+        let moving_key = overlay.contours[&moving].planar.key;
+        let contacts = |overlay: &IncrementalCurveOverlay<i32>, partner: usize| {
+            let planar = &overlay.contours[&first].planar;
+            planar.marks.iter().filter(|mark| mark.partner == partner).count()
+        };
+        let shared = contacts(&overlay, second_key);
+        assert!(shared > 0);
+        assert!(contacts(&overlay, moving_key) > 0);
+
+        overlay
+            .replace_contour(moving, contour(700, 100, 1_000, true))
+            .unwrap();
+        assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+
+        assert!(overlay.contours[&first].planar.is_planarized);
+        assert_eq!(contacts(&overlay, second_key), shared);
+        assert_eq!(contacts(&overlay, moving_key), 0);
+        let moved_key = overlay.contours[&moving].planar.key;
+        assert!(contacts(&overlay, moved_key) > 0);
+    }
+
+    #[test]
+    fn edits_split_only_curves_near_the_change() {
+        let mut overlay = IncrementalCurveOverlay::new(OverlayRule::Union, FillRule::NonZero);
+        let mut ids = Vec::new();
+        for index in 0..4 {
+            let path = contour(index * 800, 0, 1_000, true);
+            ids.push(overlay.add_contour(path, ShapeType::Subject).unwrap());
+        }
+        resolve(&mut overlay);
+
+        // The last contour shares the cluster but touches nothing near the
+        // edit. Its cached edges are moved away, so splitting it again would
+        // change the result.
+        let planar = &mut overlay.contours.get_mut(&ids[3]).unwrap().planar;
+        for edge in planar.edges.as_mut().unwrap() {
+            let points = match &mut edge.curve {
+                Segment::Line(line) => &mut line.control_points[..],
+                Segment::Quad(quad) => &mut quad.control_points[..],
+                _ => unreachable!(),
+            };
+            for point in points {
+                point.y += 100_000;
+            }
+        }
+        overlay
+            .replace_contour(ids[0], contour(-100, 100, 1_000, false))
+            .unwrap();
+        assert_same_shapes(resolve(&mut overlay), full_overlay(&overlay));
+    }
+}
@@ -0,0 +1,417 @@
+use crate::collections::disjoint_set::DisjointSet;
+use crate::int::CurveInt;
+use crate::int::bool::arrangement::{CurveArrangement, CurveArrangementHalfEdge, IntCurveArrangement};
+use crate::int::bool::overlay::{CurveInputError, CurveOverlayOptions, CurveOverlayOptionsError};
+use crate::int::curve::shape::CurveShape;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::solver::Solver;
+
+/// Set of layer indices.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CurveLayerSet {
+    words: Vec<u64>,
+}
+
+impl CurveLayerSet {
+    /// Creates an empty set.
+    #[inline]
+    pub fn new() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    /// Adds `layer` to the set.
+    pub fn insert(&mut self, layer: usize) {
+        let word = layer / u64::BITS as usize;
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= 1 << (layer % u64::BITS as usize);
+    }
+
+    /// Returns whether `layer` is in the set.
+    #[inline]
+    pub fn contains(&self, layer: usize) -> bool {
+        self.words
+            .get(layer / u64::BITS as usize)
+            .is_some_and(|word| word & (1 << (layer % u64::BITS as usize)) != 0)
+    }
+
+    /// Returns the number of layers in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns whether the set has no layers.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the layers in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let base = index * u64::BITS as usize;
+            (0..u64::BITS as usize)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| base + bit)
+        })
+    }
+}
+
+impl FromIterator<usize> for CurveLayerSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for layer in iter {
+            set.insert(layer);
+        }
+        set
+    }
+}
+
+/// Connected region covered by the same set of layers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurveLayerRegion<I: CurveInt> {
+    /// Region boundary. Contours keep the region on their left, so outer
+    /// boundaries run counterclockwise and holes clockwise. The first
+    /// contour passes through the smallest vertex of the region.
+    pub shape: CurveShape<I>,
+    /// Layers whose fill rule covers the region.
+    pub layers: CurveLayerSet,
+}
+
+struct CurveLayer {
+    paths: Range<usize>,
+    fill_rule: FillRule,
+}
+
+/// Overlays any number of layers at once and labels every resulting region
+/// with the layers that cover it.
+///
+/// Each layer is a closed shape resolved with its own fill rule. All layers
+/// share one [`CurveArrangement`], so the work grows with the total input and
+/// its intersections rather than with the number of layer pairs. Region
+/// boundaries keep their line, Bézier, and arc segments.
+pub struct IntCurveLayerOverlay<I: CurveInt> {
+    arrangement: IntCurveArrangement<I>,
+    layers: Vec<CurveLayer>,
+}
+
+impl<I: CurveInt> IntCurveLayerOverlay<I> {
+    /// Creates an overlay without layers.
+    pub fn new() -> Self {
+        Self {
+            arrangement: IntCurveArrangement::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    /// Sets the polygon solver strategy and precision.
+    #[must_use]
+    pub fn with_solver(mut self, solver: Solver) -> Self {
+        self.arrangement = self.arrangement.with_solver(solver);
+        self
+    }
+
+    /// Validates and sets the curve approximation options.
+    pub fn try_with_options(
+        mut self,
+        options: CurveOverlayOptions,
+    ) -> Result<Self, CurveOverlayOptionsError> {
+        self.arrangement = self.arrangement.try_with_options(options)?;
+        Ok(self)
+    }
+
+    /// Returns the number of layers added so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns whether no layer has been added.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Validates and adds a layer resolved with `fill_rule`, and returns its
+    /// index.
+    pub fn add_layer(&mut self, shape: CurveShape<I>, fill_rule: FillRule) -> Result<usize, CurveInputError> {
+        let paths = self.arrangement.add_shape(shape)?;
+        self.layers.push(CurveLayer { paths, fill_rule });
+        Ok(self.layers.len() - 1)
+    }
+
+    /// Splits all layers into regions, ordered by their first face in the
+    /// arrangement. Areas that no layer covers are not reported.
+    pub fn overlay(self) -> Vec<CurveLayerRegion<I>> {
+        let arrangement = self.arrangement.build();
+        let memberships: Vec<CurveLayerSet> = arrangement
+            .faces()
+            .iter()
+            .map(|face| {
+                self.layers
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, layer)| {
+                        let winding = face.winding[layer.paths.clone()].iter().sum();
+                        is_filled(layer.fill_rule, winding)
+                    })
+                    .map(|(index, _)| index)
+                    .collect()
+            })
+            .collect();
+
+        let mut regions = DisjointSet::new();
+        regions.reset(memberships.len());
+        for edge in arrangement.edges() {
+            if memberships[edge.left_face] == memberships[edge.right_face] {
+                regions.union(edge.left_face, edge.right_face);
+            }
+        }
+        // Each region is named by its smallest face.
+        let mut first_face = vec![usize::MAX; memberships.len()];
+        let region_of: Vec<usize> = (0..memberships.len())
+            .map(|face| {
+                let root = regions.find(face);
+                if first_face[root] == usize::MAX {
+                    first_face[root] = face;
+                }
+                first_face[root]
+            })
+            .collect();
+
+        RegionTracer::new(&arrangement, &region_of)
+            .trace()
+            .into_iter()
+            .filter(|(region, _)| !memberships[*region].is_empty())
+            .map(|(region, shape)| CurveLayerRegion {
+                shape,
+                layers: memberships[region].clone(),
+            })
+            .collect()
+    }
+}
+
+impl<I: CurveInt> Default for IntCurveLayerOverlay<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Walks the boundaries between regions of arrangement faces.
+///
+/// Half-edge `2 * edge` runs along the edge and `2 * edge + 1` against it,
+/// with its face on the left.
+struct RegionTracer<'a, I: CurveInt> {
+    arrangement: &'a CurveArrangement<I>,
+    region_of: &'a [usize],
+    next: Vec<usize>,
+}
+
+impl<'a, I: CurveInt> RegionTracer<'a, I> {
+    fn new(arrangement: &'a CurveArrangement<I>, region_of: &'a [usize]) -> Self {
+        let mut next = vec![0; 2 * arrangement.edges().len()];
+        for face in arrangement.faces() {
+            for cycle in core::iter::once(&face.boundary).chain(&face.holes) {
+                for (index, &half_edge) in cycle.iter().enumerate() {
+                    next[Self::id(half_edge)] = Self::id(cycle[(index + 1) % cycle.len()]);
+                }
+            }
+        }
+        Self {
+            arrangement,
+            region_of,
+            next,
+        }
+    }
+
+    #[inline]
+    fn id(half_edge: CurveArrangementHalfEdge) -> usize {
+        2 * half_edge.edge + usize::from(half_edge.is_reversed)
+    }
+
+    #[inline]
+    fn half_edge(id: usize) -> CurveArrangementHalfEdge {
+        CurveArrangementHalfEdge {
+            edge: id >> 1,
+            is_reversed: id & 1 == 1,
+        }
+    }
+
+    /// Returns the region on the left of a half-edge.
+    #[inline]
+    fn region(&self, id: usize) -> usize {
+        let edge = &self.arrangement.edges()[id >> 1];
+        let face = if id & 1 == 0 {
+            edge.left_face
+        } else {
+            edge.right_face
+        };
+        self.region_of[face]
+    }
+
+    #[inline]
+    fn is_boundary(&self, id: usize) -> bool {
+        self.region(id) != self.region(id ^ 1)
+    }
+
+    /// Returns every region with its boundary, ordered by region.
+    fn trace(&self) -> Vec<(usize, CurveShape<I>)> {
+        let mut is_visited = vec![false; self.next.len()];
+        let mut cycles: Vec<(usize, usize, Vec<CurveArrangementHalfEdge>)> = Vec::new();
+        for first in 0..self.next.len() {
+            if is_visited[first] || !self.is_boundary(first) {
+                continue;
+            }
+
+            let mut cycle = Vec::new();
+            let mut min_vertex = usize::MAX;
+            let mut id = first;
+            while !is_visited[id] {
+                is_visited[id] = true;
+                let half_edge = Self::half_edge(id);
+                min_vertex = min_vertex.min(self.arrangement.origin(half_edge));
+                cycle.push(half_edge);
+
+                // Faces of the same region are crossed by turning clockwise
+                // around the end vertex until the boundary continues.
+                id = self.next[id];
+                while !self.is_boundary(id) {
+                    id = self.next[id ^ 1];
+                }
+            }
+            cycles.push((self.region(first), min_vertex, cycle));
+        }
+        cycles.sort_unstable_by_key(|&(region, min_vertex, _)| (region, min_vertex));
+
+        let mut shapes: Vec<(usize, CurveShape<I>)> = Vec::new();
+        for (region, _, cycle) in cycles {
+            let Some(path) = self.arrangement.cycle_path(&cycle) else {
+                continue;
+            };
+            match shapes.last_mut() {
+                Some((last, shape)) if *last == region => shape.contours.push(path),
+                _ => shapes.push((region, CurveShape::from_path(path))),
+            }
+        }
+        shapes
+    }
+}
+
+#[inline]
+fn is_filled(fill_rule: FillRule, winding: i32) -> bool {
+    match fill_rule {
+        FillRule::EvenOdd => winding % 2 != 0,
+        FillRule::NonZero => winding != 0,
+        FillRule::Positive => winding > 0,
+        FillRule::Negative => winding < 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::bool::overlay::validate_shape;
+    use crate::int::curve::path::CurvePath;
+    use crate::int::curve::segment::CurveSegment;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn square(x: i32, y: i32, size: i32) -> CurvePath<i32> {
+        CurvePath::new(
+            IntPoint::new(x, y),
+            vec![
+                CurveSegment::Line {
+                    to: IntPoint::new(x + size, y),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x + size, y + size),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x, y + size),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x, y),
+                },
+            ],
+        )
+    }
+
+    fn layer_sets(regions: &[CurveLayerRegion<i32>]) -> Vec<Vec<usize>> {
+        let mut sets: Vec<Vec<usize>> = regions
+            .iter()
+            .map(|region| region.layers.iter().collect())
+            .collect();
+        sets.sort_unstable();
+        sets
+    }
+
+    #[test]
+    fn layer_set_tracks_layers_beyond_one_word() {
+        let set: CurveLayerSet = [3, 64, 130].into_iter().collect();
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(64));
+        assert!(!set.contains(65));
+        assert!(!set.contains(1000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 64, 130]);
+        assert!(CurveLayerSet::new().is_empty());
+    }
+
+    #[test]
+    fn three_layers_label_every_overlap() {
+        let mut overlay = IntCurveLayerOverlay::new();
+        for (x, y) in [(0, 0), (20, 0), (10, 15)] {
+            overlay
+                .add_layer(CurveShape::from_path(square(x, y, 30)), FillRule::NonZero)
+                .unwrap();
+        }
+
+        let regions = overlay.overlay();
+
+        assert_eq!(
+            layer_sets(&regions),
+            vec![
+                vec![0],
+                vec![0, 1],
+                vec![0, 1, 2],
+                vec![0, 2],
+                vec![1],
+                vec![1, 2],
+                vec![2],
+            ]
+        );
+        assert!(regions.iter().all(|region| validate_shape(&region.shape).is_ok()));
+    }
+
+    #[test]
+    fn each_layer_uses_its_own_fill_rule() {
+        let nested = CurveShape::new(vec![square(0, 0, 100), square(25, 25, 50)]);
+        let mut overlay = IntCurveLayerOverlay::new();
+        overlay.add_layer(nested.clone(), FillRule::EvenOdd).unwrap();
+        overlay.add_layer(nested, FillRule::NonZero).unwrap();
+
+        let regions = overlay.overlay();
+
+        assert_eq!(layer_sets(&regions), vec![vec![0, 1], vec![1]]);
+        let ring = regions.iter().find(|region| region.layers.len() == 2).unwrap();
+        assert_eq!(ring.shape.contours.len(), 2);
+    }
+
+    #[test]
+    fn faces_with_equal_layers_merge_into_one_region() {
+        let halves = CurveShape::new(vec![square(0, 0, 10), square(10, 0, 10)]);
+        let mut overlay = IntCurveLayerOverlay::new();
+        overlay.add_layer(halves, FillRule::NonZero).unwrap();
+
+        let regions = overlay.overlay();
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].shape.contours.len(), 1);
+        assert_eq!(regions[0].shape.contours[0].segments.len(), 6);
+    }
+}
@@ -1,10 +1,23 @@
 mod approximate;
+pub(crate) mod arrangement;
 mod bounds;
+mod cleanup;
 mod data;
+pub(crate) mod diagnostics;
 mod edge;
+mod filter;
+pub(crate) mod hierarchy;
+pub(crate) mod incremental;
+pub(crate) mod layers;
+mod order;
 pub(crate) mod overlay;
+mod passthrough;
 mod planarize;
+pub(crate) mod prepared;
 mod recompose;
 mod refine;
+pub(crate) mod repro;
 mod source;
 mod split;
+pub(crate) mod verify;
+pub(crate) mod workspace;
@@ -0,0 +1,285 @@
+use crate::int::CurveInt;
+use crate::int::curve::path::CurvePath;
+use crate::int::curve::segment::CurveSegment;
+use crate::int::curve::shape::CurveShape;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use i_overlay::i_shape::int::IntPoint;
+use i_overlay::i_shape::int::shape::IntShapes;
+
+/// Rewrites Boolean results into an order that depends only on their
+/// geometry.
+///
+/// Every contour starts at its smallest vertex, comparing `x` first and `y`
+/// second, with its segments rotated to match. Holes are sorted within their
+/// shape and shapes are sorted among each other by comparing the vertex
+/// sequences of those rotated contours, then the kinds and control points of
+/// their segments. When `nesting` holds the polygons
+/// the shapes were traced from, they are permuted the same way so a
+/// hierarchy built from them keeps referring to the right contours.
+pub(crate) fn canonicalize<I: CurveInt>(
+    shapes: &mut Vec<CurveShape<I>>,
+    mut nesting: Option<&mut IntShapes<I>>,
+) {
+    for (index, shape) in shapes.iter_mut().enumerate() {
+        for path in &mut shape.contours {
+            rotate_to_smallest_vertex(path);
+        }
+        let contours = &shape.contours;
+        let mut order: Vec<usize> = (0..contours.len()).collect();
+        order[1..].sort_by(|&a, &b| compare_paths(&contours[a], &contours[b]));
+        permute(&mut shape.contours, &order);
+        if let Some(nesting) = nesting.as_deref_mut() {
+            permute(&mut nesting[index], &order);
+        }
+    }
+
+    let mut order: Vec<usize> = (0..shapes.len()).collect();
+    order.sort_by(|&a, &b| compare_contour_lists(&shapes[a].contours, &shapes[b].contours));
+    permute(shapes, &order);
+    if let Some(nesting) = nesting {
+        permute(nesting, &order);
+    }
+}
+
+/// Reorders `items` so that position `i` holds the item at `order[i]`.
+fn permute<T>(items: &mut Vec<T>, order: &[usize]) {
+    let mut slots: Vec<Option<T>> = items.drain(..).map(Some).collect();
+    items.extend(order.iter().filter_map(|&index| slots[index].take()));
+}
+
+fn rotate_to_smallest_vertex<I: CurveInt>(path: &mut CurvePath<I>) {
+    let vertices = &vertices(path);
+    let Some(&smallest) = vertices.iter().min() else {
+        return;
+    };
+    // A contour can pass through its smallest vertex more than once; the
+    // rotation with the smallest vertex sequence wins, then the one with the
+    // smallest segments.
+    let len = vertices.len();
+    let rotated = |start: usize| (0..len).map(move |offset| vertices[(start + offset) % len]);
+    let segments = &path.segments;
+    let rotated_segments = |start: usize| (0..len).map(move |offset| &segments[(start + offset) % len]);
+    let Some(start) = (0..len)
+        .filter(|&index| vertices[index] == smallest)
+        .min_by(|&a, &b| {
+            rotated(a)
+                .cmp(rotated(b))
+                .then_with(|| compare_segment_runs(rotated_segments(a), rotated_segments(b)))
+        })
+    else {
+        return;
+    };
+    if start != 0 {
+        path.start = vertices[start];
+        path.segments.rotate_left(start);
+    }
+}
+
+/// Returns the start point of every segment of a closed contour.
+fn vertices<I: CurveInt>(path: &CurvePath<I>) -> Vec<IntPoint<I>> {
+    let mut vertices = Vec::with_capacity(path.segments.len());
+    if !path.segments.is_empty() {
+        vertices.push(path.start);
+        vertices.extend(
+            path.segments[..path.segments.len() - 1]
+                .iter()
+                .map(CurveSegment::end_point),
+        );
+    }
+    vertices
+}
+
+/// Compares vertex sequences first, then the segments between equal
+/// vertices by kind and control points.
+fn compare_paths<I: CurveInt>(a: &CurvePath<I>, b: &CurvePath<I>) -> Ordering {
+    let a_points = core::iter::once(a.start).chain(a.segments.iter().map(CurveSegment::end_point));
+    let b_points = core::iter::once(b.start).chain(b.segments.iter().map(CurveSegment::end_point));
+    a_points
+        .cmp(b_points)
+        .then_with(|| compare_segment_runs(a.segments.iter(), b.segments.iter()))
+}
+
+fn compare_segment_runs<'a, I: CurveInt + 'a>(
+    a: impl Iterator<Item = &'a CurveSegment<I>>,
+    b: impl Iterator<Item = &'a CurveSegment<I>>,
+) -> Ordering {
+    a.zip(b)
+        .map(|(a, b)| compare_segments(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Orders segments with the same endpoints by kind, then control points.
+fn compare_segments<I: CurveInt>(a: &CurveSegment<I>, b: &CurveSegment<I>) -> Ordering {
+    let kind = |segment: &CurveSegment<I>| match segment {
+        CurveSegment::Line { .. } => 0,
+        CurveSegment::Quad { .. } => 1,
+        CurveSegment::Cubic { .. } => 2,
+        CurveSegment::Arc { .. } => 3,
+    };
+    kind(a).cmp(&kind(b)).then_with(|| match (a, b) {
+        (CurveSegment::Quad { ctrl: a, .. }, CurveSegment::Quad { ctrl: b, .. }) => a.cmp(b),
+        (
+            CurveSegment::Cubic {
+                ctrl0: a0, ctrl1: a1, ..
+            },
+            CurveSegment::Cubic {
+                ctrl0: b0, ctrl1: b1, ..
+            },
+        ) => (a0, a1).cmp(&(b0, b1)),
+        (CurveSegment::Arc { arc: a }, CurveSegment::Arc { arc: b }) => {
+            (a.control_points, a.weights).cmp(&(b.control_points, b.weights))
+        }
+        _ => Ordering::Equal,
+    })
+}
+
+fn compare_contour_lists<I: CurveInt>(a: &[CurvePath<I>], b: &[CurvePath<I>]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| compare_paths(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn polygon(points: &[[i32; 2]]) -> CurvePath<i32> {
+        let start = IntPoint::new(points[0][0], points[0][1]);
+        let segments = points[1..]
+            .iter()
+            .chain(core::iter::once(&points[0]))
+            .map(|&[x, y]| CurveSegment::Line {
+                to: IntPoint::new(x, y),
+            })
+            .collect();
+        CurvePath::new(start, segments)
+    }
+
+    #[test]
+    fn contours_start_at_their_smallest_vertex() {
+        let mut path = polygon(&[[4, 4], [0, 4], [0, 0], [4, 0]]);
+        path.segments[1] = CurveSegment::Quad {
+            ctrl: IntPoint::new(-2, 2),
+            to: IntPoint::new(0, 0),
+        };
+
+        rotate_to_smallest_vertex(&mut path);
+
+        assert_eq!(path.start, IntPoint::new(0, 0));
+        assert_eq!(path.segments[0].end_point(), IntPoint::new(4, 0));
+        assert_eq!(
+            path.segments[3],
+            CurveSegment::Quad {
+                ctrl: IntPoint::new(-2, 2),
+                to: IntPoint::new(0, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn repeated_smallest_vertex_picks_the_smallest_sequence() {
+        // Two loops meet at the origin; the loop toward (1, -3) sorts first.
+        let mut path = polygon(&[[0, 0], [3, 1], [3, 3], [0, 0], [1, -3], [3, -3]]);
+
+        rotate_to_smallest_vertex(&mut path);
+
+        assert_eq!(path.start, IntPoint::new(0, 0));
+        assert_eq!(path.segments[0].end_point(), IntPoint::new(1, -3));
+    }
+
+    #[test]
+    fn shapes_holes_and_nesting_follow_one_order() {
+        let outer = |x: i32| polygon(&[[x + 10, x + 10], [x, x + 10], [x, x], [x + 10, x]]);
+        let hole = |x: i32, y: i32| polygon(&[[x, y], [x, y + 2], [x + 2, y + 2], [x + 2, y]]);
+        let mut shapes = vec![
+            CurveShape::new(vec![outer(20)]),
+            CurveShape::new(vec![outer(0), hole(5, 5), hole(1, 1)]),
+        ];
+        let mut nesting: IntShapes<i32> = vec![
+            vec![vec![IntPoint::new(20, 20)]],
+            vec![
+                vec![IntPoint::new(0, 0)],
+                vec![IntPoint::new(5, 5)],
+                vec![IntPoint::new(1, 1)],
+            ],
+        ];
+
+        canonicalize(&mut shapes, Some(&mut nesting));
+
+        assert_eq!(shapes[0].contours[0].start, IntPoint::new(0, 0));
+        assert_eq!(shapes[0].holes()[0].start, IntPoint::new(1, 1));
+        assert_eq!(shapes[0].holes()[1].start, IntPoint::new(5, 5));
+        assert_eq!(shapes[1].contours[0].start, IntPoint::new(20, 20));
+        let firsts: Vec<Vec<_>> = nesting
+            .iter()
+            .map(|shape| shape.iter().map(|contour| contour[0]).collect())
+            .collect();
+        assert_eq!(
+            firsts,
+            vec![
+                vec![IntPoint::new(0, 0), IntPoint::new(1, 1), IntPoint::new(5, 5)],
+                vec![IntPoint::new(20, 20)],
+            ]
+        );
+    }
+
+    #[test]
+    fn holes_with_equal_vertices_sort_by_their_segments() {
+        let outer = polygon(&[[0, 0], [10, 0], [10, 10], [0, 10]]);
+        let line = polygon(&[[2, 2], [2, 4], [4, 2]]);
+        let mut quad = line.clone();
+        quad.segments[2] = CurveSegment::Quad {
+            ctrl: IntPoint::new(3, 1),
+            to: IntPoint::new(2, 2),
+        };
+        let mut cubic = line.clone();
+        cubic.segments[2] = CurveSegment::Cubic {
+            ctrl0: IntPoint::new(4, 1),
+            ctrl1: IntPoint::new(3, 1),
+            to: IntPoint::new(2, 2),
+        };
+        let mut flatter = quad.clone();
+        flatter.segments[2] = CurveSegment::Quad {
+            ctrl: IntPoint::new(3, 2),
+            to: IntPoint::new(2, 2),
+        };
+        let holes = [line, quad, flatter, cubic];
+
+        let canonical = |order: [usize; 4]| {
+            let mut contours = vec![outer.clone()];
+            contours.extend(order.map(|index| holes[index].clone()));
+            let mut shapes = vec![CurveShape::new(contours)];
+            canonicalize(&mut shapes, None);
+            shapes
+        };
+
+        let expected = canonical([0, 1, 2, 3]);
+        assert_eq!(canonical([3, 2, 1, 0]), expected);
+        assert_eq!(canonical([2, 0, 3, 1]), expected);
+        assert_eq!(expected[0].holes(), &holes);
+    }
+
+    #[test]
+    fn repeated_smallest_vertex_breaks_ties_by_segments() {
+        // Both loops visit the same vertices; the straight loop sorts first.
+        let mut path = polygon(&[[0, 0], [2, 1], [2, 2], [0, 0], [2, 1], [2, 2]]);
+        path.segments[2] = CurveSegment::Quad {
+            ctrl: IntPoint::new(0, 2),
+            to: IntPoint::new(0, 0),
+        };
+        let mut rotated = path.clone();
+        rotated.start = IntPoint::new(0, 0);
+        rotated.segments.rotate_left(3);
+
+        rotate_to_smallest_vertex(&mut path);
+        rotate_to_smallest_vertex(&mut rotated);
+
+        assert_eq!(path, rotated);
+        assert!(matches!(path.segments[2], CurveSegment::Line { .. }));
+    }
+}
@@ -1,24 +1,35 @@
+use crate::float::CurveToFloatError;
 use crate::int::CURVE_COORDINATE_SAFETY_BITS;
 use crate::int::CurveInt;
-use crate::int::bool::approximate::CurveApproximator;
-use crate::int::bool::bounds::CurveBoundsBuffer;
-use crate::int::bool::data::{CurveEdgeData, CurveEdgeDataStore, CurveSourceSpan};
+use crate::int::bool::cleanup::clean_up_segments;
+use crate::int::bool::data::{CurveEdgeData, CurveSourceSpan};
+use crate::int::bool::diagnostics::CurveOverlayDiagnostics;
 use crate::int::bool::edge::CurveEdge;
-use crate::int::bool::planarize::CurvePlanarizer;
-use crate::int::bool::recompose::CurveRecomposer;
-use crate::int::bool::refine::CurveContainmentRefiner;
-use crate::int::bool::source::{CurveId, CurveSource};
+use crate::int::bool::filter::drop_small_contours;
+use crate::int::bool::hierarchy::CurveContourTree;
+use crate::int::bool::incremental::{CurveContourMark, CurveContourOverlap, CurvePlanarCluster};
+use crate::int::bool::order::canonicalize;
+use crate::int::bool::passthrough::ContourRoute;
+use crate::int::bool::prepared::PreparedCurveSubject;
+use crate::int::bool::source::{CurveContourSource, CurveId, CurveOrigin, CurveSource};
+use crate::int::bool::split::CurveSplitMark;
+use crate::int::bool::workspace::CurveOverlayWorkspace;
+use crate::int::curve::path::CurvePath;
 use crate::int::curve::shape::CurveShape;
 use crate::kernel::int::curve::arc::RationalArcError;
 use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::segment::Segment;
 use crate::kernel::int::normalization::canonical::{PushCanonicalSimpleParametricSegment, PushSimpleSegment};
 use alloc::vec::Vec;
 use i_overlay::core::edge_overlay::{EdgeOverlay, InputEdge};
 use i_overlay::core::fill_rule::FillRule;
-use i_overlay::core::overlay::ShapeType;
+use i_overlay::core::overlay::{ContourDirection, ShapeType};
 use i_overlay::core::overlay_rule::OverlayRule;
 use i_overlay::core::solver::Solver;
 use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_float::int::rect::IntRect;
+use i_overlay::i_shape::int::IntPoint;
+use i_overlay::i_shape::int::shape::IntShapes;
 use i_overlay::vector::edge::DataVectorShape;
 
 /// Structural error in an integer curve input.
@@ -138,6 +149,102 @@ impl core::fmt::Display for CurveOverlayOptionsError {
 
 impl core::error::Error for CurveOverlayOptionsError {}
 
+/// Pipeline stage that detected a [`CurveOverlayError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CurveOverlayStage {
+    /// Conversion of float input to the integer grid.
+    Conversion,
+    /// Reconstruction of curves from the resolved polygon topology.
+    Recomposition,
+    /// Validation and conversion of the reconstructed result.
+    Output,
+}
+
+/// Internal invariant violated while resolving a Boolean operation.
+///
+/// Returned by the `try_overlay` methods instead of panicking or returning
+/// inconsistent shapes. Use [`stage`](Self::stage) to find the failing part
+/// of the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum CurveOverlayError {
+    /// Float conversion produced an operand the integer engine rejects.
+    Conversion {
+        /// Operand whose conversion failed.
+        shape_type: ShapeType,
+        /// Structural error of the converted operand.
+        error: CurveInputError,
+    },
+    /// Two consecutive edges of a resolved contour do not share an endpoint.
+    DisconnectedEdges,
+    /// A resolved edge keeps no source curve span it can be rebuilt from.
+    UnreconstructableEdge,
+    /// A reconstructed result shape is not a valid closed curve shape.
+    InvalidOutput {
+        /// Zero-based index of the rejected result shape.
+        shape: usize,
+        /// Structural error of the rejected shape.
+        error: CurveInputError,
+    },
+    /// A reconstructed result shape cannot be converted back to float curves.
+    FloatOutput {
+        /// Zero-based index of the rejected result shape.
+        shape: usize,
+        /// Reverse conversion error.
+        error: CurveToFloatError,
+    },
+}
+
+impl CurveOverlayError {
+    /// Returns the pipeline stage that detected the error.
+    pub fn stage(&self) -> CurveOverlayStage {
+        match self {
+            Self::Conversion { .. } => CurveOverlayStage::Conversion,
+            Self::DisconnectedEdges | Self::UnreconstructableEdge => CurveOverlayStage::Recomposition,
+            Self::InvalidOutput { .. } | Self::FloatOutput { .. } => CurveOverlayStage::Output,
+        }
+    }
+}
+
+impl core::fmt::Display for CurveOverlayError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Conversion { shape_type, .. } => {
+                let operand = match shape_type {
+                    ShapeType::Subject => "subject",
+                    ShapeType::Clip => "clip",
+                };
+                write!(
+                    formatter,
+                    "float conversion produced an invalid {operand} operand"
+                )
+            }
+            Self::DisconnectedEdges => formatter.write_str("resolved contour edges are disconnected"),
+            Self::UnreconstructableEdge => {
+                formatter.write_str("resolved edge cannot be reconstructed from its source curves")
+            }
+            Self::InvalidOutput { shape, .. } => write!(formatter, "result shape {shape} is invalid"),
+            Self::FloatOutput { shape, .. } => {
+                write!(
+                    formatter,
+                    "result shape {shape} cannot be converted to float curves"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for CurveOverlayError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Conversion { error, .. } | Self::InvalidOutput { error, .. } => Some(error),
+            Self::FloatOutput { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
 /// Controls the chord approximation used to determine boolean topology.
 ///
 /// These values are expressed in the integer coordinate system. The default
@@ -146,7 +253,7 @@ impl core::error::Error for CurveOverlayOptionsError {}
 ///
 /// Construct this non-exhaustive configuration from [`Default`] and override
 /// only the values your application needs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[non_exhaustive]
 pub struct CurveOverlayOptions {
     /// Chords shorter than `2^min_chord_length_power` are accepted without
@@ -169,6 +276,46 @@ pub struct CurveOverlayOptions {
     /// Maximum number of containment-refinement passes. Zero disables the
     /// additional refinement stage.
     pub max_refinement_iterations: u32,
+    /// Certifies every intersection that splits an input curve with
+    /// interval arithmetic on the fixed-point control points. The results
+    /// are reported by
+    /// [`IntCurveOverlay::overlay_with_diagnostics`]. The default is `false`.
+    pub certified_intersections: bool,
+    /// Orientation of result contours. With the default
+    /// [`ContourDirection::CounterClockwise`], outer contours run
+    /// counter-clockwise and holes clockwise; `Clockwise` reverses both.
+    /// Curved segments are reversed together with their contour.
+    pub output_direction: ContourDirection,
+    /// Returns results in an order that depends only on their geometry.
+    /// Every contour then starts at its smallest vertex, comparing `x`
+    /// before `y`, with its segments rotated to match. Holes follow the
+    /// outer contour sorted by their vertex sequences, and shapes are sorted
+    /// by the vertex sequences of their contours. Equal vertex sequences are
+    /// ordered by segment kinds and control points. The default is `false`,
+    /// which keeps the internal traversal order.
+    pub canonical_output: bool,
+    /// Result contours that enclose fewer square units are dropped. A
+    /// failing outer contour drops its shape together with its holes; a
+    /// failing hole is filled, which also drops the islands inside it. Areas
+    /// include the curved segments. The default of `0` keeps every contour.
+    pub min_contour_area: u64,
+    /// Result contours whose area divided by their perimeter is below this
+    /// value are dropped like those below
+    /// [`min_contour_area`](Self::min_contour_area). A strip of width `w`
+    /// has a ratio of about `w / 2`, so this removes slivers left by nearly
+    /// coincident edges regardless of their length. The default of `0`
+    /// keeps every contour.
+    pub min_area_perimeter_ratio: u32,
+    /// Result segments whose control points all lie closer than this to
+    /// their start are absorbed into a neighbouring line or Bézier by moving
+    /// the shared vertex. A segment is kept when absorbing it would make the
+    /// neighbour touch another result segment or collapse its contour, so
+    /// contours stay closed and their nesting is unchanged. The default of
+    /// `0` keeps every segment.
+    pub min_segment_length: u32,
+    /// Joins consecutive result lines that continue in the same direction
+    /// into one line. The default is `false`.
+    pub merge_collinear_lines: bool,
 }
 
 impl Default for CurveOverlayOptions {
@@ -180,10 +327,19 @@ impl Default for CurveOverlayOptions {
             refinement_subdivision_power: 3,
             refinement_angle_tolerance_power: 8,
             max_refinement_iterations: 2,
+            certified_intersections: false,
+            output_direction: ContourDirection::CounterClockwise,
+            canonical_output: false,
+            min_contour_area: 0,
+            min_area_perimeter_ratio: 0,
+            min_segment_length: 0,
+            merge_collinear_lines: false,
         }
     }
 }
 
+impl Eq for CurveOverlayOptions {}
+
 impl CurveOverlayOptions {
     /// Absolute safety ceiling for local approximation subdivision.
     pub const MAX_APPROXIMATION_DEPTH: u32 = 16;
@@ -234,6 +390,56 @@ impl CurveOverlayOptions {
         self
     }
 
+    /// Sets whether intersections are certified.
+    #[must_use]
+    pub const fn with_certified_intersections(mut self, is_certified: bool) -> Self {
+        self.certified_intersections = is_certified;
+        self
+    }
+
+    /// Sets the orientation of result contours.
+    #[must_use]
+    pub const fn with_output_direction(mut self, direction: ContourDirection) -> Self {
+        self.output_direction = direction;
+        self
+    }
+
+    /// Sets whether results are returned in canonical order.
+    #[must_use]
+    pub const fn with_canonical_output(mut self, is_canonical: bool) -> Self {
+        self.canonical_output = is_canonical;
+        self
+    }
+
+    /// Sets the smallest area of a kept result contour.
+    #[must_use]
+    pub const fn with_min_contour_area(mut self, area: u64) -> Self {
+        self.min_contour_area = area;
+        self
+    }
+
+    /// Sets the smallest area-to-perimeter ratio of a kept result contour.
+    #[must_use]
+    pub const fn with_min_area_perimeter_ratio(mut self, ratio: u32) -> Self {
+        self.min_area_perimeter_ratio = ratio;
+        self
+    }
+
+    /// Sets the length below which result segments are absorbed into a
+    /// neighbour.
+    #[must_use]
+    pub const fn with_min_segment_length(mut self, length: u32) -> Self {
+        self.min_segment_length = length;
+        self
+    }
+
+    /// Sets whether consecutive collinear result lines are joined.
+    #[must_use]
+    pub const fn with_merge_collinear_lines(mut self, is_merged: bool) -> Self {
+        self.merge_collinear_lines = is_merged;
+        self
+    }
+
     /// Validates the computational safety limits of this configuration.
     pub fn validate(&self) -> Result<(), CurveOverlayOptionsError> {
         if self.max_approximation_depth > Self::MAX_APPROXIMATION_DEPTH {
@@ -264,12 +470,35 @@ impl CurveOverlayOptions {
 /// Shapes are validated when passed to [`add_subject`](Self::add_subject),
 /// [`add_clip`](Self::add_clip), or [`add_shape`](Self::add_shape). Add any
 /// number of inputs, configure the solver and approximation, then consume the
-/// builder with [`overlay`](Self::overlay).
+/// builder with [`overlay`](Self::overlay). Spatial queries that only need a
+/// yes/no answer can consume it with a predicate such as
+/// [`intersects`](Self::intersects) or [`within`](Self::within) instead.
+///
+/// Applications that run many small operations can keep one overlay and call
+/// [`overlay_and_clear`](Self::overlay_and_clear), or move a
+/// [`CurveOverlayWorkspace`] between overlays, to reuse internal buffers.
+/// Operations that resolve one fixed subject against many clips can prepare
+/// it once with [`into_prepared_subject`](Self::into_prepared_subject).
+///
+/// Operations whose inputs contain only line segments skip curve
+/// approximation and containment refinement. Line segments of mixed inputs
+/// also bypass curve approximation. Both trace and recompose their result
+/// like curved input, so a line-only contour resolves the same way whether or
+/// not the input also holds curves. A contour
+/// whose bounds touch no other input contour is copied to the output with its
+/// original segments, or dropped, without entering the pipeline, unless
+/// normalization would change those segments.
 pub struct IntCurveOverlay<I: CurveInt> {
     solver: Solver,
     options: CurveOverlayOptions,
     pub(crate) curve_sources: Vec<CurveSource<I>>,
     pub(crate) curve_edges: Vec<CurveEdge<I>>,
+    pub(crate) contours: Vec<CurveContourSource<I>>,
+    workspace: CurveOverlayWorkspace<I>,
+    diagnostics: CurveOverlayDiagnostics<I>,
+    // Polygons the result is traced from, collected only while a contour
+    // hierarchy is requested.
+    nesting: Option<IntShapes<I>>,
 }
 
 impl<I: CurveInt> IntCurveOverlay<I> {
@@ -285,9 +514,51 @@ impl<I: CurveInt> IntCurveOverlay<I> {
             options: CurveOverlayOptions::default(),
             curve_sources: Vec::with_capacity(capacity),
             curve_edges: Vec::with_capacity(capacity),
+            contours: Vec::new(),
+            workspace: CurveOverlayWorkspace::new(),
+            diagnostics: CurveOverlayDiagnostics::default(),
+            nesting: None,
         }
     }
 
+    /// Creates an empty overlay that reuses the buffers of `workspace`.
+    pub fn with_workspace(mut workspace: CurveOverlayWorkspace<I>) -> Self {
+        let mut curve_sources = core::mem::take(&mut workspace.curve_sources);
+        let mut curve_edges = core::mem::take(&mut workspace.curve_edges);
+        let mut contours = core::mem::take(&mut workspace.contours);
+        curve_sources.clear();
+        curve_edges.clear();
+        contours.clear();
+
+        Self {
+            solver: Solver::default(),
+            options: CurveOverlayOptions::default(),
+            curve_sources,
+            curve_edges,
+            contours,
+            workspace,
+            diagnostics: CurveOverlayDiagnostics::default(),
+            nesting: None,
+        }
+    }
+
+    pub(super) fn from_prepared_subject(prepared: &PreparedCurveSubject<I>) -> Self {
+        let mut overlay = Self::new().with_solver(prepared.solver);
+        overlay.options = prepared.options;
+        overlay.curve_sources.clone_from(&prepared.curve_sources);
+        overlay.contours.clone_from(&prepared.contours);
+        overlay
+    }
+
+    /// Discards all inputs and returns the internal buffers for reuse.
+    pub fn into_workspace(mut self) -> CurveOverlayWorkspace<I> {
+        self.clear();
+        self.workspace.curve_sources = self.curve_sources;
+        self.workspace.curve_edges = self.curve_edges;
+        self.workspace.contours = self.contours;
+        self.workspace
+    }
+
     /// Sets the polygon solver strategy and precision.
     #[must_use]
     pub fn with_solver(mut self, solver: Solver) -> Self {
@@ -332,71 +603,411 @@ impl<I: CurveInt> IntCurveOverlay<I> {
     /// Validates and adds a shape as a subject or clip operand.
     pub fn add_shape(&mut self, shape: CurveShape<I>, shape_type: ShapeType) -> Result<(), CurveInputError> {
         validate_shape(&shape)?;
-        let mut simple_curves = Vec::new();
-        let mut canonical_curves = Vec::new();
+        for path in shape.contours {
+            self.push_path(path, shape_type);
+        }
+
+        Ok(())
+    }
+
+    /// Converts a validated path into source curves and edges. The path may
+    /// be open.
+    pub(super) fn push_path(&mut self, path: CurvePath<I>, shape_type: ShapeType) {
+        let simple_curves = &mut self.workspace.simple_curves;
+        let canonical_curves = &mut self.workspace.canonical_curves;
+        let contour = self.contours.len();
+        let first_source = self.curve_sources.len();
+        let first_edge = self.curve_edges.len();
+        let mut current = path.start;
+
+        for (segment_index, segment) in path.segments.iter().enumerate() {
+            let (curve, end) = segment.clone().into_kernel_segment(current);
+            simple_curves.clear();
+            simple_curves.push_simple(curve);
+
+            for (piece, simple_curve) in simple_curves.drain(..).enumerate() {
+                let curve_id = CurveId(self.curve_sources.len());
+                canonical_curves.clear();
+                canonical_curves.push_canonical_simple_parametric(simple_curve);
+
+                let origin = CurveOrigin {
+                    contour,
+                    segment: segment_index,
+                    piece,
+                };
+                self.curve_sources
+                    .push(CurveSource::with_origin(simple_curve, shape_type, origin));
+
+                self.curve_edges
+                    .extend(canonical_curves.drain(..).map(|canonical| {
+                        CurveEdge::new(canonical.curve, curve_id, canonical.start, canonical.end)
+                    }));
+            }
+
+            current = end;
+        }
+
+        self.contours.push(CurveContourSource {
+            path,
+            shape_type,
+            sources: first_source..self.curve_sources.len(),
+            edges: first_edge..self.curve_edges.len(),
+        });
+    }
+
+    /// Removes every input while keeping the solver, options, and all
+    /// allocated buffers.
+    pub fn clear(&mut self) {
+        self.curve_sources.clear();
+        self.curve_edges.clear();
+        self.contours.clear();
+    }
+
+    /// Prepares every input added so far for overlays against many clips.
+    ///
+    /// The inputs keep their operand roles. Each later
+    /// [`PreparedCurveSubject::overlay`] call adds one clip to them.
+    pub fn into_prepared_subject(mut self) -> PreparedCurveSubject<I> {
+        self.prepare();
+        PreparedCurveSubject::from_prepared_edges(
+            self.solver,
+            self.options,
+            self.curve_sources,
+            self.curve_edges,
+            self.contours,
+        )
+    }
+
+    pub(super) fn prepare(&mut self) {
+        // Build a bounded local chord approximation, run one curve-aware
+        // planarization pass, then apply bounded containment refinement.
+        // Lines are their own chords and never need containment refinement.
+        let is_line_only = self.is_line_only();
+        let workspace = &mut self.workspace;
+        if !is_line_only {
+            workspace
+                .approximator
+                .approximate(&mut self.curve_edges, self.options);
+        }
+
+        let cross_radius = self.initial_snap_radius();
+        let workspace = &mut self.workspace;
+        let is_certified = self.options.certified_intersections;
+        workspace.planarizer.record_contacts(is_certified);
+        workspace
+            .planarizer
+            .planarize(&mut self.curve_edges, cross_radius, &mut workspace.bounds);
+        if is_certified {
+            self.diagnostics.certify(
+                workspace.planarizer.contacts(),
+                &self.curve_sources,
+                cross_radius.to_f64(),
+            );
+        }
+        if !is_line_only {
+            self.refine();
+        }
+    }
 
-        for contour in shape.contours {
-            let mut current = contour.start;
+    /// Prepares the pipeline contours like [`prepare`](Self::prepare), but
+    /// reuses the approximated edges, contacts, and pieces cached in
+    /// `cluster`.
+    ///
+    /// Only edge pairs that involve a contour not planarized before are
+    /// intersected, and the contacts they add are cached. A source curve is
+    /// split and refined again only when one of its edges touches the bounds
+    /// of such a contour or of a contour that left, lost a contact, or
+    /// overlaps an edge of another curve split again. Every other curve
+    /// keeps its cached pieces.
+    fn prepare_cached(&mut self, cluster: CurvePlanarCluster<'_, I>) {
+        let CurvePlanarCluster {
+            contours: planar,
+            released,
+        } = cluster;
+        let routes = self.workspace.pass_through.routes();
+        let is_pipeline: Vec<_> = routes
+            .iter()
+            .map(|&route| route == ContourRoute::Pipeline)
+            .collect();
 
-            for segment in contour.segments {
-                let (curve, end) = segment.into_kernel_segment(current);
-                simple_curves.clear();
-                simple_curves.push_simple(curve);
+        // Contours outside this pass cannot keep contacts with those inside,
+        // and the curves near their former pieces split again.
+        let mut changed_rects = released.to_vec();
+        let mut settled_keys = Vec::new();
+        for (index, cache) in planar.iter_mut().enumerate() {
+            if !is_pipeline[index] {
+                if let (true, Some(edges)) = (cache.is_planarized, &cache.edges) {
+                    changed_rects.extend(edges.iter().map(Self::edge_rect));
+                }
+                cache.reset();
+            } else if cache.is_planarized {
+                settled_keys.push((cache.key, index));
+            } else {
+                cache.reset();
+            }
+        }
+        settled_keys.sort_unstable();
+        let settled_index = |key: usize| {
+            settled_keys
+                .binary_search_by_key(&key, |&(key, _)| key)
+                .ok()
+                .map(|position| settled_keys[position].1)
+        };
 
-                for simple_curve in simple_curves.drain(..) {
-                    let curve_id = CurveId(self.curve_sources.len());
-                    canonical_curves.clear();
-                    canonical_curves.push_canonical_simple_parametric(simple_curve);
+        // Pipeline edges were compacted in contour order by `route_contours`.
+        let canonical = core::mem::take(&mut self.curve_edges);
+        let mut approximated = Vec::new();
+        let mut edge_starts = alloc::vec![0; planar.len()];
+        let mut owners = Vec::new();
+        let mut settled = Vec::new();
+        let mut dirty = alloc::vec![false; self.curve_sources.len()];
+        let mut offset = 0;
+        for (index, contour) in self.contours.iter().enumerate() {
+            if !is_pipeline[index] {
+                continue;
+            }
+            let cache = &mut planar[index];
+            let first_source = contour.sources.start;
+            let canonical = &canonical[offset..offset + contour.edges.len()];
+            offset += contour.edges.len();
+            let edges = cache.edges.get_or_insert_with(|| {
+                let mut edges = canonical.to_vec();
+                self.workspace.approximator.approximate(&mut edges, self.options);
+                for edge in &mut edges {
+                    edge.curve_id.0 -= first_source;
+                }
+                edges
+            });
 
-                    self.curve_sources
-                        .push(CurveSource::new(simple_curve, shape_type));
+            edge_starts[index] = approximated.len();
+            approximated.extend(edges.iter().map(|edge| CurveEdge {
+                curve_id: CurveId(edge.curve_id.0 + first_source),
+                ..*edge
+            }));
+            owners.resize(approximated.len(), index);
+            settled.resize(approximated.len(), cache.is_planarized);
+            if !cache.is_planarized {
+                dirty[contour.sources.clone()].fill(true);
+            }
+        }
 
-                    self.curve_edges
-                        .extend(canonical_curves.drain(..).map(|canonical| {
-                            CurveEdge::new(canonical.curve, curve_id, canonical.start, canonical.end)
-                        }));
+        // Settled curves whose edges touch a changed contour split again.
+        let edge_count = approximated.len();
+        let rects: Vec<_> = approximated
+            .iter()
+            .map(Self::edge_rect)
+            .chain(changed_rects)
+            .collect();
+        let bounds = &mut self.workspace.bounds;
+        bounds.build_rects(rects.into_iter());
+        bounds.for_each_overlap(|first, second| {
+            let is_changed = |index: usize| index >= edge_count || !settled[index];
+            for (index, other) in [(first, second), (second, first)] {
+                if index < edge_count && is_changed(other) {
+                    dirty[approximated[index].curve_id.0] = true;
+                }
+            }
+        });
+
+        let mut settled_overlaps = Vec::new();
+        for &(_, index) in &settled_keys {
+            let start = edge_starts[index];
+            let cache = &mut planar[index];
+            cache.marks.retain(|cached| {
+                let is_kept = settled_index(cached.partner).is_some();
+                if !is_kept {
+                    dirty[approximated[start + cached.mark.edge_index].curve_id.0] = true;
                 }
+                is_kept
+            });
+            cache.overlaps.retain(|overlap| {
+                let is_kept = settled_index(overlap.partner).is_some();
+                if !is_kept {
+                    dirty[approximated[start + overlap.edge].curve_id.0] = true;
+                }
+                is_kept
+            });
+            settled_overlaps.extend(cache.overlaps.iter().map(|overlap| {
+                let partner_start = edge_starts[settled_index(overlap.partner).unwrap()];
+                [start + overlap.edge, partner_start + overlap.partner_edge]
+            }));
+        }
 
-                current = end;
+        // Overlapping edges share their marks and geometry, so they split
+        // together.
+        let mut is_grown = true;
+        while is_grown {
+            is_grown = false;
+            for &[edge, partner_edge] in &settled_overlaps {
+                let curve = approximated[edge].curve_id.0;
+                let partner = approximated[partner_edge].curve_id.0;
+                if dirty[curve] != dirty[partner] {
+                    dirty[curve] = true;
+                    dirty[partner] = true;
+                    is_grown = true;
+                }
             }
         }
 
-        Ok(())
-    }
+        let mut local_index = alloc::vec![usize::MAX; edge_count];
+        let mut local_edges = Vec::new();
+        let mut local_settled = Vec::new();
+        let mut local_sources = Vec::new();
+        for (index, edge) in approximated.iter().enumerate() {
+            if dirty[edge.curve_id.0] {
+                local_index[index] = local_edges.len();
+                local_edges.push(*edge);
+                local_settled.push(settled[index]);
+                local_sources.push(index);
+            }
+        }
 
-    fn prepare(&mut self) {
-        // Build a bounded local chord approximation, run one curve-aware
-        // planarization pass, then apply bounded containment refinement.
-        CurveApproximator::new().approximate(&mut self.curve_edges, self.options);
+        let mut settled_marks = Vec::new();
+        let mut local_overlaps = Vec::new();
+        for &(_, index) in &settled_keys {
+            let start = edge_starts[index];
+            let cache = &planar[index];
+            settled_marks.extend(cache.marks.iter().filter_map(|cached| {
+                let edge_index = local_index[start + cached.mark.edge_index];
+                (edge_index != usize::MAX).then_some(CurveSplitMark {
+                    edge_index,
+                    ..cached.mark
+                })
+            }));
+        }
+        for &[edge, partner_edge] in &settled_overlaps {
+            if local_index[edge] != usize::MAX {
+                local_overlaps.push([local_index[edge], local_index[partner_edge]]);
+            }
+        }
 
-        let mut bounds = CurveBoundsBuffer::new();
-        let mut planarizer = CurvePlanarizer::new();
         let cross_radius = self.initial_snap_radius();
-        planarizer.planarize(&mut self.curve_edges, cross_radius, &mut bounds);
-        CurveContainmentRefiner::new().refine(
+        let workspace = &mut self.workspace;
+        let planarizer = &mut workspace.planarizer;
+        planarizer.record_contacts(false);
+        planarizer.planarize_with_settled(
+            &mut local_edges,
+            &local_settled,
+            &settled_marks,
+            &local_overlaps,
+            cross_radius,
+            &mut workspace.bounds,
+        );
+
+        for pair in planarizer.pair_marks() {
+            let edge = local_sources[pair.mark.edge_index];
+            let owner = owners[edge];
+            let partner = planar[owners[local_sources[pair.partner_index]]].key;
+            let mark = CurveSplitMark {
+                edge_index: edge - edge_starts[owner],
+                ..pair.mark
+            };
+            planar[owner].marks.push(CurveContourMark { mark, partner });
+        }
+        for &[edge, partner_edge] in planarizer.overlaps() {
+            let [edge, partner_edge] = [local_sources[edge], local_sources[partner_edge]];
+            let owner = owners[edge];
+            let partner = owners[partner_edge];
+            let overlap = CurveContourOverlap {
+                edge: edge - edge_starts[owner],
+                partner: planar[partner].key,
+                partner_edge: partner_edge - edge_starts[partner],
+            };
+            planar[owner].overlaps.push(overlap);
+        }
+
+        // Pieces keep the contour and source order of a full pass.
+        let mut local_pieces = local_edges.iter().peekable();
+        for (index, contour) in self.contours.iter().enumerate() {
+            if !is_pipeline[index] {
+                continue;
+            }
+            let first_source = contour.sources.start;
+            let mut cached_pieces = planar[index].pieces.iter().peekable();
+            for curve in contour.sources.clone() {
+                let relative = CurveId(curve - first_source);
+                while cached_pieces.next_if(|piece| piece.curve_id < relative).is_some() {}
+                if dirty[curve] {
+                    while let Some(piece) = local_pieces.next_if(|piece| piece.curve_id.0 == curve) {
+                        self.curve_edges.push(*piece);
+                    }
+                } else {
+                    while let Some(piece) = cached_pieces.next_if(|piece| piece.curve_id == relative) {
+                        self.curve_edges.push(CurveEdge {
+                            curve_id: CurveId(curve),
+                            ..*piece
+                        });
+                    }
+                }
+            }
+        }
+
+        if !self.is_line_only() {
+            let settled_curves: Vec<_> = dirty.iter().map(|&is_dirty| !is_dirty).collect();
+            let workspace = &mut self.workspace;
+            workspace.refiner.refine_with_settled(
+                &mut self.curve_edges,
+                &settled_curves,
+                self.options,
+                &mut workspace.bounds,
+            );
+        }
+
+        let mut start = 0;
+        for (index, contour) in self.contours.iter().enumerate() {
+            if !is_pipeline[index] {
+                continue;
+            }
+            let end = start
+                + self.curve_edges[start..].partition_point(|edge| edge.curve_id.0 < contour.sources.end);
+            let cache = &mut planar[index];
+            cache.pieces.clear();
+            cache
+                .pieces
+                .extend(self.curve_edges[start..end].iter().map(|edge| CurveEdge {
+                    curve_id: CurveId(edge.curve_id.0 - contour.sources.start),
+                    ..*edge
+                }));
+            cache.is_planarized = true;
+            start = end;
+        }
+    }
+
+    #[inline]
+    fn edge_rect(edge: &CurveEdge<I>) -> IntRect<I> {
+        IntRect::with_points(edge.curve.convex_hull().as_slice()).unwrap()
+    }
+
+    fn refine(&mut self) {
+        let workspace = &mut self.workspace;
+        workspace.refiner.refine(
             &mut self.curve_edges,
             self.options.refinement_subdivision_power,
             self.options.max_refinement_iterations,
             self.options.min_chord_length_power,
             self.options.refinement_angle_tolerance_power,
-            &mut bounds,
+            &mut workspace.bounds,
         );
     }
 
     #[inline]
-    fn initial_snap_radius(&self) -> I::Wide {
+    pub(super) fn initial_snap_radius(&self) -> I::Wide {
         let coordinate_bits = I::BITS - CURVE_COORDINATE_SAFETY_BITS;
         let max_exponent = 2 * coordinate_bits;
         I::Wide::ONE << (self.solver.precision.start as u32).min(max_exponent)
     }
 
     fn build_vector_shapes(
-        &self,
+        &mut self,
         overlay_rule: OverlayRule,
         fill_rule: FillRule,
-    ) -> (Vec<DataVectorShape<I, CurveEdgeData>>, CurveEdgeDataStore) {
+    ) -> Vec<DataVectorShape<I, CurveEdgeData>> {
         let mut edge_overlay = EdgeOverlay::new(self.curve_edges.len());
         edge_overlay.solver = self.solver;
+        edge_overlay.options.output_direction = self.options.output_direction;
+        edge_overlay.boolean_buffer = self.workspace.boolean_buffer.take();
+        self.workspace.data_store.clear();
+        core::mem::swap(edge_overlay.data_store_mut(), &mut self.workspace.data_store);
 
         for edge in &self.curve_edges {
             let chord = edge.curve.chord();
@@ -412,24 +1023,419 @@ impl<I: CurveInt> IntCurveOverlay<I> {
         }
 
         let shapes = edge_overlay.build_vector_shapes(overlay_rule, fill_rule);
-        let data_store = edge_overlay.into_data_store();
+        self.workspace.boolean_buffer = edge_overlay.boolean_buffer.take();
+        self.workspace.data_store = edge_overlay.into_data_store();
+
+        shapes
+    }
 
-        (shapes, data_store)
+    #[inline]
+    fn is_line_only(&self) -> bool {
+        self.curve_edges
+            .iter()
+            .all(|edge| matches!(edge.curve, Segment::Line(_)))
     }
 
     /// Resolves the configured Boolean operation and returns reconstructed curves.
     #[inline]
     pub fn overlay(mut self, overlay_rule: OverlayRule, fill_rule: FillRule) -> Vec<CurveShape<I>> {
-        self.prepare();
+        self.overlay_and_clear(overlay_rule, fill_rule)
+    }
+
+    /// Resolves the configured Boolean operation like [`overlay`](Self::overlay),
+    /// but returns an error instead of panicking or returning inconsistent
+    /// shapes when an internal invariant fails.
+    ///
+    /// Every result shape is validated as closed curve input before it is
+    /// returned.
+    #[inline]
+    pub fn try_overlay(
+        mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<Vec<CurveShape<I>>, CurveOverlayError> {
+        self.try_overlay_and_clear(overlay_rule, fill_rule)
+    }
+
+    /// Resolves and clears like [`overlay_and_clear`](Self::overlay_and_clear)
+    /// with the checks of [`try_overlay`](Self::try_overlay).
+    ///
+    /// The inputs are removed even when an error is returned.
+    pub fn try_overlay_and_clear(
+        &mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<Vec<CurveShape<I>>, CurveOverlayError> {
+        let shapes = self.resolve_and_clear(overlay_rule, fill_rule, None);
+        if let Some(error) = self.workspace.recomposer.take_fault() {
+            return Err(error);
+        }
+        for (index, shape) in shapes.iter().enumerate() {
+            validate_shape(shape)
+                .map_err(|error| CurveOverlayError::InvalidOutput { shape: index, error })?;
+        }
+        Ok(shapes)
+    }
+
+    /// Resolves the configured Boolean operation like [`overlay`](Self::overlay)
+    /// and returns the diagnostics of the run.
+    ///
+    /// With [`CurveOverlayOptions::certified_intersections`] set, the
+    /// diagnostics list every intersection that split an input curve
+    /// together with its certification status. Otherwise they are empty.
+    pub fn overlay_with_diagnostics(
+        mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> (Vec<CurveShape<I>>, CurveOverlayDiagnostics<I>) {
+        let shapes = self.overlay_and_clear_with(overlay_rule, fill_rule, None);
+        (shapes, core::mem::take(&mut self.diagnostics))
+    }
+
+    /// Resolves the configured Boolean operation like [`overlay`](Self::overlay)
+    /// and returns the nesting of the result contours.
+    ///
+    /// The tree links every hole to the outer contour of its shape and every
+    /// island to the hole that surrounds it.
+    pub fn overlay_with_hierarchy(
+        mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> (Vec<CurveShape<I>>, CurveContourTree) {
+        self.nesting = Some(Vec::new());
+        let shapes = self.overlay_and_clear_with(overlay_rule, fill_rule, None);
+        let polygons = self.nesting.take().unwrap_or_default();
+        debug_assert_eq!(polygons.len(), shapes.len());
+        (shapes, CurveContourTree::from_polygons(&polygons))
+    }
+
+    /// Resolves the configured Boolean operation, then removes every input.
+    ///
+    /// The solver, options, and internal buffers are kept, so the same
+    /// overlay can receive new inputs for the next operation without
+    /// reallocating its pipeline.
+    pub fn overlay_and_clear(
+        &mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Vec<CurveShape<I>> {
+        self.overlay_and_clear_with(overlay_rule, fill_rule, None)
+    }
+
+    /// Returns `true` if the subject and clip share any point, including
+    /// boundary contact.
+    ///
+    /// Like every predicate, this planarizes and classifies the inputs but
+    /// stops at the first decisive edge, without tracing or recomposing a
+    /// result. Operands whose bounds do not touch are rejected before any
+    /// curve processing.
+    #[inline]
+    pub fn intersects(mut self, fill_rule: FillRule) -> bool {
+        self.relate(CurvePredicate::Intersects, fill_rule)
+    }
+
+    /// Returns `true` if the subject and clip share no point.
+    ///
+    /// This is the negation of [`intersects`](Self::intersects).
+    #[inline]
+    pub fn disjoint(mut self, fill_rule: FillRule) -> bool {
+        !self.relate(CurvePredicate::Intersects, fill_rule)
+    }
+
+    /// Returns `true` if the boundaries of the subject and clip meet while
+    /// their interiors do not overlap.
+    #[inline]
+    pub fn touches(mut self, fill_rule: FillRule) -> bool {
+        self.relate(CurvePredicate::Touches, fill_rule)
+    }
+
+    /// Returns `true` if every filled area of the subject is also filled by
+    /// the clip. An empty subject is not within anything.
+    #[inline]
+    pub fn within(mut self, fill_rule: FillRule) -> bool {
+        self.relate(CurvePredicate::Within, fill_rule)
+    }
+
+    /// Returns `true` if every filled area of the clip is also filled by the
+    /// subject, which is [`within`](Self::within) with the roles swapped.
+    #[inline]
+    pub fn contains(mut self, fill_rule: FillRule) -> bool {
+        self.relate(CurvePredicate::Contains, fill_rule)
+    }
+
+    fn relate(&mut self, predicate: CurvePredicate, fill_rule: FillRule) -> bool {
+        let is_related = self.operands_may_meet() && self.evaluate_predicate(predicate, fill_rule);
+        self.clear();
+        is_related
+    }
+
+    fn operands_may_meet(&self) -> bool {
+        let bounds = |shape_type: ShapeType| {
+            self.curve_sources
+                .iter()
+                .filter(|source| source.shape_type == shape_type)
+                .map(|source| IntRect::with_points(source.curve.convex_hull().as_slice()).unwrap())
+                .reduce(|a, b| IntRect::with_rects(&a, &b))
+        };
+        match (bounds(ShapeType::Subject), bounds(ShapeType::Clip)) {
+            (Some(subject), Some(clip)) => subject.is_intersect_border_include(&clip),
+            _ => false,
+        }
+    }
+
+    fn evaluate_predicate(&mut self, predicate: CurvePredicate, fill_rule: FillRule) -> bool {
+        // Line-only inputs are planarized by the predicate overlay itself;
+        // curves first need their chords split at every crossing.
+        let is_line_only = self.is_line_only();
+        if !is_line_only {
+            self.prepare();
+            self.curve_edges
+                .sort_unstable_by_key(|edge| (edge.curve_id, edge.start_param.value()));
+        }
+
+        let overlay = &mut self.workspace.predicate_overlay;
+        overlay.clear();
+        overlay.solver = self.solver;
+        overlay.fill_rule = fill_rule;
+
+        let mut edges = self.curve_edges.as_slice();
+        for contour in &self.contours {
+            let shape_type = match (predicate, contour.shape_type) {
+                (CurvePredicate::Contains, ShapeType::Subject) => ShapeType::Clip,
+                (CurvePredicate::Contains, ShapeType::Clip) => ShapeType::Subject,
+                (_, shape_type) => shape_type,
+            };
+            if is_line_only {
+                let sources = &self.curve_sources[contour.sources.clone()];
+                overlay.add_path_iter(sources.iter().map(|source| source.curve.chord().a), shape_type);
+            } else {
+                // Sorted edges of one contour follow its source curves in order.
+                let count = edges
+                    .iter()
+                    .take_while(|edge| edge.curve_id.0 < contour.sources.end)
+                    .count();
+                let (contour_edges, rest) = edges.split_at(count);
+                edges = rest;
+                overlay.add_path_iter(contour_edges.iter().map(|edge| edge.curve.chord().a), shape_type);
+            }
+        }
+
+        let is_related = match predicate {
+            CurvePredicate::Intersects => overlay.intersects(),
+            CurvePredicate::Touches => overlay.touches(),
+            CurvePredicate::Within | CurvePredicate::Contains => overlay.within(),
+        };
+        overlay.clear();
+        is_related
+    }
+
+    /// Resolves and clears like [`overlay_and_clear`](Self::overlay_and_clear).
+    ///
+    /// When `planar` is given, the pipeline input is planarized from and into
+    /// that per-contour cache.
+    pub(super) fn overlay_and_clear_with(
+        &mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+        planar: Option<CurvePlanarCluster<'_, I>>,
+    ) -> Vec<CurveShape<I>> {
+        let result = self.resolve_and_clear(overlay_rule, fill_rule, planar);
+        let fault = self.workspace.recomposer.take_fault();
+        debug_assert!(fault.is_none(), "curve recomposition failed: {fault:?}");
+        result
+    }
+
+    fn resolve_and_clear(
+        &mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+        planar: Option<CurvePlanarCluster<'_, I>>,
+    ) -> Vec<CurveShape<I>> {
+        self.diagnostics.clear();
+        self.route_contours(overlay_rule, fill_rule);
+
+        let mut result = if self.curve_edges.is_empty() {
+            Vec::new()
+        } else {
+            self.overlay_curves(overlay_rule, fill_rule, planar)
+        };
+        self.append_copied_contours(&mut result);
+        clean_up_segments(&mut result, &self.options);
+        drop_small_contours(&mut result, self.nesting.as_mut(), &self.options);
+        if self.options.canonical_output {
+            canonicalize(&mut result, self.nesting.as_mut());
+        }
+        self.clear();
+        result
+    }
+
+    fn route_contours(&mut self, overlay_rule: OverlayRule, fill_rule: FillRule) {
+        let cross_radius = self.initial_snap_radius();
+        let workspace = &mut self.workspace;
+        workspace.pass_through.route(
+            &self.contours,
+            &self.curve_sources,
+            overlay_rule,
+            fill_rule,
+            cross_radius,
+            &mut workspace.bounds,
+        );
+
+        // Only contours that interact with other input enter the pipeline.
+        let mut len = 0;
+        for (contour, &route) in self.contours.iter().zip(workspace.pass_through.routes()) {
+            if route == ContourRoute::Pipeline {
+                self.curve_edges.copy_within(contour.edges.clone(), len);
+                len += contour.edges.len();
+            }
+        }
+        self.curve_edges.truncate(len);
+    }
+
+    fn append_copied_contours(&mut self, result: &mut Vec<CurveShape<I>>) {
+        // Routes orient copied outer contours counter-clockwise.
+        let is_clockwise = self.options.output_direction == ContourDirection::Clockwise;
+        for (contour, &route) in self.contours.iter_mut().zip(self.workspace.pass_through.routes()) {
+            if let ContourRoute::Copy { reverse } = route {
+                let path = if reverse != is_clockwise {
+                    contour.path.reversed()
+                } else {
+                    let start = contour.path.start;
+                    core::mem::replace(&mut contour.path, CurvePath::new(start, Vec::new()))
+                };
+                if let Some(nesting) = &mut self.nesting {
+                    nesting.push(alloc::vec![alloc::vec![path.start]]);
+                }
+                result.push(CurveShape::from_path(path));
+            }
+        }
+    }
+
+    /// Resolves the inputs added after `from_prepared_subject` against the
+    /// prepared edges.
+    ///
+    /// Contours are routed like in [`overlay`](Self::overlay), so isolated
+    /// prepared contours are copied or dropped rather than recomposed.
+    pub(super) fn overlay_prepared(
+        &mut self,
+        prepared: &PreparedCurveSubject<I>,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Vec<CurveShape<I>> {
+        // Prepared contours own no canonical edges here, so routing keeps
+        // only the new pipeline edges in `curve_edges`.
+        self.route_contours(overlay_rule, fill_rule);
+        let routes = self.workspace.pass_through.routes();
+        let sources = &self.curve_sources;
+        let is_pipeline =
+            |edge: &CurveEdge<I>| routes[sources[edge.curve_id.0].origin.contour] == ContourRoute::Pipeline;
+        let prepared_edges: Vec<_> = prepared
+            .curve_edges
+            .iter()
+            .zip(&prepared.edge_bounds)
+            .filter(|(edge, _)| is_pipeline(edge))
+            .collect();
+
+        // Only new edges need approximation; prepared edges whose bounds touch
+        // them are the only ones that can gain split points.
+        let workspace = &mut self.workspace;
+        workspace
+            .approximator
+            .approximate(&mut self.curve_edges, self.options);
+
+        let new_bounds = self
+            .curve_edges
+            .iter()
+            .map(|edge| IntRect::with_points(edge.curve.convex_hull().as_slice()).unwrap())
+            .reduce(|a, b| IntRect::with_rects(&a, &b));
+        let is_local =
+            |rect: &IntRect<I>| new_bounds.is_some_and(|bounds| bounds.is_intersect_border_include(rect));
+        self.curve_edges.extend(
+            prepared_edges
+                .iter()
+                .filter(|(_, rect)| is_local(rect))
+                .map(|(edge, _)| **edge),
+        );
+
+        let first_unsettled = CurveId(prepared.curve_sources.len());
+        let cross_radius = self.initial_snap_radius();
+        let workspace = &mut self.workspace;
+        workspace.planarizer.record_contacts(false);
+        workspace.planarizer.planarize_against(
+            &mut self.curve_edges,
+            first_unsettled,
+            cross_radius,
+            &mut workspace.bounds,
+        );
+        workspace.refiner.refine_against(
+            &mut self.curve_edges,
+            first_unsettled,
+            self.options,
+            &mut workspace.bounds,
+        );
+
+        self.curve_edges.extend(
+            prepared_edges
+                .iter()
+                .filter(|(_, rect)| !is_local(rect))
+                .map(|(edge, _)| **edge),
+        );
+
+        let mut result = if self.curve_edges.is_empty() {
+            Vec::new()
+        } else {
+            let vector_shapes = self.build_vector_shapes(overlay_rule, fill_rule);
+            self.workspace.recomposer.recompose(
+                vector_shapes,
+                &self.workspace.data_store,
+                &self.curve_sources,
+                None,
+            )
+        };
+        self.append_copied_contours(&mut result);
+        clean_up_segments(&mut result, &self.options);
+        drop_small_contours(&mut result, None, &self.options);
+        if self.options.canonical_output {
+            canonicalize(&mut result, None);
+        }
+        let fault = self.workspace.recomposer.take_fault();
+        debug_assert!(fault.is_none(), "curve recomposition failed: {fault:?}");
+        result
+    }
+
+    fn overlay_curves(
+        &mut self,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+        planar: Option<CurvePlanarCluster<'_, I>>,
+    ) -> Vec<CurveShape<I>> {
+        match planar {
+            Some(planar) => self.prepare_cached(planar),
+            None => self.prepare(),
+        }
 
         // Resolve the boolean topology while preserving CurveId provenance.
-        let (vector_shapes, data_store) = self.build_vector_shapes(overlay_rule, fill_rule);
+        let vector_shapes = self.build_vector_shapes(overlay_rule, fill_rule);
 
         // Restore maximal runs from their source curves and parameter spans.
-        CurveRecomposer::new().recompose(vector_shapes, &data_store, &self.curve_sources)
+        self.workspace.recomposer.recompose(
+            vector_shapes,
+            &self.workspace.data_store,
+            &self.curve_sources,
+            self.nesting.as_mut(),
+        )
     }
 }
 
+/// Spatial relation evaluated by the predicate methods of [`IntCurveOverlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CurvePredicate {
+    Intersects,
+    Touches,
+    Within,
+    Contains,
+}
+
 impl<I: CurveInt> Default for IntCurveOverlay<I> {
     fn default() -> Self {
         Self::new()
@@ -466,55 +1472,65 @@ pub(crate) fn validate_shape<I: CurveInt>(shape: &CurveShape<I>) -> Result<(), C
     }
 
     for (contour_index, contour) in shape.contours.iter().enumerate() {
-        if contour.segments.is_empty() {
-            return Err(CurveInputError::EmptyContour {
+        let end = validate_path(contour, contour_index)?;
+        if end != contour.start {
+            return Err(CurveInputError::UnclosedContour {
                 contour: contour_index,
             });
         }
+    }
 
-        let mut current = contour.start;
-        for (segment_index, segment) in contour.segments.iter().enumerate() {
-            current = match segment {
-                crate::int::curve::segment::CurveSegment::Line { to }
-                | crate::int::curve::segment::CurveSegment::Quad { to, .. }
-                | crate::int::curve::segment::CurveSegment::Cubic { to, .. } => *to,
-                crate::int::curve::segment::CurveSegment::Arc { arc } => {
-                    if arc.control_points[0] != current {
-                        return Err(CurveInputError::DisconnectedArc {
-                            contour: contour_index,
-                            segment: segment_index,
-                        });
-                    }
-                    arc.validate().map_err(|error| CurveInputError::InvalidArc {
+    Ok(())
+}
+
+/// Validates the segments of a possibly open path and returns its end point.
+pub(crate) fn validate_path<I: CurveInt>(
+    path: &CurvePath<I>,
+    contour_index: usize,
+) -> Result<IntPoint<I>, CurveInputError> {
+    if path.segments.is_empty() {
+        return Err(CurveInputError::EmptyContour {
+            contour: contour_index,
+        });
+    }
+
+    let mut current = path.start;
+    for (segment_index, segment) in path.segments.iter().enumerate() {
+        current = match segment {
+            crate::int::curve::segment::CurveSegment::Line { to }
+            | crate::int::curve::segment::CurveSegment::Quad { to, .. }
+            | crate::int::curve::segment::CurveSegment::Cubic { to, .. } => *to,
+            crate::int::curve::segment::CurveSegment::Arc { arc } => {
+                if arc.control_points[0] != current {
+                    return Err(CurveInputError::DisconnectedArc {
                         contour: contour_index,
                         segment: segment_index,
-                        error,
-                    })?;
-                    arc.control_points[2]
+                    });
                 }
-            };
-        }
-
-        if current != contour.start {
-            return Err(CurveInputError::UnclosedContour {
-                contour: contour_index,
-            });
-        }
+                arc.validate().map_err(|error| CurveInputError::InvalidArc {
+                    contour: contour_index,
+                    segment: segment_index,
+                    error,
+                })?;
+                arc.control_points[2]
+            }
+        };
     }
 
-    Ok(())
+    Ok(current)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::int::bool::diagnostics::CurveIntersectionStatus;
+    use crate::int::bool::recompose::CurveRecomposer;
     use crate::int::curve::path::CurvePath;
     use crate::int::curve::segment::CurveSegment;
     use crate::kernel::int::curve::arc::{ArcDirection, ArcPhase, ArcSegment, ArcVector, EllipseFrame};
     use crate::kernel::int::curve::segment::Segment;
     use alloc::vec;
     use i_overlay::i_float::int::number::fixed_scale::FixedScale;
-    use i_overlay::i_shape::int::IntPoint;
 
     fn circle(center: IntPoint<i32>) -> CurveShape<i32> {
         let one = FixedScale::<i32>::DENOMINATOR as i32;
@@ -574,12 +1590,7 @@ mod tests {
                 segments: vec![CurveSegment::Line { to: p1 }, CurveSegment::Line { to: p0 }],
             }],
         };
-        let mut overlay = IntCurveOverlay {
-            solver: Solver::default(),
-            options: CurveOverlayOptions::default(),
-            curve_sources: Vec::new(),
-            curve_edges: Vec::new(),
-        };
+        let mut overlay = IntCurveOverlay::new();
 
         overlay.add_shape(shape, ShapeType::Subject).unwrap();
 
@@ -615,12 +1626,7 @@ mod tests {
                 ],
             }],
         };
-        let mut overlay = IntCurveOverlay {
-            solver: Solver::default(),
-            options: CurveOverlayOptions::default(),
-            curve_sources: Vec::new(),
-            curve_edges: Vec::new(),
-        };
+        let mut overlay = IntCurveOverlay::new();
 
         overlay.add_shape(shape, ShapeType::Clip).unwrap();
 
@@ -648,12 +1654,7 @@ mod tests {
                 ],
             }],
         };
-        let mut overlay = IntCurveOverlay {
-            solver: Solver::default(),
-            options: CurveOverlayOptions::default(),
-            curve_sources: Vec::new(),
-            curve_edges: Vec::new(),
-        };
+        let mut overlay = IntCurveOverlay::new();
 
         overlay.add_shape(shape, ShapeType::Subject).unwrap();
 
@@ -695,12 +1696,7 @@ mod tests {
                 ],
             }],
         };
-        let mut overlay = IntCurveOverlay {
-            solver: Solver::default(),
-            options: CurveOverlayOptions::default(),
-            curve_sources: Vec::new(),
-            curve_edges: Vec::new(),
-        };
+        let mut overlay = IntCurveOverlay::new();
 
         overlay.add_shape(shape, ShapeType::Subject).unwrap();
 
@@ -735,12 +1731,7 @@ mod tests {
                 ],
             }],
         };
-        let mut overlay = IntCurveOverlay {
-            solver: Solver::default(),
-            options: CurveOverlayOptions::default(),
-            curve_sources: Vec::new(),
-            curve_edges: Vec::new(),
-        };
+        let mut overlay = IntCurveOverlay::new();
 
         overlay.add_shape(shape, ShapeType::Clip).unwrap();
 
@@ -838,17 +1829,13 @@ mod tests {
             }
         }
 
-        let mut overlay = IntCurveOverlay {
-            solver: Solver::default(),
-            options: CurveOverlayOptions::default(),
-            curve_sources: Vec::new(),
-            curve_edges: Vec::new(),
-        };
+        let mut overlay = IntCurveOverlay::new();
         overlay.add_shape(square(), ShapeType::Subject).unwrap();
         overlay.add_shape(square(), ShapeType::Clip).unwrap();
         overlay.prepare();
 
-        let (shapes, store) = overlay.build_vector_shapes(OverlayRule::Intersect, FillRule::NonZero);
+        let shapes = overlay.build_vector_shapes(OverlayRule::Intersect, FillRule::NonZero);
+        let store = &overlay.workspace.data_store;
 
         assert_eq!(shapes.len(), 1);
         assert_eq!(shapes[0].len(), 1);
@@ -865,7 +1852,7 @@ mod tests {
             assert_ne!(first_type, second_type);
         }
 
-        let result = CurveRecomposer::new().recompose(shapes, &store, &overlay.curve_sources);
+        let result = CurveRecomposer::new().recompose(shapes, store, &overlay.curve_sources, None);
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].contours.len(), 1);
         assert_eq!(result[0].contours[0].segments.len(), 4);
@@ -898,6 +1885,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tangent_circles_touch_without_overlap() {
+        let subject = circle(IntPoint::new(0, 0));
+        let clip = circle(IntPoint::new(120, 160));
+
+        let intersection = overlay(
+            subject.clone(),
+            clip.clone(),
+            OverlayRule::Intersect,
+            FillRule::NonZero,
+        );
+        assert_eq!(intersection, Ok(vec![]));
+
+        let union = overlay(subject, clip, OverlayRule::Union, FillRule::NonZero).unwrap();
+        let segments: Vec<_> = union
+            .iter()
+            .flat_map(|shape| &shape.contours)
+            .flat_map(|contour| &contour.segments)
+            .collect();
+        assert!(
+            segments
+                .iter()
+                .all(|segment| matches!(segment, CurveSegment::Arc { .. }))
+        );
+        assert!(segments.len() >= 8);
+    }
+
+    #[test]
+    fn parcels_sharing_a_split_cubic_boundary_merge_without_slivers() {
+        let line = |x, y| CurveSegment::Line {
+            to: IntPoint::new(x, y),
+        };
+        let cubic = |ctrl0: [i32; 2], ctrl1: [i32; 2], to: [i32; 2]| CurveSegment::Cubic {
+            ctrl0: ctrl0.into(),
+            ctrl1: ctrl1.into(),
+            to: to.into(),
+        };
+        let below = CurveShape {
+            contours: vec![CurvePath {
+                start: IntPoint::new(0, 0),
+                segments: vec![
+                    cubic([0, 1_024], [1_024, 1_024], [1_024, 0]),
+                    line(1_024, -512),
+                    line(0, -512),
+                    line(0, 0),
+                ],
+            }],
+        };
+        let above = CurveShape {
+            contours: vec![CurvePath {
+                start: IntPoint::new(0, 0),
+                segments: vec![
+                    line(0, 1_024),
+                    line(1_024, 1_024),
+                    line(1_024, 0),
+                    cubic([1_024, 512], [768, 768], [512, 768]),
+                    cubic([256, 768], [0, 512], [0, 0]),
+                ],
+            }],
+        };
+
+        let intersection = overlay(
+            below.clone(),
+            above.clone(),
+            OverlayRule::Intersect,
+            FillRule::NonZero,
+        );
+        assert_eq!(intersection, Ok(vec![]));
+
+        let union = overlay(below, above, OverlayRule::Union, FillRule::NonZero).unwrap();
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].contours.len(), 1);
+        assert!(
+            union[0].contours[0]
+                .segments
+                .iter()
+                .all(|segment| matches!(segment, CurveSegment::Line { .. }))
+        );
+    }
+
     #[test]
     fn overlapping_circles_recompose_split_boundaries_as_arcs() {
         let mut overlay = IntCurveOverlay::with_capacity(8);
@@ -921,6 +1988,605 @@ mod tests {
         );
     }
 
+    fn rectangle(x0: i32, y0: i32, x1: i32, y1: i32) -> CurveShape<i32> {
+        let line = |x, y| CurveSegment::Line {
+            to: IntPoint::new(x, y),
+        };
+        CurveShape::from_path(CurvePath::new(
+            IntPoint::new(x0, y0),
+            vec![line(x1, y0), line(x1, y1), line(x0, y1), line(x0, y0)],
+        ))
+    }
+
+    fn relate(subject: CurveShape<i32>, clip: CurveShape<i32>) -> IntCurveOverlay<i32> {
+        let mut overlay = IntCurveOverlay::new();
+        overlay.add_subject(subject).unwrap();
+        overlay.add_clip(clip).unwrap();
+        overlay
+    }
+
+    #[test]
+    fn predicates_classify_curved_operands() {
+        let center = IntPoint::new(0, 0);
+        let cases = [
+            (IntPoint::new(100, 0), [true, false, false, false, false]),
+            (IntPoint::new(400, 0), [false, true, false, false, false]),
+            (center, [true, false, false, true, true]),
+        ];
+
+        for (clip_center, expected) in cases {
+            let predicates = [
+                IntCurveOverlay::intersects,
+                IntCurveOverlay::disjoint,
+                IntCurveOverlay::touches,
+                IntCurveOverlay::within,
+                IntCurveOverlay::contains,
+            ];
+            for (predicate, expected) in predicates.into_iter().zip(expected) {
+                let overlay = relate(circle(center), circle(clip_center));
+                assert_eq!(predicate(overlay, FillRule::NonZero), expected, "{clip_center:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn predicates_distinguish_containment_roles() {
+        let disk = circle(IntPoint::new(0, 0));
+        let frame = rectangle(-150, -150, 150, 150);
+
+        assert!(relate(disk.clone(), frame.clone()).within(FillRule::NonZero));
+        assert!(!relate(disk.clone(), frame.clone()).contains(FillRule::NonZero));
+        assert!(relate(frame.clone(), disk.clone()).contains(FillRule::NonZero));
+        assert!(!relate(frame.clone(), disk.clone()).touches(FillRule::NonZero));
+
+        // The side of the rectangle meets the disk at the end of an arc.
+        let side = rectangle(100, -100, 300, 100);
+        assert!(relate(disk.clone(), side.clone()).touches(FillRule::NonZero));
+        assert!(relate(side, disk).intersects(FillRule::NonZero));
+
+        // Line-only operands sharing an edge skip curve processing.
+        let neighbour = rectangle(150, -150, 300, 150);
+        assert!(relate(frame.clone(), neighbour.clone()).touches(FillRule::NonZero));
+        assert!(!relate(frame.clone(), neighbour).within(FillRule::NonZero));
+
+        let mut subject_only = IntCurveOverlay::new();
+        subject_only.add_subject(frame).unwrap();
+        assert!(!subject_only.intersects(FillRule::NonZero));
+        assert!(!IntCurveOverlay::<i32>::new().within(FillRule::NonZero));
+    }
+
+    #[test]
+    fn hierarchy_nests_curved_holes_and_islands() {
+        let mut contours = rectangle(-200, -200, 200, 200).contours;
+        contours.extend(circle(IntPoint::new(0, 0)).contours);
+        contours.extend(rectangle(-50, -50, 50, 50).contours);
+        let mut overlay = IntCurveOverlay::new();
+        overlay.add_subject(CurveShape::new(contours)).unwrap();
+
+        let (shapes, tree) = overlay.overlay_with_hierarchy(OverlayRule::Subject, FillRule::EvenOdd);
+
+        assert_eq!(shapes.len(), 2);
+        let nodes = tree.nodes();
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(
+            nodes.iter().map(|node| node.depth).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(nodes[1].parent, Some(0));
+        assert_eq!(nodes[2].parent, Some(1));
+        assert!(
+            shapes[nodes[1].shape].holes()[0]
+                .segments
+                .iter()
+                .all(|segment| matches!(segment, CurveSegment::Arc { .. }))
+        );
+        assert_eq!(shapes[nodes[2].shape].holes().len(), 0);
+    }
+
+    #[test]
+    fn hierarchy_places_copied_contours_inside_polygon_holes() {
+        let bars = [
+            rectangle(0, 0, 100, 20),
+            rectangle(80, 0, 100, 100),
+            rectangle(0, 80, 100, 100),
+            rectangle(0, 0, 20, 100),
+        ];
+        let mut overlay = IntCurveOverlay::new();
+        for bar in bars {
+            overlay.add_subject(bar).unwrap();
+        }
+        overlay.add_clip(rectangle(40, 40, 60, 60)).unwrap();
+
+        let (shapes, tree) = overlay.overlay_with_hierarchy(OverlayRule::Union, FillRule::NonZero);
+
+        assert_eq!(shapes.len(), 2);
+        let island = tree.nodes().iter().find(|node| node.depth == 2).unwrap();
+        assert_eq!(shapes[island.shape], rectangle(40, 40, 60, 60));
+        assert_eq!(island.parent, tree.find(1 - island.shape, 1));
+        assert_eq!(tree.roots().count(), 1);
+    }
+
+    #[test]
+    fn output_direction_orients_curved_and_copied_contours() {
+        fn doubled_area(path: &CurvePath<i32>) -> i64 {
+            let mut points = vec![path.start];
+            points.extend(path.segments.iter().map(CurveSegment::end_point));
+            points.windows(2).map(|pair| pair[0].cross_product(pair[1])).sum()
+        }
+
+        let run = |direction| {
+            let options = CurveOverlayOptions::default().with_output_direction(direction);
+            let mut overlay = IntCurveOverlay::new().try_with_options(options).unwrap();
+            overlay.add_subject(rectangle(-200, -200, 200, 200)).unwrap();
+            overlay.add_subject(rectangle(1000, 1000, 1100, 1100)).unwrap();
+            overlay.add_clip(circle(IntPoint::new(0, 0))).unwrap();
+            let mut shapes = overlay.overlay(OverlayRule::Difference, FillRule::NonZero);
+            shapes.sort_by_key(|shape| shape.contours.len());
+            shapes
+        };
+        let counter_clockwise = run(ContourDirection::CounterClockwise);
+        let clockwise = run(ContourDirection::Clockwise);
+
+        assert_eq!(clockwise.len(), 2);
+        for (ccw, cw) in counter_clockwise.iter().zip(&clockwise) {
+            assert!(validate_shape(cw).is_ok());
+            assert_eq!(ccw.contours.len(), cw.contours.len());
+            assert!(doubled_area(ccw.outer().unwrap()) > 0);
+            assert!(doubled_area(cw.outer().unwrap()) < 0);
+            for (ccw_hole, cw_hole) in ccw.holes().iter().zip(cw.holes()) {
+                assert!(doubled_area(ccw_hole) < 0);
+                assert_eq!(doubled_area(cw_hole), -doubled_area(ccw_hole));
+                assert!(
+                    cw_hole
+                        .segments
+                        .iter()
+                        .all(|segment| matches!(segment, CurveSegment::Arc { .. }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_output_ignores_input_order() {
+        let run = |reverse_inputs: bool| {
+            let mut inputs = vec![
+                (rectangle(-200, -200, 200, 200), ShapeType::Subject),
+                (rectangle(300, -50, 400, 50), ShapeType::Subject),
+                (rectangle(-50, -50, 50, 50), ShapeType::Subject),
+                (circle(IntPoint::new(0, 0)), ShapeType::Clip),
+            ];
+            if reverse_inputs {
+                inputs.reverse();
+            }
+            let options = CurveOverlayOptions::default().with_canonical_output(true);
+            let mut overlay = IntCurveOverlay::new().try_with_options(options).unwrap();
+            for (shape, shape_type) in inputs {
+                overlay.add_shape(shape, shape_type).unwrap();
+            }
+            overlay.overlay_with_hierarchy(OverlayRule::Xor, FillRule::EvenOdd)
+        };
+
+        let (shapes, tree) = run(false);
+        assert_eq!((shapes.clone(), tree.clone()), run(true));
+
+        assert_eq!(shapes.len(), 3);
+        assert_eq!(shapes[0].outer().unwrap().start, IntPoint::new(-200, -200));
+        assert_eq!(shapes[2].outer().unwrap().start, IntPoint::new(300, -50));
+        for shape in &shapes {
+            for path in &shape.contours {
+                let smallest = path.segments.iter().map(CurveSegment::end_point).min();
+                assert_eq!(smallest, Some(path.start));
+            }
+        }
+        let island = tree.nodes().iter().find(|node| node.depth == 2).unwrap();
+        assert_eq!(island.shape, 1);
+        assert_eq!(island.parent, tree.find(0, 1));
+    }
+
+    #[test]
+    fn small_contours_and_slivers_are_dropped_before_output() {
+        let options = CurveOverlayOptions::default()
+            .with_min_contour_area(16)
+            .with_min_area_perimeter_ratio(1);
+        let mut overlay = IntCurveOverlay::new().try_with_options(options).unwrap();
+        overlay.add_subject(rectangle(-200, -200, 200, 200)).unwrap();
+        overlay.add_subject(rectangle(300, -50, 400, 50)).unwrap();
+        overlay.add_clip(circle(IntPoint::new(0, 0))).unwrap();
+        overlay.add_clip(rectangle(150, 150, 152, 152)).unwrap();
+        overlay.add_clip(rectangle(300, -50, 400, 49)).unwrap();
+
+        let (shapes, tree) = overlay.overlay_with_hierarchy(OverlayRule::Difference, FillRule::NonZero);
+
+        // The sliver above the second clip and the tiny hole are gone; the
+        // circular hole is kept.
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0].holes().len(), 1);
+        assert!(
+            shapes[0].holes()[0]
+                .segments
+                .iter()
+                .any(|segment| matches!(segment, CurveSegment::Arc { .. }))
+        );
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.nodes()[1].parent, Some(0));
+    }
+
+    #[test]
+    fn micro_segments_are_absorbed_without_opening_contours() {
+        let options = CurveOverlayOptions::default()
+            .with_min_segment_length(2)
+            .with_merge_collinear_lines(true);
+        let mut overlay = IntCurveOverlay::new().try_with_options(options).unwrap();
+        overlay.add_subject(rectangle(-100, -100, 0, 0)).unwrap();
+        overlay.add_subject(circle(IntPoint::new(300, 0))).unwrap();
+        overlay.add_clip(rectangle(0, -99, 100, 1)).unwrap();
+        overlay.add_clip(rectangle(300, 0, 500, 100)).unwrap();
+
+        let result = overlay.overlay(OverlayRule::Union, FillRule::NonZero);
+
+        assert_eq!(result.len(), 2);
+        for shape in &result {
+            assert_eq!(validate_shape(shape), Ok(()));
+            let path = &shape.contours[0];
+            let mut start = path.start;
+            for segment in &path.segments {
+                let end = segment.end_point();
+                assert!(start.sqr_distance(end) >= 4, "{start:?} -> {end:?}");
+                start = end;
+            }
+        }
+        let rectangles = result
+            .iter()
+            .find(|shape| shape.contours[0].start.x < 200)
+            .unwrap();
+        assert_eq!(rectangles.contours[0].segments.len(), 6);
+        let disk = result
+            .iter()
+            .find(|shape| shape.contours[0].start.x >= 200)
+            .unwrap();
+        assert!(
+            disk.contours[0]
+                .segments
+                .iter()
+                .any(|segment| matches!(segment, CurveSegment::Arc { .. }))
+        );
+    }
+
+    #[test]
+    fn certified_mode_encloses_circle_crossings() {
+        let options = CurveOverlayOptions::default().with_certified_intersections(true);
+        let mut overlay = IntCurveOverlay::new().try_with_options(options).unwrap();
+        overlay.add_subject(circle(IntPoint::new(0, 0))).unwrap();
+        overlay.add_clip(circle(IntPoint::new(100, 0))).unwrap();
+
+        let (result, diagnostics) =
+            overlay.overlay_with_diagnostics(OverlayRule::Intersect, FillRule::NonZero);
+
+        assert_eq!(result.len(), 1);
+        assert!(diagnostics.is_certified());
+        let intersections = diagnostics.intersections();
+        assert_eq!(intersections.len(), 2);
+        for intersection in intersections {
+            assert_eq!(intersection.point.x, 50);
+            assert!((intersection.point.y.abs() - 87).abs() <= 1);
+
+            let [subject, clip] = intersection.curves;
+            assert_eq!((subject.shape_type, subject.contour), (ShapeType::Subject, 0));
+            assert_eq!((clip.shape_type, clip.contour), (ShapeType::Clip, 1));
+            for side in intersection.curves {
+                assert!(side.start.value() <= side.end.value());
+                assert!(side.end.value() - side.start.value() < 1 << 10);
+            }
+        }
+    }
+
+    #[test]
+    fn certified_mode_flags_tangent_contacts() {
+        let arch = CurveShape {
+            contours: vec![CurvePath {
+                start: IntPoint::new(0, 0),
+                segments: vec![
+                    CurveSegment::Cubic {
+                        ctrl0: IntPoint::new(0, 1024),
+                        ctrl1: IntPoint::new(1024, 1024),
+                        to: IntPoint::new(1024, 0),
+                    },
+                    CurveSegment::Line {
+                        to: IntPoint::new(0, 0),
+                    },
+                ],
+            }],
+        };
+        let lid = CurveShape {
+            contours: vec![CurvePath {
+                start: IntPoint::new(-256, 768),
+                segments: vec![
+                    CurveSegment::Line {
+                        to: IntPoint::new(1280, 768),
+                    },
+                    CurveSegment::Line {
+                        to: IntPoint::new(1280, 1024),
+                    },
+                    CurveSegment::Line {
+                        to: IntPoint::new(-256, 1024),
+                    },
+                    CurveSegment::Line {
+                        to: IntPoint::new(-256, 768),
+                    },
+                ],
+            }],
+        };
+        let options = CurveOverlayOptions::default().with_certified_intersections(true);
+        let mut overlay = IntCurveOverlay::new().try_with_options(options).unwrap();
+        overlay.add_subject(arch).unwrap();
+        overlay.add_clip(lid).unwrap();
+
+        let (_, diagnostics) = overlay.overlay_with_diagnostics(OverlayRule::Union, FillRule::NonZero);
+
+        assert!(!diagnostics.is_certified());
+        assert!(diagnostics.uncertified().any(|intersection| {
+            intersection.point == IntPoint::new(512, 768)
+                && intersection.status == CurveIntersectionStatus::Uncertified
+        }));
+    }
+
+    #[test]
+    fn try_overlay_matches_overlay() {
+        let build = || {
+            let mut overlay = IntCurveOverlay::new();
+            overlay.add_subject(circle(IntPoint::new(0, 0))).unwrap();
+            overlay.add_clip(circle(IntPoint::new(120, 60))).unwrap();
+            overlay
+        };
+
+        let expected = build().overlay(OverlayRule::Union, FillRule::NonZero);
+        let result = build()
+            .try_overlay(OverlayRule::Union, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result, expected);
+
+        let mut overlay = build();
+        let result = overlay
+            .try_overlay_and_clear(OverlayRule::Intersect, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(overlay.curve_sources.is_empty());
+    }
+
+    #[test]
+    fn overlay_errors_name_their_stage() {
+        let conversion = CurveOverlayError::Conversion {
+            shape_type: ShapeType::Clip,
+            error: CurveInputError::EmptyShape,
+        };
+        assert_eq!(conversion.stage(), CurveOverlayStage::Conversion);
+        assert_eq!(
+            CurveOverlayError::DisconnectedEdges.stage(),
+            CurveOverlayStage::Recomposition
+        );
+        let output = CurveOverlayError::InvalidOutput {
+            shape: 0,
+            error: CurveInputError::UnclosedContour { contour: 1 },
+        };
+        assert_eq!(output.stage(), CurveOverlayStage::Output);
+        assert_eq!(alloc::format!("{output}"), "result shape 0 is invalid");
+    }
+
+    #[test]
+    fn diagnostics_are_empty_without_certified_mode() {
+        let mut overlay = IntCurveOverlay::new();
+        overlay.add_subject(circle(IntPoint::new(0, 0))).unwrap();
+        overlay.add_clip(circle(IntPoint::new(100, 0))).unwrap();
+
+        let (result, diagnostics) = overlay.overlay_with_diagnostics(OverlayRule::Union, FillRule::NonZero);
+
+        assert_eq!(result.len(), 1);
+        assert!(diagnostics.intersections().is_empty());
+    }
+
+    #[test]
+    fn overlay_and_clear_reuses_buffers_without_changing_results() {
+        let fresh = overlay(
+            circle(IntPoint::new(0, 0)),
+            circle(IntPoint::new(100, 0)),
+            OverlayRule::Union,
+            FillRule::NonZero,
+        )
+        .unwrap();
+
+        let mut overlay = IntCurveOverlay::new();
+        for _ in 0..3 {
+            overlay.add_subject(circle(IntPoint::new(0, 0))).unwrap();
+            overlay.add_clip(circle(IntPoint::new(100, 0))).unwrap();
+            let capacity = overlay.curve_edges.capacity();
+
+            assert_eq!(
+                overlay.overlay_and_clear(OverlayRule::Union, FillRule::NonZero),
+                fresh
+            );
+            assert!(overlay.curve_sources.is_empty());
+            assert!(overlay.curve_edges.is_empty());
+            assert!(overlay.curve_edges.capacity() >= capacity);
+        }
+
+        let workspace = overlay.into_workspace();
+        let edge_capacity = workspace.curve_edges.capacity();
+        let mut overlay = IntCurveOverlay::with_workspace(workspace);
+        assert_eq!(overlay.curve_edges.capacity(), edge_capacity);
+
+        overlay.add_subject(circle(IntPoint::new(0, 0))).unwrap();
+        overlay.add_clip(circle(IntPoint::new(100, 0))).unwrap();
+        assert_eq!(overlay.overlay(OverlayRule::Union, FillRule::NonZero), fresh);
+    }
+
+    #[test]
+    fn line_only_results_do_not_depend_on_curved_contours() {
+        fn polygon(points: &[[i32; 2]]) -> CurveShape<i32> {
+            let start = IntPoint::new(points[0][0], points[0][1]);
+            let segments = points[1..]
+                .iter()
+                .chain(core::iter::once(&points[0]))
+                .map(|&[x, y]| CurveSegment::Line {
+                    to: IntPoint::new(x, y),
+                })
+                .collect();
+            CurveShape::from_path(CurvePath::new(start, segments))
+        }
+
+        let subject = polygon(&[[0, 0], [50, 0], [100, 0], [100, 100], [0, 100]]);
+        let clip = polygon(&[[60, 60], [160, 60], [160, 160], [60, 160]]);
+        for rule in [OverlayRule::Union, OverlayRule::Xor] {
+            let mut overlay = IntCurveOverlay::new();
+            overlay.add_subject(subject.clone()).unwrap();
+            overlay.add_clip(clip.clone()).unwrap();
+            assert!(overlay.is_line_only());
+            let lines = overlay.overlay_and_clear(rule, FillRule::NonZero);
+
+            overlay.add_subject(subject.clone()).unwrap();
+            overlay.add_clip(clip.clone()).unwrap();
+            // Overlapping circles far away send their contours through the
+            // pipeline as well.
+            overlay.add_subject(circle(IntPoint::new(10_000, 0))).unwrap();
+            overlay.add_clip(circle(IntPoint::new(10_050, 0))).unwrap();
+            let mixed = overlay.overlay_and_clear(rule, FillRule::NonZero);
+
+            assert!(lines.iter().all(|shape| mixed.contains(shape)));
+            assert!(mixed.len() > lines.len());
+            let vertex = CurveSegment::Line {
+                to: IntPoint::new(50, 0),
+            };
+            let mut contours = lines.iter().flat_map(|shape| &shape.contours);
+            assert!(contours.any(|contour| contour.segments.contains(&vertex)));
+        }
+    }
+
+    #[test]
+    fn isolated_contours_bypass_the_pipeline() {
+        let far = circle(IntPoint::new(1_000, 0)).contours.remove(0);
+        let mut subject = circle(IntPoint::new(0, 0));
+        subject.contours.push(far.clone());
+
+        let union = overlay(
+            subject.clone(),
+            circle(IntPoint::new(100, 0)),
+            OverlayRule::Union,
+            FillRule::NonZero,
+        )
+        .unwrap();
+        assert_eq!(union.len(), 2);
+        assert_eq!(union[1].contours, vec![far.clone()]);
+
+        let intersect = overlay(
+            subject,
+            circle(IntPoint::new(100, 0)),
+            OverlayRule::Intersect,
+            FillRule::NonZero,
+        )
+        .unwrap();
+        assert_eq!(intersect.len(), 1);
+        assert!(
+            intersect[0].contours[0]
+                .segments
+                .iter()
+                .all(|segment| segment.end_point().x < 500)
+        );
+
+        let reversed = far.reversed();
+        let positive = overlay(
+            CurveShape::from_path(reversed.clone()),
+            circle(IntPoint::new(0, 0)),
+            OverlayRule::Union,
+            FillRule::Positive,
+        )
+        .unwrap();
+        assert_eq!(positive.len(), 1);
+        assert!(
+            positive[0].contours[0]
+                .segments
+                .iter()
+                .all(|segment| segment.end_point().x < 500)
+        );
+
+        let negative = overlay(
+            CurveShape::from_path(reversed),
+            circle(IntPoint::new(0, 0)),
+            OverlayRule::Union,
+            FillRule::Negative,
+        )
+        .unwrap();
+        assert_eq!(negative.len(), 1);
+        assert_eq!(negative[0].contours, vec![far]);
+    }
+
+    #[test]
+    fn isolated_degenerate_contours_are_normalized() {
+        let corners = [[1_000, 0], [1_100, 0], [1_100, 100], [1_000, 100]].map(|[x, y]| IntPoint::new(x, y));
+        let square = CurveShape::from_path(CurvePath::new(
+            corners[0],
+            vec![
+                CurveSegment::Line { to: corners[1] },
+                CurveSegment::Line { to: corners[1] },
+                CurveSegment::Quad {
+                    ctrl: IntPoint::new(1_100, 50),
+                    to: corners[2],
+                },
+                CurveSegment::Line { to: corners[3] },
+                CurveSegment::Line { to: corners[0] },
+            ],
+        ));
+
+        let result = overlay(
+            square,
+            circle(IntPoint::new(0, 0)),
+            OverlayRule::Union,
+            FillRule::NonZero,
+        )
+        .unwrap();
+
+        let contour = result
+            .iter()
+            .map(|shape| &shape.contours[0])
+            .find(|contour| contour.start.x >= 1_000)
+            .unwrap();
+        assert_eq!(contour.segments.len(), 4);
+        for segment in &contour.segments {
+            let CurveSegment::Line { to } = segment else {
+                panic!("expected line segment");
+            };
+            assert!(corners.contains(to));
+        }
+    }
+
+    #[test]
+    fn isolated_self_intersecting_contour_is_resolved() {
+        let p0 = IntPoint::new(1_000, 0);
+        let bow_tie = CurveShape::from_path(CurvePath::new(
+            p0,
+            vec![
+                CurveSegment::Line {
+                    to: IntPoint::new(1_100, 100),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(1_100, 0),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(1_000, 100),
+                },
+                CurveSegment::Line { to: p0 },
+            ],
+        ));
+
+        let result = overlay(
+            bow_tie,
+            circle(IntPoint::new(0, 0)),
+            OverlayRule::Union,
+            FillRule::NonZero,
+        )
+        .unwrap();
+
+        assert_eq!(result.len(), 3);
+    }
+
     #[test]
     fn with_solver_preserves_precision_settings() {
         use i_overlay::core::solver::Precision;
@@ -0,0 +1,322 @@
+use crate::collections::disjoint_set::DisjointSet;
+use crate::int::CurveInt;
+use crate::int::bool::bounds::CurveBoundsBuffer;
+use crate::int::bool::source::{CurveContourSource, CurveSource};
+use crate::int::curve::segment::CurveSegment;
+use crate::kernel::int::cross::intersector::{SegmentIntersectionBuffer, SegmentIntersector, SplitOptions};
+use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::segment::Segment;
+use alloc::vec::Vec;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay::ShapeType;
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::i_float::int::rect::IntRect;
+use i_overlay::i_shape::int::IntPoint;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ContourRoute {
+    /// The contour interacts with other input and needs the full pipeline.
+    Pipeline,
+    /// The contour is isolated and simple; it is copied to the output,
+    /// reversed first when `reverse` is set.
+    Copy { reverse: bool },
+    /// The contour is isolated and cannot contribute to the result.
+    Drop,
+}
+
+/// Routes contours whose bounds touch no other input contour around the
+/// curve pipeline.
+///
+/// An isolated contour is the only boundary inside its bounds, so the result
+/// there is decided by its operand, the overlay rule, and the fill rule
+/// alone. Contours that overlap any other contour bounds, including holes
+/// nested inside an outer contour, stay in the pipeline. So do contours that
+/// normalization changes, for example by dropping a zero-length segment or
+/// reducing a collinear curve to a line, since a copy would keep them.
+pub(crate) struct CurvePassThrough<I: CurveInt> {
+    routes: Vec<ContourRoute>,
+    clusters: DisjointSet,
+    source_bounds: CurveBoundsBuffer<I>,
+    intersection_buffer: SegmentIntersectionBuffer<I>,
+}
+
+impl<I: CurveInt> CurvePassThrough<I> {
+    pub(crate) fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            clusters: DisjointSet::new(),
+            source_bounds: CurveBoundsBuffer::new(),
+            intersection_buffer: SegmentIntersectionBuffer::default(),
+        }
+    }
+
+    pub(crate) fn route(
+        &mut self,
+        contours: &[CurveContourSource<I>],
+        sources: &[CurveSource<I>],
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+        cross_radius: I::Wide,
+        bounds: &mut CurveBoundsBuffer<I>,
+    ) {
+        self.routes.clear();
+        self.clusters.reset(contours.len());
+
+        bounds.build_rects(
+            contours
+                .iter()
+                .map(|contour| Self::contour_rect(contour, sources)),
+        );
+        bounds.for_each_overlap(|first, second| self.clusters.union(first, second));
+
+        for (index, contour) in contours.iter().enumerate() {
+            let route = if self.clusters.set_size(index) > 1 || !Self::is_normalized(contour, sources) {
+                ContourRoute::Pipeline
+            } else if !Self::is_kept(overlay_rule, contour.shape_type) {
+                ContourRoute::Drop
+            } else {
+                self.isolated_route(&sources[contour.sources.clone()], fill_rule, cross_radius)
+            };
+            self.routes.push(route);
+        }
+    }
+
+    /// Returns the route of every contour passed to the last
+    /// [`route`](Self::route) call.
+    #[inline]
+    pub(crate) fn routes(&self) -> &[ContourRoute] {
+        &self.routes
+    }
+
+    fn isolated_route(
+        &mut self,
+        sources: &[CurveSource<I>],
+        fill_rule: FillRule,
+        cross_radius: I::Wide,
+    ) -> ContourRoute {
+        // Only a simple contour has a single interior with winding +1 or -1;
+        // anything else is resolved by the pipeline.
+        let Some(is_positive) = Self::orientation(sources) else {
+            return ContourRoute::Pipeline;
+        };
+        if !self.is_simple(sources, cross_radius) {
+            return ContourRoute::Pipeline;
+        }
+
+        let is_filled = match fill_rule {
+            FillRule::EvenOdd | FillRule::NonZero => true,
+            FillRule::Positive => is_positive,
+            FillRule::Negative => !is_positive,
+        };
+
+        if is_filled {
+            // Resolved outer contours have positive area.
+            ContourRoute::Copy {
+                reverse: !is_positive,
+            }
+        } else {
+            ContourRoute::Drop
+        }
+    }
+
+    /// Returns whether an operand region survives where the other operand is
+    /// absent.
+    #[inline]
+    fn is_kept(overlay_rule: OverlayRule, shape_type: ShapeType) -> bool {
+        match overlay_rule {
+            OverlayRule::Intersect => false,
+            OverlayRule::Union | OverlayRule::Xor => true,
+            OverlayRule::Subject | OverlayRule::Difference => shape_type == ShapeType::Subject,
+            OverlayRule::Clip | OverlayRule::InverseDifference => shape_type == ShapeType::Clip,
+        }
+    }
+
+    fn is_simple(&mut self, sources: &[CurveSource<I>], cross_radius: I::Wide) -> bool {
+        let count = sources.len();
+        if count < 2 {
+            return false;
+        }
+
+        self.source_bounds.build_rects(sources.iter().map(|source| {
+            let hull = source.curve.convex_hull();
+            IntRect::with_points(hull.as_slice()).unwrap()
+        }));
+
+        let buffer = &mut self.intersection_buffer;
+        let mut is_simple = true;
+        self.source_bounds.for_each_overlap(|index_0, index_1| {
+            if !is_simple {
+                return;
+            }
+            let (first, second) = (index_0.min(index_1), index_0.max(index_1));
+            let shared_0 = (second == first + 1).then(|| sources[first].curve.chord().b);
+            let shared_1 = (first == 0 && second == count - 1).then(|| sources[first].curve.chord().a);
+
+            let intersector = SegmentIntersector::new(
+                sources[first].curve,
+                sources[second].curve,
+                SplitOptions::with_cross_radius(cross_radius),
+            );
+            is_simple = intersector
+                .intersect_with_buffer(buffer)
+                .iter()
+                .all(|contact| Some(contact.point) == shared_0 || Some(contact.point) == shared_1);
+        });
+
+        is_simple
+    }
+
+    /// Returns whether the contour has positive signed area, or `None` when
+    /// the sign is too close to call.
+    ///
+    /// Lines and Béziers contribute their exact doubled area. A rational arc
+    /// covers an unknown fraction of its control triangle, so it widens the
+    /// range of possible areas instead.
+    fn orientation(sources: &[CurveSource<I>]) -> Option<bool> {
+        let mut exact = 0.0;
+        let mut low = 0.0;
+        let mut high = 0.0;
+        let mut magnitude = 0.0;
+
+        for source in sources {
+            let area = match source.curve {
+                Segment::Line(line) => {
+                    let [p0, p1] = line.control_points;
+                    cross(p0, p1)
+                }
+                Segment::Quad(quad) => {
+                    let [p0, p1, p2] = quad.control_points;
+                    (2.0 * cross(p0, p1) + 2.0 * cross(p1, p2) + cross(p0, p2)) / 3.0
+                }
+                Segment::Cubic(cubic) => {
+                    let [p0, p1, p2, p3] = cubic.control_points;
+                    (6.0 * cross(p0, p1)
+                        + 3.0 * cross(p0, p2)
+                        + cross(p0, p3)
+                        + 3.0 * cross(p1, p2)
+                        + 3.0 * cross(p1, p3)
+                        + 6.0 * cross(p2, p3))
+                        / 10.0
+                }
+                Segment::Arc(arc) => {
+                    let [p0, p1, p2] = arc.control_points;
+                    let triangle = cross(p0, p1) + cross(p1, p2) + cross(p2, p0);
+                    if triangle < 0.0 {
+                        low += triangle;
+                    } else {
+                        high += triangle;
+                    }
+                    magnitude += triangle.abs();
+                    cross(p0, p2)
+                }
+            };
+            exact += area;
+            magnitude += area.abs();
+        }
+
+        let tolerance = magnitude * 1e-9;
+        if exact + low > tolerance {
+            Some(true)
+        } else if exact + high < -tolerance {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether every segment of the contour became exactly one
+    /// source curve of the same kind.
+    fn is_normalized(contour: &CurveContourSource<I>, sources: &[CurveSource<I>]) -> bool {
+        let sources = &sources[contour.sources.clone()];
+        sources.len() == contour.path.segments.len()
+            && contour
+                .path
+                .segments
+                .iter()
+                .zip(sources)
+                .all(|(segment, source)| {
+                    matches!(
+                        (segment, source.curve),
+                        (CurveSegment::Line { .. }, Segment::Line(_))
+                            | (CurveSegment::Quad { .. }, Segment::Quad(_))
+                            | (CurveSegment::Cubic { .. }, Segment::Cubic(_))
+                            | (CurveSegment::Arc { .. }, Segment::Arc(_))
+                    )
+                })
+    }
+
+    fn contour_rect(contour: &CurveContourSource<I>, sources: &[CurveSource<I>]) -> IntRect<I> {
+        let mut rect = IntRect::with_point(contour.path.start);
+        for source in &sources[contour.sources.clone()] {
+            for point in source.curve.convex_hull().as_slice() {
+                rect.add_point(point);
+            }
+        }
+        rect
+    }
+}
+
+#[inline]
+fn cross<I: CurveInt>(a: IntPoint<I>, b: IntPoint<I>) -> f64 {
+    a.x.to_f64() * b.y.to_f64() - a.y.to_f64() * b.x.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::int::curve::line::LineSegment;
+    use crate::kernel::int::curve::quad::QuadSegment;
+    use alloc::vec;
+
+    fn line(a: [i32; 2], b: [i32; 2]) -> CurveSource<i32> {
+        CurveSource::new(
+            Segment::Line(LineSegment {
+                control_points: [a.into(), b.into()],
+            }),
+            ShapeType::Subject,
+        )
+    }
+
+    #[test]
+    fn orientation_uses_exact_bezier_area() {
+        // The chord polygon is degenerate; only the curve encloses area.
+        let bulge = |ctrl: [i32; 2]| {
+            vec![
+                CurveSource::new(
+                    Segment::Quad(QuadSegment {
+                        control_points: [[0, 0].into(), ctrl.into(), [10, 0].into()],
+                    }),
+                    ShapeType::Subject,
+                ),
+                line([10, 0], [0, 0]),
+            ]
+        };
+
+        assert_eq!(CurvePassThrough::orientation(&bulge([5, -10])), Some(true));
+        assert_eq!(CurvePassThrough::orientation(&bulge([5, 10])), Some(false));
+        assert_eq!(
+            CurvePassThrough::orientation(&[line([0, 0], [10, 0]), line([10, 0], [0, 0])]),
+            None
+        );
+    }
+
+    #[test]
+    fn crossing_contour_is_not_simple() {
+        let square = [
+            line([0, 0], [10, 0]),
+            line([10, 0], [10, 10]),
+            line([10, 10], [0, 10]),
+            line([0, 10], [0, 0]),
+        ];
+        let bow_tie = [
+            line([0, 0], [10, 10]),
+            line([10, 10], [10, 0]),
+            line([10, 0], [0, 10]),
+            line([0, 10], [0, 0]),
+        ];
+
+        let mut pass_through = CurvePassThrough::new();
+        assert!(pass_through.is_simple(&square, 1));
+        assert!(!pass_through.is_simple(&bow_tie, 1));
+    }
+}
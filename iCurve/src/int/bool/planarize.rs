@@ -1,17 +1,44 @@
 use crate::int::CurveInt;
 use crate::int::bool::bounds::CurveBoundsBuffer;
 use crate::int::bool::edge::CurveEdge;
+use crate::int::bool::source::CurveId;
 use crate::int::bool::split::{CurveEdgeSplitter, CurveSplitMark};
 use crate::kernel::int::cross::intersector::{SegmentIntersectionBuffer, SegmentIntersector, SplitOptions};
+use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::param::{SegmentParam, interpolate_segment_param};
 use crate::kernel::int::curve::segment::Segment;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Kernel contact that split at least one edge, located on the source
+/// curves of both edges.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CurveContact<I: CurveInt> {
+    pub(crate) point: IntPoint<I>,
+    pub(crate) curves: [CurveId; 2],
+    pub(crate) params: [SegmentParam<I>; 2],
+}
+
+/// Split mark found by a pass together with the edge whose contact
+/// produced it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CurvePairMark<I: CurveInt> {
+    pub(crate) mark: CurveSplitMark<I>,
+    pub(crate) partner_index: usize,
+}
 
 pub(crate) struct CurvePlanarizer<I: CurveInt> {
     split_marks: Vec<CurveSplitMark<I>>,
     split_marks_buffer: Vec<CurveSplitMark<I>>,
     splitter: CurveEdgeSplitter<I>,
     intersection_buffer: SegmentIntersectionBuffer<I>,
+    edge_order: Vec<usize>,
+    overlaps: Vec<[usize; 2]>,
+    contacts: Vec<CurveContact<I>>,
+    is_recording_contacts: bool,
+    pair_marks: Vec<CurvePairMark<I>>,
+    is_recording_pairs: bool,
 }
 
 impl<I: CurveInt + i_key_sort::sort::key::SortKey> CurvePlanarizer<I> {
@@ -21,80 +48,271 @@ impl<I: CurveInt + i_key_sort::sort::key::SortKey> CurvePlanarizer<I> {
             split_marks_buffer: Vec::new(),
             splitter: CurveEdgeSplitter::new(),
             intersection_buffer: SegmentIntersectionBuffer::default(),
+            edge_order: Vec::new(),
+            overlaps: Vec::new(),
+            contacts: Vec::new(),
+            is_recording_contacts: false,
+            pair_marks: Vec::new(),
+            is_recording_pairs: false,
         }
     }
 
+    /// Sets whether later passes record the contacts that split edges.
+    #[inline]
+    pub(crate) fn record_contacts(&mut self, is_recording: bool) {
+        self.is_recording_contacts = is_recording;
+    }
+
+    /// Contacts recorded by the last pass.
+    #[inline]
+    pub(crate) fn contacts(&self) -> &[CurveContact<I>] {
+        &self.contacts
+    }
+
     pub(crate) fn planarize(
         &mut self,
         edges: &mut Vec<CurveEdge<I>>,
         cross_radius: I::Wide,
         bounds: &mut CurveBoundsBuffer<I>,
+    ) {
+        self.planarize_against(edges, CurveId(0), cross_radius, bounds);
+    }
+
+    /// Planarizes `edges`, assuming that edges of curves preceding
+    /// `first_unsettled` are already planar with each other.
+    pub(crate) fn planarize_against(
+        &mut self,
+        edges: &mut Vec<CurveEdge<I>>,
+        first_unsettled: CurveId,
+        cross_radius: I::Wide,
+        bounds: &mut CurveBoundsBuffer<I>,
     ) {
         if edges.len() < 2 {
             return;
         }
 
+        self.is_recording_pairs = false;
         bounds.build(edges);
-        self.collect_split_marks(edges, cross_radius, bounds);
+        self.collect_split_marks(
+            edges,
+            |index| edges[index].curve_id < first_unsettled,
+            cross_radius,
+            bounds,
+        );
+        self.finish(edges);
+    }
+
+    /// Planarizes `edges` that have not been split yet, reusing the split
+    /// marks and overlaps found earlier between pairs of `settled` edges.
+    ///
+    /// Only pairs with at least one unsettled edge are intersected. The marks
+    /// they add are recorded with the edge that produced them and can be read
+    /// from [`pair_marks`](Self::pair_marks), together with the new overlaps
+    /// from [`overlaps`](Self::overlaps). Both use indices into `edges` as
+    /// they were before splitting.
+    pub(crate) fn planarize_with_settled(
+        &mut self,
+        edges: &mut Vec<CurveEdge<I>>,
+        settled: &[bool],
+        settled_marks: &[CurveSplitMark<I>],
+        settled_overlaps: &[[usize; 2]],
+        cross_radius: I::Wide,
+        bounds: &mut CurveBoundsBuffer<I>,
+    ) {
+        self.pair_marks.clear();
+        if edges.len() < 2 {
+            // A lone edge still splits at the marks of its settled partners.
+            self.split_marks.clear();
+            self.overlaps.clear();
+        } else {
+            self.is_recording_pairs = true;
+            bounds.build(edges);
+            self.collect_split_marks(edges, |index| settled[index], cross_radius, bounds);
+            self.is_recording_pairs = false;
+        }
+
+        let new_overlaps = self.overlaps.len();
+        self.split_marks.extend_from_slice(settled_marks);
+        self.overlaps.extend_from_slice(settled_overlaps);
+        self.finish(edges);
+        self.overlaps.truncate(new_overlaps);
+    }
+
+    /// Marks added by the last [`planarize_with_settled`](Self::planarize_with_settled) pass.
+    #[inline]
+    pub(crate) fn pair_marks(&self) -> &[CurvePairMark<I>] {
+        &self.pair_marks
+    }
+
+    /// Overlapping edge pairs found by the last
+    /// [`planarize_with_settled`](Self::planarize_with_settled) pass.
+    #[inline]
+    pub(crate) fn overlaps(&self) -> &[[usize; 2]] {
+        &self.overlaps
+    }
+
+    fn finish(&mut self, edges: &mut Vec<CurveEdge<I>>) {
+        CurveSplitMark::sort_and_dedup(&mut self.split_marks, &mut self.split_marks_buffer);
+        self.mirror_overlap_marks(edges);
         self.splitter.split(edges, &self.split_marks);
+        self.share_coincident_geometry(edges);
+    }
+
+    /// Gives edges that trace the same curve between the same ends one
+    /// geometry, so refinement cuts overlapping pieces into identical
+    /// chords. Source parameters stay with each edge.
+    fn share_coincident_geometry(&mut self, edges: &mut [CurveEdge<I>]) {
+        let ends = |edge: &CurveEdge<I>| {
+            let chord = edge.curve.chord();
+            if chord.a <= chord.b {
+                (chord.a, chord.b)
+            } else {
+                (chord.b, chord.a)
+            }
+        };
+
+        self.edge_order.clear();
+        self.edge_order.extend(
+            edges
+                .iter()
+                .enumerate()
+                .filter(|(_, edge)| matches!(edge.curve, Segment::Quad(_) | Segment::Cubic(_)))
+                .map(|(index, _)| index),
+        );
+        self.edge_order.sort_unstable_by_key(|&index| ends(&edges[index]));
+
+        let order = &self.edge_order;
+        let mut group_start = 0;
+        while group_start < order.len() {
+            let key = ends(&edges[order[group_start]]);
+            let mut group_end = group_start + 1;
+            while group_end < order.len() && ends(&edges[order[group_end]]) == key {
+                group_end += 1;
+            }
+
+            for position in group_start + 1..group_end {
+                let edge = edges[order[position]];
+                let Some(&shared) = order[group_start..position]
+                    .iter()
+                    .find(|&&other| edges[other].curve.coincides_with(&edge.curve))
+                else {
+                    continue;
+                };
+                let mut shared = edges[shared].curve;
+                if shared.chord().a != edge.curve.chord().a {
+                    shared.reverse();
+                }
+                edges[order[position]].curve = shared;
+            }
+
+            group_start = group_end;
+        }
     }
 
     fn collect_split_marks(
         &mut self,
         edges: &[CurveEdge<I>],
+        is_settled: impl Fn(usize) -> bool,
         cross_radius: I::Wide,
         bounds: &mut CurveBoundsBuffer<I>,
     ) {
-        bounds.active.clear();
         self.split_marks.clear();
+        self.overlaps.clear();
+        self.contacts.clear();
 
-        for bounds_index in 0..bounds.bounds.len() {
-            let current = bounds.bounds[bounds_index];
-            bounds
-                .active
-                .retain(|other| other.rect.max_x >= current.rect.min_x);
-
-            for active_index in 0..bounds.active.len() {
-                let other = bounds.active[active_index];
-                if !other.rect.is_intersect_border_include(&current.rect) {
-                    continue;
-                }
+        bounds.for_each_overlap(|index_0, index_1| {
+            let edge_0 = edges[index_0];
+            let edge_1 = edges[index_1];
+            if is_settled(index_0) && is_settled(index_1) {
+                return;
+            }
+            let (first_index, first_edge, second_index, second_edge) =
+                if Self::compare_geometry(&edge_0.curve, &edge_1.curve) != Ordering::Greater {
+                    (index_0, edge_0, index_1, edge_1)
+                } else {
+                    (index_1, edge_1, index_0, edge_0)
+                };
+            let intersector = SegmentIntersector::new(
+                first_edge.curve,
+                second_edge.curve,
+                SplitOptions::with_cross_radius(cross_radius),
+            );
+            let contacts = intersector.intersect_with_buffer(&mut self.intersection_buffer);
 
-                let edge_0 = edges[other.edge_index];
-                let edge_1 = edges[current.edge_index];
-                let (first_index, first_edge, second_index, second_edge) =
-                    if Self::compare_geometry(&edge_0.curve, &edge_1.curve) != Ordering::Greater {
-                        (other.edge_index, edge_0, current.edge_index, edge_1)
-                    } else {
-                        (current.edge_index, edge_1, other.edge_index, edge_0)
-                    };
-                let intersector = SegmentIntersector::new(
-                    first_edge.curve,
-                    second_edge.curve,
-                    SplitOptions::with_cross_radius(cross_radius),
+            for &contact in contacts {
+                let marks_len = self.split_marks.len();
+                CurveSplitMark::push_if_interior(
+                    &mut self.split_marks,
+                    first_index,
+                    contact.point,
+                    contact.t0,
                 );
-                let contacts = intersector.intersect_with_buffer(&mut self.intersection_buffer);
-
-                for &contact in contacts {
-                    CurveSplitMark::push_if_interior(
-                        &mut self.split_marks,
-                        first_index,
-                        contact.point,
-                        contact.t0,
-                    );
-                    CurveSplitMark::push_if_interior(
-                        &mut self.split_marks,
-                        second_index,
-                        contact.point,
-                        contact.t1,
-                    );
+                CurveSplitMark::push_if_interior(
+                    &mut self.split_marks,
+                    second_index,
+                    contact.point,
+                    contact.t1,
+                );
+                if self.is_recording_pairs {
+                    for &mark in &self.split_marks[marks_len..] {
+                        let partner_index = if mark.edge_index == first_index {
+                            second_index
+                        } else {
+                            first_index
+                        };
+                        self.pair_marks.push(CurvePairMark { mark, partner_index });
+                    }
+                }
+                if self.is_recording_contacts && self.split_marks.len() > marks_len {
+                    self.contacts.push(CurveContact {
+                        point: contact.point,
+                        curves: [first_edge.curve_id, second_edge.curve_id],
+                        params: [
+                            interpolate_segment_param(
+                                first_edge.start_param,
+                                first_edge.end_param,
+                                contact.t0,
+                            ),
+                            interpolate_segment_param(
+                                second_edge.start_param,
+                                second_edge.end_param,
+                                contact.t1,
+                            ),
+                        ],
+                    });
                 }
             }
+            if self.intersection_buffer.is_overlap() {
+                self.overlaps.push([first_index, second_index]);
+            }
+        });
+    }
 
-            bounds.active.push(current);
+    /// Copies the marks of each edge onto the edges it overlaps. Other curves
+    /// cross overlapping edges at points that round apart, and only pieces
+    /// cut at the same points can share one geometry.
+    fn mirror_overlap_marks(&mut self, edges: &[CurveEdge<I>]) {
+        if self.overlaps.is_empty() {
+            return;
         }
 
-        CurveSplitMark::sort_and_dedup(&mut self.split_marks, &mut self.split_marks_buffer);
+        let marks_len = self.split_marks.len();
+        for &[index_0, index_1] in self.overlaps.iter() {
+            for (from, to) in [(index_0, index_1), (index_1, index_0)] {
+                let start = self.split_marks[..marks_len].partition_point(|mark| mark.edge_index < from);
+                let end = self.split_marks[..marks_len].partition_point(|mark| mark.edge_index <= from);
+                for mark_index in start..end {
+                    let point = self.split_marks[mark_index].point;
+                    if let Some(param) = edges[to].curve.param_of_point(point) {
+                        CurveSplitMark::push_if_interior(&mut self.split_marks, to, point, param);
+                    }
+                }
+            }
+        }
+
+        if self.split_marks.len() > marks_len {
+            CurveSplitMark::sort_and_dedup(&mut self.split_marks, &mut self.split_marks_buffer);
+        }
     }
 
     fn compare_geometry(lhs: &Segment<I>, rhs: &Segment<I>) -> Ordering {
@@ -131,11 +349,13 @@ impl<I: CurveInt + i_key_sort::sort::key::SortKey> CurvePlanarizer<I> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::int::bool::source::CurveId;
+    use crate::kernel::int::curve::arc::{ArcDirection, ArcPhase, ArcSegment, ArcVector, EllipseFrame};
     use crate::kernel::int::curve::chord::Chord;
     use crate::kernel::int::curve::line::LineSegment;
+    use crate::kernel::int::curve::param::SegmentParam;
     use crate::kernel::int::curve::segment::Segment;
     use alloc::vec;
+    use i_overlay::i_float::int::number::fixed_scale::FixedScale;
     use i_overlay::i_shape::int::IntPoint;
 
     fn line(id: usize, a: [i32; 2], b: [i32; 2]) -> CurveEdge<i32> {
@@ -147,6 +367,28 @@ mod tests {
         )
     }
 
+    fn quarter_circle(radius: i32) -> ArcSegment<i32> {
+        let one = FixedScale::<i32>::DENOMINATOR as i32;
+        let diagonal = 759_250_125;
+
+        ArcSegment {
+            ellipse: EllipseFrame {
+                center: IntPoint::new(0, 0),
+                axis_x: ArcVector { x: radius, y: 0 },
+                axis_y: ArcVector { x: 0, y: radius },
+            },
+            control_points: [
+                IntPoint::new(radius, 0),
+                IntPoint::new(radius, radius),
+                IntPoint::new(0, radius),
+            ],
+            weights: [one, diagonal, one],
+            start_phase: ArcPhase { cos: one, sin: 0 },
+            end_phase: ArcPhase { cos: 0, sin: one },
+            direction: ArcDirection::CounterClockwise,
+        }
+    }
+
     #[test]
     fn splits_crossing_edges_and_preserves_curve_ids() {
         let mut edges = vec![line(0, [0, 0], [10, 10]), line(1, [0, 10], [10, 0])];
@@ -217,4 +459,85 @@ mod tests {
         assert_eq!(edges.iter().filter(|edge| edge.curve_id == CurveId(0)).count(), 2);
         assert_eq!(edges.iter().filter(|edge| edge.curve_id == CurveId(1)).count(), 2);
     }
+
+    #[test]
+    fn splits_short_collinear_overlaps() {
+        let mut edges = vec![line(0, [-4, -4], [-2, -2]), line(1, [-4, -4], [-3, -3])];
+        let mut planarizer = CurvePlanarizer::new();
+        let mut bounds = CurveBoundsBuffer::new();
+
+        planarizer.planarize(&mut edges, 2_i64, &mut bounds);
+
+        assert_eq!(edges.len(), 3);
+        assert!(
+            edges
+                .iter()
+                .any(|edge| edge.curve.chord().a == IntPoint::new(-3, -3))
+        );
+    }
+
+    #[test]
+    fn splits_line_near_the_end_of_a_crossing_line() {
+        let mut edges = vec![line(0, [-845, 658], [8, -8]), line(1, [-1, -1], [-213, 198])];
+        let mut planarizer = CurvePlanarizer::new();
+        let mut bounds = CurveBoundsBuffer::new();
+
+        planarizer.planarize(&mut edges, 1_i64 << 20, &mut bounds);
+
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges.iter().filter(|edge| edge.curve_id == CurveId(0)).count(), 2);
+    }
+
+    #[test]
+    fn lone_edge_splits_at_settled_marks() {
+        let mut edges = vec![line(0, [0, 0], [10, 0])];
+        let mark = CurveSplitMark {
+            edge_index: 0,
+            point: IntPoint::new(5, 0),
+            param: crate::kernel::int::curve::param::SegmentParam::half(),
+        };
+        let mut planarizer = CurvePlanarizer::new();
+        let mut bounds = CurveBoundsBuffer::new();
+
+        planarizer.planarize_with_settled(&mut edges, &[true], &[mark], &[], 2_i64, &mut bounds);
+
+        assert_eq!(edges.len(), 2);
+        assert!(planarizer.pair_marks().is_empty());
+    }
+
+    #[test]
+    fn splits_arcs_of_one_circle_where_their_shared_run_ends() {
+        let one = FixedScale::<i32>::DENOMINATOR as i32;
+        let arc = quarter_circle(10_000);
+        let head = arc.rational_split(SegmentParam::new(one / 4 * 3))[0];
+        let tail = arc.rational_split(SegmentParam::new(one / 4))[1];
+        let mut edges = vec![
+            CurveEdge::full(Segment::Arc(head), CurveId(0)),
+            CurveEdge::full(Segment::Arc(tail), CurveId(1)),
+            line(2, [5_000, 5_000], [9_000, 9_000]),
+        ];
+        let mut planarizer = CurvePlanarizer::new();
+        let mut bounds = CurveBoundsBuffer::new();
+
+        planarizer.planarize(&mut edges, 2_i64, &mut bounds);
+
+        // Both arcs end their shared run at the same points and cross the
+        // line at one point, so the run splits into two common pieces.
+        let ends = |id: usize| -> Vec<_> {
+            edges
+                .iter()
+                .filter(|edge| edge.curve_id == CurveId(id))
+                .map(|edge| {
+                    let chord = edge.curve.chord();
+                    (chord.a, chord.b)
+                })
+                .collect()
+        };
+        let (head_ends, tail_ends) = (ends(0), ends(1));
+        assert_eq!(head_ends.len(), 3);
+        assert_eq!(tail_ends.len(), 3);
+        let shared = head_ends.iter().filter(|ends| tail_ends.contains(ends)).count();
+        assert_eq!(shared, 2);
+        assert!(!planarizer.overlaps().is_empty());
+    }
 }
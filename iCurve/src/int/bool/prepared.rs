@@ -0,0 +1,206 @@
+use crate::int::CurveInt;
+use crate::int::bool::edge::CurveEdge;
+use crate::int::bool::overlay::{CurveInputError, CurveOverlayOptions, IntCurveOverlay};
+use crate::int::bool::source::{CurveContourSource, CurveSource};
+use crate::int::curve::shape::CurveShape;
+use alloc::vec::Vec;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::core::solver::Solver;
+use i_overlay::i_float::int::rect::IntRect;
+
+/// Integer curve operands prepared once for overlays against many clips.
+///
+/// Preparation converts the operands to canonical curves, approximates them,
+/// planarizes them against each other, and applies containment refinement.
+/// The prepared edges keep their bounds, so each [`overlay`](Self::overlay)
+/// call approximates only the new clip and re-planarizes only the prepared
+/// edges whose bounds touch it. Contours are routed like in
+/// [`IntCurveOverlay::overlay`], so the result equals that of an unprepared
+/// overlay of the same inputs.
+///
+/// Create one with [`new`](Self::new) or, to configure the solver and
+/// approximation first, with [`IntCurveOverlay::into_prepared_subject`].
+#[derive(Clone)]
+pub struct PreparedCurveSubject<I: CurveInt> {
+    pub(super) solver: Solver,
+    pub(super) options: CurveOverlayOptions,
+    pub(super) curve_sources: Vec<CurveSource<I>>,
+    pub(super) curve_edges: Vec<CurveEdge<I>>,
+    pub(super) edge_bounds: Vec<IntRect<I>>,
+    /// Input contours, which own no canonical edges once prepared.
+    pub(super) contours: Vec<CurveContourSource<I>>,
+}
+
+impl<I: CurveInt> PreparedCurveSubject<I> {
+    /// Validates and prepares a subject shape with the default solver and
+    /// approximation options.
+    pub fn new(subject: CurveShape<I>) -> Result<Self, CurveInputError> {
+        let capacity = subject.contours.iter().map(|path| path.segments.len()).sum();
+        let mut overlay = IntCurveOverlay::with_capacity(capacity);
+        overlay.add_subject(subject)?;
+        Ok(overlay.into_prepared_subject())
+    }
+
+    pub(super) fn from_prepared_edges(
+        solver: Solver,
+        options: CurveOverlayOptions,
+        curve_sources: Vec<CurveSource<I>>,
+        curve_edges: Vec<CurveEdge<I>>,
+        mut contours: Vec<CurveContourSource<I>>,
+    ) -> Self {
+        let edge_bounds = curve_edges
+            .iter()
+            .map(|edge| IntRect::with_points(edge.curve.convex_hull().as_slice()).unwrap())
+            .collect();
+        for contour in &mut contours {
+            contour.edges = 0..0;
+        }
+        Self {
+            solver,
+            options,
+            curve_sources,
+            curve_edges,
+            edge_bounds,
+            contours,
+        }
+    }
+
+    /// Returns the polygon solver configuration used by every overlay.
+    #[inline]
+    pub fn solver(&self) -> Solver {
+        self.solver
+    }
+
+    /// Returns the curve approximation configuration used by every overlay.
+    #[inline]
+    pub fn options(&self) -> CurveOverlayOptions {
+        self.options
+    }
+
+    /// Validates `clip` and resolves it against the prepared operands.
+    pub fn overlay(
+        &self,
+        clip: CurveShape<I>,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Result<Vec<CurveShape<I>>, CurveInputError> {
+        let mut overlay = IntCurveOverlay::from_prepared_subject(self);
+        overlay.add_clip(clip)?;
+        Ok(overlay.overlay_prepared(self, overlay_rule, fill_rule))
+    }
+
+    /// Resolves the prepared operands alone with the given fill rule.
+    pub fn resolve(&self, overlay_rule: OverlayRule, fill_rule: FillRule) -> Vec<CurveShape<I>> {
+        IntCurveOverlay::from_prepared_subject(self).overlay_prepared(self, overlay_rule, fill_rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::bool::overlay::overlay;
+    use crate::int::curve::path::CurvePath;
+    use crate::int::curve::segment::CurveSegment;
+    use alloc::vec;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn blob(x: i32, y: i32, size: i32) -> CurveShape<i32> {
+        let point = |dx: i32, dy: i32| IntPoint::new(x + dx * size / 4, y + dy * size / 4);
+        CurveShape {
+            contours: vec![CurvePath {
+                start: point(0, 0),
+                segments: vec![
+                    CurveSegment::Cubic {
+                        ctrl0: point(2, -2),
+                        ctrl1: point(4, 2),
+                        to: point(4, 0),
+                    },
+                    CurveSegment::Quad {
+                        ctrl: point(6, 4),
+                        to: point(2, 4),
+                    },
+                    CurveSegment::Line { to: point(0, 0) },
+                ],
+            }],
+        }
+    }
+
+    fn square(x: i32, y: i32, size: i32) -> CurveShape<i32> {
+        CurveShape {
+            contours: vec![CurvePath {
+                start: IntPoint::new(x, y),
+                segments: vec![
+                    CurveSegment::Line {
+                        to: IntPoint::new(x + size, y),
+                    },
+                    CurveSegment::Quad {
+                        ctrl: IntPoint::new(x + 2 * size, y + size / 2),
+                        to: IntPoint::new(x + size, y + size),
+                    },
+                    CurveSegment::Line {
+                        to: IntPoint::new(x, y + size),
+                    },
+                    CurveSegment::Line {
+                        to: IntPoint::new(x, y),
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn prepared_subject_matches_full_overlay() {
+        let mut subject = blob(0, 0, 4_000);
+        subject.contours.extend(blob(5_000, 0, 4_000).contours);
+        let prepared = PreparedCurveSubject::new(subject.clone()).unwrap();
+        let rules = [
+            OverlayRule::Intersect,
+            OverlayRule::Union,
+            OverlayRule::Difference,
+            OverlayRule::Xor,
+        ];
+
+        for clip in [square(1_000, 500, 2_000), square(3_000, 1_000, 3_000)] {
+            for rule in rules {
+                let expected = overlay(subject.clone(), clip.clone(), rule, FillRule::NonZero).unwrap();
+                let actual = prepared.overlay(clip.clone(), rule, FillRule::NonZero).unwrap();
+                assert_eq!(actual, expected, "{rule:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn union_keeps_subject_contours_away_from_the_clip() {
+        let mut subject = blob(0, 0, 4_000);
+        subject.contours.extend(blob(20_000, 0, 4_000).contours);
+        let prepared = PreparedCurveSubject::new(subject.clone()).unwrap();
+
+        let clip = square(21_000, 500, 2_000);
+        let result = prepared
+            .overlay(clip.clone(), OverlayRule::Union, FillRule::NonZero)
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        for rule in [OverlayRule::Union, OverlayRule::Difference, OverlayRule::Xor] {
+            let expected = overlay(subject.clone(), clip.clone(), rule, FillRule::NonZero).unwrap();
+            let actual = prepared.overlay(clip.clone(), rule, FillRule::NonZero).unwrap();
+            assert_eq!(actual, expected, "{rule:?}");
+        }
+    }
+
+    #[test]
+    fn resolve_and_invalid_clip() {
+        let prepared = PreparedCurveSubject::new(blob(0, 0, 4_000)).unwrap();
+
+        assert_eq!(prepared.resolve(OverlayRule::Subject, FillRule::NonZero).len(), 1);
+        assert_eq!(
+            prepared.overlay(
+                CurveShape { contours: vec![] },
+                OverlayRule::Union,
+                FillRule::NonZero
+            ),
+            Err(CurveInputError::EmptyShape)
+        );
+    }
+}
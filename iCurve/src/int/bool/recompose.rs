@@ -1,6 +1,7 @@
 use crate::collections::circular_merge_list::CircularMergeList;
 use crate::int::CurveInt;
 use crate::int::bool::data::{CurveEdgeData, CurveEdgeDataStore, CurveSourceSpan};
+use crate::int::bool::overlay::CurveOverlayError;
 use crate::int::bool::source::CurveSource;
 use crate::int::curve::path::CurvePath;
 use crate::int::curve::segment::CurveSegment;
@@ -14,18 +15,19 @@ use core::cmp::Ordering;
 use i_overlay::i_float::int::number::uint::UIntNumber;
 use i_overlay::i_float::int::number::wide_int::WideIntNumber;
 use i_overlay::i_shape::int::IntPoint;
+use i_overlay::i_shape::int::shape::IntShapes;
 use i_overlay::vector::edge::{DataVectorEdge, DataVectorShape};
 
-struct CurveRun<I: CurveInt> {
-    start: IntPoint<I>,
-    end: IntPoint<I>,
-    candidates: Vec<CurveSourceSpan>,
+pub(super) struct CurveRun<I: CurveInt> {
+    pub(super) start: IntPoint<I>,
+    pub(super) end: IntPoint<I>,
+    pub(super) candidates: Vec<CurveSourceSpan>,
 }
 
 impl<I: CurveInt> CurveRun<I> {
     fn try_merge(&mut self, next: &mut Self) -> bool {
+        // Disconnected neighbors are reported by `recompose_contour`.
         if self.end != next.start {
-            debug_assert!(false, "adjacent overlay edges must share an endpoint");
             return false;
         }
         // A closed run cannot be represented by one source subsegment.
@@ -149,47 +151,80 @@ impl<I: CurveInt> CurveRun<I> {
         }
     }
 
-    fn into_curve_segment(self, sources: &[CurveSource<I>]) -> CurveSegment<I> {
+    /// Rebuilds the run from a source span, or falls back to its chord.
+    ///
+    /// The fallback is expected only for collapsed runs; it is `Err` for a
+    /// run that lost every reconstructable span.
+    pub(super) fn into_curve_segment(
+        self,
+        sources: &[CurveSource<I>],
+    ) -> Result<CurveSegment<I>, CurveSegment<I>> {
         if let Some(segment) = self.try_curve_segment(sources) {
-            return segment;
+            return Ok(segment);
         }
 
-        debug_assert!(
-            self.is_collapsed(),
-            "a non-collapsed overlay edge must retain a reconstructable source span"
-        );
-        CurveSegment::Line { to: self.end }
+        let line = CurveSegment::Line { to: self.end };
+        if self.is_collapsed() { Ok(line) } else { Err(line) }
     }
 }
 
 pub(crate) struct CurveRecomposer<I: CurveInt> {
     merge_list: CircularMergeList<CurveRun<I>>,
+    fault: Option<CurveOverlayError>,
 }
 
 impl<I: CurveInt> CurveRecomposer<I> {
     pub(crate) fn new() -> Self {
         Self {
             merge_list: CircularMergeList::with_capacity(0),
+            fault: None,
         }
     }
 
+    /// Returns and resets the first invariant violation seen since the
+    /// last call. The affected contours are still emitted.
+    pub(crate) fn take_fault(&mut self) -> Option<CurveOverlayError> {
+        self.fault.take()
+    }
+
+    /// Rebuilds curve shapes from the traced polygon shapes.
+    ///
+    /// When `nesting` is given, the polygon of every kept contour is pushed
+    /// to it in result order, so it stays aligned with the returned shapes.
     pub(crate) fn recompose(
         &mut self,
         shapes: Vec<DataVectorShape<I, CurveEdgeData>>,
         data_store: &CurveEdgeDataStore,
         sources: &[CurveSource<I>],
+        mut nesting: Option<&mut IntShapes<I>>,
     ) -> Vec<CurveShape<I>> {
         let mut result = Vec::with_capacity(shapes.len());
 
         for shape in shapes {
             let mut contours = Vec::with_capacity(shape.len());
+            let mut polygons = Vec::new();
             for contour in shape {
-                if let Some(path) = self.recompose_contour(contour, data_store, sources) {
-                    contours.push(path);
+                if nesting.is_some() {
+                    polygons.push(
+                        contour
+                            .iter()
+                            .filter(|edge| edge.a != edge.b)
+                            .map(|edge| edge.a)
+                            .collect(),
+                    );
+                }
+                match self.recompose_contour(contour, data_store, sources) {
+                    Some(path) => contours.push(path),
+                    None => {
+                        polygons.pop();
+                    }
                 }
             }
 
             if !contours.is_empty() {
+                if let Some(nesting) = nesting.as_deref_mut() {
+                    nesting.push(polygons);
+                }
                 result.push(CurveShape { contours });
             }
         }
@@ -218,12 +253,25 @@ impl<I: CurveInt> CurveRecomposer<I> {
             });
         }
 
+        let is_connected = runs
+            .iter()
+            .zip(runs.iter().cycle().skip(1))
+            .all(|(run, next)| run.end == next.start);
+        if !is_connected {
+            self.fault.get_or_insert(CurveOverlayError::DisconnectedEdges);
+        }
+
         let runs = self.merge_list.merge_with(runs, CurveRun::try_merge);
         let runs = self.bridge_collapsed_runs(runs);
         let start = runs.first()?.start;
         let segments = runs
             .into_iter()
-            .map(|run| run.into_curve_segment(sources))
+            .map(|run| {
+                run.into_curve_segment(sources).unwrap_or_else(|line| {
+                    self.fault.get_or_insert(CurveOverlayError::UnreconstructableEdge);
+                    line
+                })
+            })
             .collect();
 
         Some(CurvePath { start, segments })
@@ -328,6 +376,7 @@ mod tests {
             shapes,
             &CurveEdgeDataStore::default(),
             &[cubic_source(), closing],
+            None,
         );
 
         assert_eq!(result[0].contours[0].segments.len(), 2);
@@ -353,6 +402,7 @@ mod tests {
             shapes,
             &CurveEdgeDataStore::default(),
             &[cubic_source(), closing],
+            None,
         );
 
         assert_eq!(result[0].contours[0].segments[0], CurveSegment::Line { to: p1 });
@@ -381,9 +431,82 @@ mod tests {
             shapes,
             &CurveEdgeDataStore::default(),
             &[cubic_source(), closing],
+            None,
         );
 
         assert_eq!(result[0].contours[0].segments.len(), 2);
         assert!(matches!(result[0].contours[0].segments[0], CurveSegment::Cubic { to, .. } if to == p3));
     }
+
+    #[test]
+    fn disconnected_edges_are_reported_as_fault() {
+        let p0 = IntPoint::new(0, 0);
+        let p1 = IntPoint::new(8, 0);
+        let p2 = IntPoint::new(8, 8);
+        let line = |a, b| {
+            CurveSource::new(
+                Segment::Line(LineSegment {
+                    control_points: [a, b],
+                }),
+                ShapeType::Subject,
+            )
+        };
+        let shapes = vec![vec![vec![
+            edge(p0, p1, span(0, (0, 1), (1, 1))),
+            edge(p2, p0, span(1, (0, 1), (1, 1))),
+        ]]];
+
+        let mut recomposer = CurveRecomposer::new();
+        let result = recomposer.recompose(
+            shapes,
+            &CurveEdgeDataStore::default(),
+            &[line(p0, p1), line(p2, p0)],
+            None,
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            recomposer.take_fault(),
+            Some(CurveOverlayError::DisconnectedEdges)
+        );
+        assert_eq!(recomposer.take_fault(), None);
+    }
+
+    #[test]
+    fn nesting_polygons_follow_the_kept_contours() {
+        let p0 = IntPoint::new(0, 0);
+        let p1 = IntPoint::new(4, 5);
+        let p2 = IntPoint::new(8, 0);
+        let closing = CurveSource::new(
+            Segment::Line(LineSegment {
+                control_points: [p2, p0],
+            }),
+            ShapeType::Subject,
+        );
+        let collapsed = || vec![edge(p1, p1, span(1, (0, 1), (0, 1)))];
+        let shapes = vec![
+            vec![collapsed()],
+            vec![
+                collapsed(),
+                vec![
+                    edge(p0, p1, span(0, (0, 1), (1, 2))),
+                    edge(p1, p2, span(0, (1, 2), (1, 1))),
+                    edge(p2, p2, span(1, (0, 1), (0, 1))),
+                    edge(p2, p0, span(1, (0, 1), (1, 1))),
+                ],
+            ],
+        ];
+        let mut nesting = Vec::new();
+
+        let result = CurveRecomposer::new().recompose(
+            shapes,
+            &CurveEdgeDataStore::default(),
+            &[cubic_source(), closing],
+            Some(&mut nesting),
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contours.len(), 1);
+        assert_eq!(nesting, vec![vec![vec![p0, p1, p2]]]);
+    }
 }
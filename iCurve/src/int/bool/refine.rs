@@ -1,14 +1,20 @@
 use crate::int::CurveInt;
 use crate::int::bool::bounds::CurveBoundsBuffer;
 use crate::int::bool::edge::CurveEdge;
+use crate::int::bool::overlay::CurveOverlayOptions;
+use crate::int::bool::source::CurveId;
 use crate::int::bool::split::{CurveEdgeSplitter, CurveSplitMark};
 use crate::kernel::int::curve::chord::Chord;
 use crate::kernel::int::curve::param::SegmentParam;
 use crate::kernel::int::curve::point_at::PointAt;
+use crate::kernel::int::curve::segment::Segment;
 use alloc::vec::Vec;
 use i_overlay::i_float::int::number::wide_int::WideIntNumber;
 
 pub(crate) struct CurveContainmentRefiner<I: CurveInt> {
+    /// Source curves, by id, whose pieces were already refined against each
+    /// other.
+    settled: Vec<bool>,
     targets: Vec<bool>,
     split_marks: Vec<CurveSplitMark<I>>,
     splitter: CurveEdgeSplitter<I>,
@@ -17,6 +23,7 @@ pub(crate) struct CurveContainmentRefiner<I: CurveInt> {
 impl<I: CurveInt> CurveContainmentRefiner<I> {
     pub(crate) fn new() -> Self {
         Self {
+            settled: Vec::new(),
             targets: Vec::new(),
             split_marks: Vec::new(),
             splitter: CurveEdgeSplitter::new(),
@@ -31,6 +38,76 @@ impl<I: CurveInt> CurveContainmentRefiner<I> {
         min_chord_length_power: u32,
         angle_tolerance_power: u32,
         bounds: &mut CurveBoundsBuffer<I>,
+    ) {
+        self.settled.clear();
+        self.refine_edges(
+            edges,
+            subdivision_power,
+            max_iterations,
+            min_chord_length_power,
+            angle_tolerance_power,
+            bounds,
+        );
+    }
+
+    /// Refines `edges`, assuming that pairs of edges whose curves both
+    /// precede `first_unsettled` were already refined against each other.
+    pub(crate) fn refine_against(
+        &mut self,
+        edges: &mut Vec<CurveEdge<I>>,
+        first_unsettled: CurveId,
+        options: CurveOverlayOptions,
+        bounds: &mut CurveBoundsBuffer<I>,
+    ) {
+        self.settled.clear();
+        self.settled.resize(first_unsettled.0, true);
+        self.refine_options(edges, options, bounds);
+    }
+
+    /// Refines `edges`, assuming that pairs of edges whose curves are both
+    /// marked in `settled`, indexed by curve id, were already refined
+    /// against each other.
+    pub(crate) fn refine_with_settled(
+        &mut self,
+        edges: &mut Vec<CurveEdge<I>>,
+        settled: &[bool],
+        options: CurveOverlayOptions,
+        bounds: &mut CurveBoundsBuffer<I>,
+    ) {
+        self.settled.clear();
+        self.settled.extend_from_slice(settled);
+        self.refine_options(edges, options, bounds);
+    }
+
+    fn refine_options(
+        &mut self,
+        edges: &mut Vec<CurveEdge<I>>,
+        options: CurveOverlayOptions,
+        bounds: &mut CurveBoundsBuffer<I>,
+    ) {
+        self.refine_edges(
+            edges,
+            options.refinement_subdivision_power,
+            options.max_refinement_iterations,
+            options.min_chord_length_power,
+            options.refinement_angle_tolerance_power,
+            bounds,
+        );
+    }
+
+    #[inline]
+    fn is_settled(&self, curve_id: CurveId) -> bool {
+        self.settled.get(curve_id.0).copied().unwrap_or(false)
+    }
+
+    fn refine_edges(
+        &mut self,
+        edges: &mut Vec<CurveEdge<I>>,
+        subdivision_power: u32,
+        max_iterations: u32,
+        min_chord_length_power: u32,
+        angle_tolerance_power: u32,
+        bounds: &mut CurveBoundsBuffer<I>,
     ) {
         if edges.len() < 2 || subdivision_power == 0 || max_iterations == 0 {
             return;
@@ -57,27 +134,12 @@ impl<I: CurveInt> CurveContainmentRefiner<I> {
         bounds: &mut CurveBoundsBuffer<I>,
     ) -> bool {
         bounds.build(edges);
-        bounds.active.clear();
         self.targets.clear();
         self.targets.resize(edges.len(), false);
 
-        for bounds_index in 0..bounds.bounds.len() {
-            let current = bounds.bounds[bounds_index];
-            bounds
-                .active
-                .retain(|other| other.rect.max_x >= current.rect.min_x);
-
-            for active_index in 0..bounds.active.len() {
-                let other = bounds.active[active_index];
-                if !other.rect.is_intersect_border_include(&current.rect) {
-                    continue;
-                }
-
-                self.collect_pair_targets(edges, other.edge_index, current.edge_index, angle_tolerance_power);
-            }
-
-            bounds.active.push(current);
-        }
+        bounds.for_each_overlap(|first_index, second_index| {
+            self.collect_pair_targets(edges, first_index, second_index, angle_tolerance_power);
+        });
 
         self.targets.iter().any(|&target| target)
     }
@@ -89,8 +151,14 @@ impl<I: CurveInt> CurveContainmentRefiner<I> {
         second_index: usize,
         angle_tolerance_power: u32,
     ) {
+        if self.is_settled(edges[first_index].curve_id) && self.is_settled(edges[second_index].curve_id) {
+            return;
+        }
         let first = edges[first_index].curve;
         let second = edges[second_index].curve;
+        if let (Segment::Line(_), Segment::Line(_)) = (first, second) {
+            return;
+        }
         let first_chord = first.chord();
         let second_chord = second.chord();
 
@@ -107,7 +175,7 @@ impl<I: CurveInt> CurveContainmentRefiner<I> {
     }
 
     fn contains_interior_endpoint(
-        curve: crate::kernel::int::curve::segment::Segment<I>,
+        curve: Segment<I>,
         other_chord: crate::kernel::int::curve::chord::SegmentChord<I>,
     ) -> bool {
         let chord = curve.chord();
@@ -196,7 +264,6 @@ impl<I: CurveInt> CurveContainmentRefiner<I> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::int::bool::source::CurveId;
     use crate::kernel::int::curve::line::LineSegment;
     use crate::kernel::int::curve::quad::QuadSegment;
     use crate::kernel::int::curve::segment::Segment;
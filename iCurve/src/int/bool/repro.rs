@@ -0,0 +1,972 @@
+use crate::int::CurveInt;
+use crate::int::bool::overlay::{
+    CurveInputError, CurveOverlayOptions, CurveOverlayOptionsError, IntCurveOverlay, validate_shape,
+};
+use crate::int::curve::path::CurvePath;
+use crate::int::curve::segment::CurveSegment;
+use crate::int::curve::shape::CurveShape;
+use crate::kernel::int::curve::arc::{ArcDirection, ArcPhase, ArcSegment, ArcVector, EllipseFrame};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::str::{FromStr, SplitWhitespace};
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay::{ContourDirection, ShapeType};
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::core::solver::{MultithreadOptions, Solver, Strategy};
+use i_overlay::i_shape::int::IntPoint;
+
+const HEADER: &str = "i_curve_repro";
+const VERSION: u32 = 1;
+
+const OVERLAY_RULES: [(OverlayRule, &str); 7] = [
+    (OverlayRule::Subject, "subject"),
+    (OverlayRule::Clip, "clip"),
+    (OverlayRule::Intersect, "intersect"),
+    (OverlayRule::Union, "union"),
+    (OverlayRule::Difference, "difference"),
+    (OverlayRule::InverseDifference, "inverse_difference"),
+    (OverlayRule::Xor, "xor"),
+];
+
+const FILL_RULES: [(FillRule, &str); 4] = [
+    (FillRule::EvenOdd, "even_odd"),
+    (FillRule::NonZero, "non_zero"),
+    (FillRule::Positive, "positive"),
+    (FillRule::Negative, "negative"),
+];
+
+const CONTOUR_DIRECTIONS: [(ContourDirection, &str); 2] = [
+    (ContourDirection::CounterClockwise, "counter_clockwise"),
+    (ContourDirection::Clockwise, "clockwise"),
+];
+
+const STRATEGIES: [(Strategy, &str); 4] = [
+    (Strategy::List, "list"),
+    (Strategy::Tree, "tree"),
+    (Strategy::Frag, "frag"),
+    (Strategy::Auto, "auto"),
+];
+
+/// Float-to-integer mapping of an overlay captured from float input.
+///
+/// A float point `p` maps to the integer point `(p - offset) * scale`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CurveReproAdapter {
+    /// Float offset subtracted before scaling.
+    pub offset: [f64; 2],
+    /// Float-to-integer scale.
+    pub scale: f64,
+}
+
+impl CurveReproAdapter {
+    /// Creates an adapter record from its offset and scale.
+    pub fn new(offset: [f64; 2], scale: f64) -> Self {
+        Self { offset, scale }
+    }
+}
+
+/// Self-contained record of one integer curve overlay.
+///
+/// A record holds every input contour with its operand role, in the order
+/// the overlay received them, together with the solver, the approximation
+/// options, and the rules. [`replay`](Self::replay) reruns the operation
+/// through [`IntCurveOverlay`] with bit-identical results. Overlays built
+/// from float input also record their [`CurveReproAdapter`].
+///
+/// [`Display`] writes a stable line-based text format that [`FromStr`]
+/// reads back, so a record can be attached to a bug report and pasted into
+/// a regression test. Each line holds one record; settings come first and
+/// every contour is listed as its operand role and start point, one line
+/// per segment, and `end`:
+///
+/// ```text
+/// i_curve_repro 1
+/// engine i32
+/// overlay_rule union
+/// fill_rule non_zero
+/// solver_strategy auto
+/// ...
+/// subject 0 0
+/// line 100 0
+/// quad 50 80 0 0
+/// end
+/// ```
+///
+/// ```
+/// use i_curve::int::{CurveOverlayRepro, CurvePath, CurveSegment, CurveShape, IntCurveOverlay, IntPoint};
+/// use i_curve::{FillRule, OverlayRule};
+///
+/// let start = IntPoint::new(0, 0);
+/// let lens = CurveShape::from_path(CurvePath::new(
+///     start,
+///     vec![
+///         CurveSegment::Line { to: IntPoint::new(100, 0) },
+///         CurveSegment::Quad { ctrl: IntPoint::new(50, 80), to: start },
+///     ],
+/// ));
+///
+/// let mut overlay = IntCurveOverlay::new();
+/// overlay.add_subject(lens.clone()).unwrap();
+/// overlay.add_clip(lens).unwrap();
+/// let repro = overlay.capture_repro(OverlayRule::Union, FillRule::NonZero);
+///
+/// let text = repro.to_string();
+/// let restored: CurveOverlayRepro<i32> = text.parse().unwrap();
+/// assert_eq!(restored.to_string(), text);
+/// assert_eq!(restored.replay(), overlay.overlay(OverlayRule::Union, FillRule::NonZero));
+/// ```
+#[derive(Clone)]
+pub struct CurveOverlayRepro<I: CurveInt> {
+    contours: Vec<(ShapeType, CurvePath<I>)>,
+    adapter: Option<CurveReproAdapter>,
+    solver: Solver,
+    options: CurveOverlayOptions,
+    overlay_rule: OverlayRule,
+    fill_rule: FillRule,
+}
+
+impl<I: CurveInt> CurveOverlayRepro<I> {
+    pub(crate) fn new(
+        contours: Vec<(ShapeType, CurvePath<I>)>,
+        solver: Solver,
+        options: CurveOverlayOptions,
+        overlay_rule: OverlayRule,
+        fill_rule: FillRule,
+    ) -> Self {
+        Self {
+            contours,
+            adapter: None,
+            solver,
+            options,
+            overlay_rule,
+            fill_rule,
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn with_adapter(mut self, adapter: CurveReproAdapter) -> Self {
+        self.adapter = Some(adapter);
+        self
+    }
+
+    /// Returns the input contours with their operand roles in input order.
+    #[inline]
+    pub fn contours(&self) -> &[(ShapeType, CurvePath<I>)] {
+        &self.contours
+    }
+
+    /// Returns the float-to-integer mapping, or `None` for integer input.
+    #[inline]
+    pub fn adapter(&self) -> Option<CurveReproAdapter> {
+        self.adapter
+    }
+
+    /// Returns the polygon solver configuration.
+    #[inline]
+    pub fn solver(&self) -> Solver {
+        self.solver
+    }
+
+    /// Returns the curve approximation options.
+    #[inline]
+    pub fn options(&self) -> CurveOverlayOptions {
+        self.options
+    }
+
+    /// Returns the recorded Boolean operation.
+    #[inline]
+    pub fn overlay_rule(&self) -> OverlayRule {
+        self.overlay_rule
+    }
+
+    /// Returns the recorded fill rule.
+    #[inline]
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Rebuilds the captured overlay without resolving it.
+    ///
+    /// Use this to resolve the record with
+    /// [`try_overlay`](IntCurveOverlay::try_overlay) or
+    /// [`overlay_with_diagnostics`](IntCurveOverlay::overlay_with_diagnostics).
+    pub fn to_overlay(&self) -> IntCurveOverlay<I> {
+        let capacity = self.contours.iter().map(|(_, path)| path.segments.len()).sum();
+        let mut overlay = IntCurveOverlay::with_capacity(capacity)
+            .with_solver(self.solver)
+            .try_with_options(self.options)
+            .expect("options were validated");
+        for (shape_type, path) in &self.contours {
+            let result = overlay.add_shape(CurveShape::from_path(path.clone()), *shape_type);
+            debug_assert!(result.is_ok(), "contours are validated when recorded");
+        }
+        overlay
+    }
+
+    /// Reruns the captured operation.
+    pub fn replay(&self) -> Vec<CurveShape<I>> {
+        self.to_overlay().overlay(self.overlay_rule, self.fill_rule)
+    }
+}
+
+impl<I: CurveInt + fmt::Debug> fmt::Debug for CurveOverlayRepro<I> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("CurveOverlayRepro")
+            .field("contours", &self.contours)
+            .field("adapter", &self.adapter)
+            .field("solver_strategy", &self.solver.strategy)
+            .field("solver_precision", &self.solver.precision)
+            .field("options", &self.options)
+            .field("overlay_rule", &self.overlay_rule)
+            .field("fill_rule", &self.fill_rule)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: CurveInt> IntCurveOverlay<I> {
+    /// Records the inputs and configuration added so far together with the
+    /// rules of the operation about to run.
+    ///
+    /// Capture the record before consuming the overlay; the record replays
+    /// the operation independently of it.
+    pub fn capture_repro(&self, overlay_rule: OverlayRule, fill_rule: FillRule) -> CurveOverlayRepro<I> {
+        let contours = self
+            .contours
+            .iter()
+            .map(|contour| (contour.shape_type, contour.path.clone()))
+            .collect();
+        CurveOverlayRepro::new(contours, self.solver(), self.options(), overlay_rule, fill_rule)
+    }
+}
+
+impl<I: CurveInt> Display for CurveOverlayRepro<I> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "{HEADER} {VERSION}")?;
+        writeln!(formatter, "engine i{}", I::BITS)?;
+        writeln!(
+            formatter,
+            "overlay_rule {}",
+            name_of(&OVERLAY_RULES, self.overlay_rule)
+        )?;
+        writeln!(formatter, "fill_rule {}", name_of(&FILL_RULES, self.fill_rule))?;
+
+        let solver = self.solver;
+        writeln!(
+            formatter,
+            "solver_strategy {}",
+            name_of(&STRATEGIES, solver.strategy)
+        )?;
+        writeln!(
+            formatter,
+            "solver_precision {} {}",
+            solver.precision.start, solver.precision.progression
+        )?;
+        match solver.multithreading {
+            Some(multithreading) => writeln!(
+                formatter,
+                "solver_multithreading {}",
+                multithreading.par_sort_min_size
+            )?,
+            None => writeln!(formatter, "solver_multithreading none")?,
+        }
+
+        let options = self.options;
+        writeln!(
+            formatter,
+            "min_chord_length_power {}",
+            options.min_chord_length_power
+        )?;
+        writeln!(
+            formatter,
+            "angle_tolerance_power {}",
+            options.angle_tolerance_power
+        )?;
+        writeln!(
+            formatter,
+            "max_approximation_depth {}",
+            options.max_approximation_depth
+        )?;
+        writeln!(
+            formatter,
+            "refinement_subdivision_power {}",
+            options.refinement_subdivision_power
+        )?;
+        writeln!(
+            formatter,
+            "refinement_angle_tolerance_power {}",
+            options.refinement_angle_tolerance_power
+        )?;
+        writeln!(
+            formatter,
+            "max_refinement_iterations {}",
+            options.max_refinement_iterations
+        )?;
+        writeln!(
+            formatter,
+            "certified_intersections {}",
+            options.certified_intersections
+        )?;
+        writeln!(
+            formatter,
+            "output_direction {}",
+            name_of(&CONTOUR_DIRECTIONS, options.output_direction)
+        )?;
+        writeln!(formatter, "canonical_output {}", options.canonical_output)?;
+        writeln!(formatter, "min_contour_area {}", options.min_contour_area)?;
+        writeln!(
+            formatter,
+            "min_area_perimeter_ratio {}",
+            options.min_area_perimeter_ratio
+        )?;
+        writeln!(formatter, "min_segment_length {}", options.min_segment_length)?;
+        writeln!(
+            formatter,
+            "merge_collinear_lines {}",
+            options.merge_collinear_lines
+        )?;
+
+        if let Some(adapter) = self.adapter {
+            let [x, y] = adapter.offset;
+            writeln!(formatter, "adapter_offset {x:?} {y:?}")?;
+            writeln!(formatter, "adapter_scale {:?}", adapter.scale)?;
+        }
+
+        for (shape_type, path) in &self.contours {
+            let operand = match shape_type {
+                ShapeType::Subject => "subject",
+                ShapeType::Clip => "clip",
+            };
+            writeln!(formatter, "{operand} {} {}", path.start.x, path.start.y)?;
+            for segment in &path.segments {
+                write_segment(formatter, segment)?;
+            }
+            writeln!(formatter, "end")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_segment<I: CurveInt>(formatter: &mut Formatter<'_>, segment: &CurveSegment<I>) -> fmt::Result {
+    match segment {
+        CurveSegment::Line { to } => writeln!(formatter, "line {} {}", to.x, to.y),
+        CurveSegment::Quad { ctrl, to } => {
+            writeln!(formatter, "quad {} {} {} {}", ctrl.x, ctrl.y, to.x, to.y)
+        }
+        CurveSegment::Cubic { ctrl0, ctrl1, to } => writeln!(
+            formatter,
+            "cubic {} {} {} {} {} {}",
+            ctrl0.x, ctrl0.y, ctrl1.x, ctrl1.y, to.x, to.y
+        ),
+        CurveSegment::Arc { arc } => {
+            let ellipse = arc.ellipse;
+            let [p0, p1, p2] = arc.control_points;
+            let [w0, w1, w2] = arc.weights;
+            let direction = match arc.direction {
+                ArcDirection::Clockwise => "cw",
+                ArcDirection::CounterClockwise => "ccw",
+            };
+            writeln!(
+                formatter,
+                "arc {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {direction}",
+                ellipse.center.x,
+                ellipse.center.y,
+                ellipse.axis_x.x,
+                ellipse.axis_x.y,
+                ellipse.axis_y.x,
+                ellipse.axis_y.y,
+                p0.x,
+                p0.y,
+                p1.x,
+                p1.y,
+                p2.x,
+                p2.y,
+                w0,
+                w1,
+                w2,
+                arc.start_phase.cos,
+                arc.start_phase.sin,
+                arc.end_phase.cos,
+                arc.end_phase.sin,
+            )
+        }
+    }
+}
+
+/// Rejected [`CurveOverlayRepro`] text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurveReproParseError {
+    /// One-based line number of the rejected record.
+    pub line: usize,
+    /// Reason the record was rejected.
+    pub kind: CurveReproParseErrorKind,
+}
+
+/// Reason a [`CurveOverlayRepro`] record was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CurveReproParseErrorKind {
+    /// The text does not start with the repro header.
+    MissingHeader,
+    /// The text was written by an unsupported format version.
+    UnsupportedVersion,
+    /// The record was captured with a different integer engine.
+    EngineMismatch,
+    /// A record name is not recognized in its position.
+    UnknownRecord,
+    /// A record has a missing, extra, or malformed value.
+    InvalidValue,
+    /// The overlay or fill rule is not recorded.
+    MissingRule,
+    /// The text ends inside a contour.
+    UnterminatedContour,
+    /// A recorded contour is not valid curve input.
+    InvalidContour(CurveInputError),
+    /// The recorded options exceed a safety limit.
+    InvalidOptions(CurveOverlayOptionsError),
+}
+
+impl Display for CurveReproParseError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let line = self.line;
+        match self.kind {
+            CurveReproParseErrorKind::MissingHeader => {
+                write!(formatter, "line {line}: missing curve overlay repro header")
+            }
+            CurveReproParseErrorKind::UnsupportedVersion => {
+                write!(formatter, "line {line}: unsupported repro format version")
+            }
+            CurveReproParseErrorKind::EngineMismatch => {
+                write!(
+                    formatter,
+                    "line {line}: repro was captured with another integer engine"
+                )
+            }
+            CurveReproParseErrorKind::UnknownRecord => write!(formatter, "line {line}: unknown record"),
+            CurveReproParseErrorKind::InvalidValue => write!(formatter, "line {line}: invalid record value"),
+            CurveReproParseErrorKind::MissingRule => {
+                write!(formatter, "line {line}: overlay or fill rule is not recorded")
+            }
+            CurveReproParseErrorKind::UnterminatedContour => {
+                write!(formatter, "line {line}: contour is not terminated")
+            }
+            CurveReproParseErrorKind::InvalidContour(_) => write!(formatter, "line {line}: invalid contour"),
+            CurveReproParseErrorKind::InvalidOptions(_) => write!(formatter, "line {line}: invalid options"),
+        }
+    }
+}
+
+impl core::error::Error for CurveReproParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self.kind {
+            CurveReproParseErrorKind::InvalidContour(error) => Some(error),
+            CurveReproParseErrorKind::InvalidOptions(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<I: CurveInt> FromStr for CurveOverlayRepro<I> {
+    type Err = CurveReproParseError;
+
+    /// Reads the text written by [`Display`]. Blank lines and lines
+    /// starting with `#` are ignored; omitted solver and option records keep
+    /// their defaults.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut records = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(line, text)| Record {
+                line,
+                tokens: text.split_whitespace(),
+            });
+
+        let mut header = records.next().ok_or(CurveReproParseError {
+            line: 1,
+            kind: CurveReproParseErrorKind::MissingHeader,
+        })?;
+        if header.name() != Some(HEADER) {
+            return Err(header.error(CurveReproParseErrorKind::MissingHeader));
+        }
+        if header.value::<u32>()? != VERSION {
+            return Err(header.error(CurveReproParseErrorKind::UnsupportedVersion));
+        }
+        header.finish()?;
+
+        let mut contours = Vec::new();
+        let mut solver = Solver::default();
+        let mut options = CurveOverlayOptions::default();
+        let mut adapter = CurveReproAdapter::new([0.0; 2], 1.0);
+        let mut has_adapter = false;
+        let mut overlay_rule = None;
+        let mut fill_rule = None;
+        let mut last_line = header.line;
+
+        while let Some(mut record) = records.next() {
+            last_line = record.line;
+            let shape_type = match record.name() {
+                Some("subject") => ShapeType::Subject,
+                Some("clip") => ShapeType::Clip,
+                Some(name) => {
+                    match name {
+                        "engine" => {
+                            if record.token()? != alloc::format!("i{}", I::BITS) {
+                                return Err(record.error(CurveReproParseErrorKind::EngineMismatch));
+                            }
+                        }
+                        "overlay_rule" => overlay_rule = Some(record.named(&OVERLAY_RULES)?),
+                        "fill_rule" => fill_rule = Some(record.named(&FILL_RULES)?),
+                        "solver_strategy" => solver.strategy = record.named(&STRATEGIES)?,
+                        "solver_precision" => {
+                            solver.precision.start = record.value()?;
+                            solver.precision.progression = record.value()?;
+                        }
+                        "solver_multithreading" => {
+                            solver.multithreading = match record.token()? {
+                                "none" => None,
+                                value => Some(MultithreadOptions {
+                                    par_sort_min_size: record.parse(value)?,
+                                }),
+                            }
+                        }
+                        "min_chord_length_power" => options.min_chord_length_power = record.value()?,
+                        "angle_tolerance_power" => options.angle_tolerance_power = record.value()?,
+                        "max_approximation_depth" => options.max_approximation_depth = record.value()?,
+                        "refinement_subdivision_power" => {
+                            options.refinement_subdivision_power = record.value()?
+                        }
+                        "refinement_angle_tolerance_power" => {
+                            options.refinement_angle_tolerance_power = record.value()?
+                        }
+                        "max_refinement_iterations" => options.max_refinement_iterations = record.value()?,
+                        "certified_intersections" => options.certified_intersections = record.value()?,
+                        "canonical_output" => options.canonical_output = record.value()?,
+                        "min_contour_area" => options.min_contour_area = record.value()?,
+                        "min_area_perimeter_ratio" => options.min_area_perimeter_ratio = record.value()?,
+                        "min_segment_length" => options.min_segment_length = record.value()?,
+                        "merge_collinear_lines" => options.merge_collinear_lines = record.value()?,
+                        "output_direction" => options.output_direction = record.named(&CONTOUR_DIRECTIONS)?,
+                        "adapter_offset" => {
+                            adapter.offset = [record.value()?, record.value()?];
+                            has_adapter = true;
+                        }
+                        "adapter_scale" => {
+                            adapter.scale = record.value()?;
+                            has_adapter = true;
+                        }
+                        _ => return Err(record.error(CurveReproParseErrorKind::UnknownRecord)),
+                    }
+                    record.finish()?;
+                    continue;
+                }
+                None => unreachable!("blank lines are skipped"),
+            };
+
+            let contour_line = record.line;
+            let mut path = CurvePath::new(record.point()?, Vec::new());
+            record.finish()?;
+            loop {
+                let mut record = records.next().ok_or(CurveReproParseError {
+                    line: last_line,
+                    kind: CurveReproParseErrorKind::UnterminatedContour,
+                })?;
+                last_line = record.line;
+                let segment = match record.name() {
+                    Some("end") => {
+                        record.finish()?;
+                        break;
+                    }
+                    Some("line") => CurveSegment::Line { to: record.point()? },
+                    Some("quad") => CurveSegment::Quad {
+                        ctrl: record.point()?,
+                        to: record.point()?,
+                    },
+                    Some("cubic") => CurveSegment::Cubic {
+                        ctrl0: record.point()?,
+                        ctrl1: record.point()?,
+                        to: record.point()?,
+                    },
+                    Some("arc") => CurveSegment::Arc { arc: record.arc()? },
+                    _ => return Err(record.error(CurveReproParseErrorKind::UnknownRecord)),
+                };
+                record.finish()?;
+                path.segments.push(segment);
+            }
+
+            let shape = CurveShape::from_path(path);
+            validate_shape(&shape).map_err(|error| CurveReproParseError {
+                line: contour_line,
+                kind: CurveReproParseErrorKind::InvalidContour(error),
+            })?;
+            contours.extend(shape.contours.into_iter().map(|path| (shape_type, path)));
+        }
+
+        options.validate().map_err(|error| CurveReproParseError {
+            line: last_line,
+            kind: CurveReproParseErrorKind::InvalidOptions(error),
+        })?;
+        let (Some(overlay_rule), Some(fill_rule)) = (overlay_rule, fill_rule) else {
+            return Err(CurveReproParseError {
+                line: last_line,
+                kind: CurveReproParseErrorKind::MissingRule,
+            });
+        };
+
+        let repro = Self::new(contours, solver, options, overlay_rule, fill_rule);
+        Ok(if has_adapter {
+            repro.with_adapter(adapter)
+        } else {
+            repro
+        })
+    }
+}
+
+/// Whitespace-separated tokens of one text line.
+struct Record<'a> {
+    line: usize,
+    tokens: SplitWhitespace<'a>,
+}
+
+impl<'a> Record<'a> {
+    #[inline]
+    fn error(&self, kind: CurveReproParseErrorKind) -> CurveReproParseError {
+        CurveReproParseError {
+            line: self.line,
+            kind,
+        }
+    }
+
+    #[inline]
+    fn name(&mut self) -> Option<&'a str> {
+        self.tokens.next()
+    }
+
+    fn token(&mut self) -> Result<&'a str, CurveReproParseError> {
+        self.tokens
+            .next()
+            .ok_or(self.error(CurveReproParseErrorKind::InvalidValue))
+    }
+
+    fn parse<T: FromStr>(&self, token: &str) -> Result<T, CurveReproParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(CurveReproParseErrorKind::InvalidValue))
+    }
+
+    fn value<T: FromStr>(&mut self) -> Result<T, CurveReproParseError> {
+        let token = self.token()?;
+        self.parse(token)
+    }
+
+    fn named<T: Copy>(&mut self, names: &[(T, &str)]) -> Result<T, CurveReproParseError> {
+        let token = self.token()?;
+        names
+            .iter()
+            .find(|(_, name)| *name == token)
+            .map(|&(value, _)| value)
+            .ok_or(self.error(CurveReproParseErrorKind::InvalidValue))
+    }
+
+    fn int<I: CurveInt>(&mut self) -> Result<I, CurveReproParseError> {
+        let token = self.token()?;
+        parse_int(token).ok_or(self.error(CurveReproParseErrorKind::InvalidValue))
+    }
+
+    fn point<I: CurveInt>(&mut self) -> Result<IntPoint<I>, CurveReproParseError> {
+        Ok(IntPoint::new(self.int()?, self.int()?))
+    }
+
+    fn vector<I: CurveInt>(&mut self) -> Result<ArcVector<I>, CurveReproParseError> {
+        Ok(ArcVector {
+            x: self.int()?,
+            y: self.int()?,
+        })
+    }
+
+    fn phase<I: CurveInt>(&mut self) -> Result<ArcPhase<I>, CurveReproParseError> {
+        Ok(ArcPhase {
+            cos: self.int()?,
+            sin: self.int()?,
+        })
+    }
+
+    fn arc<I: CurveInt>(&mut self) -> Result<ArcSegment<I>, CurveReproParseError> {
+        let ellipse = EllipseFrame {
+            center: self.point()?,
+            axis_x: self.vector()?,
+            axis_y: self.vector()?,
+        };
+        let control_points = [self.point()?, self.point()?, self.point()?];
+        let weights = [self.int()?, self.int()?, self.int()?];
+        let start_phase = self.phase()?;
+        let end_phase = self.phase()?;
+        let direction = match self.token()? {
+            "cw" => ArcDirection::Clockwise,
+            "ccw" => ArcDirection::CounterClockwise,
+            _ => return Err(self.error(CurveReproParseErrorKind::InvalidValue)),
+        };
+        Ok(ArcSegment {
+            ellipse,
+            control_points,
+            weights,
+            start_phase,
+            end_phase,
+            direction,
+        })
+    }
+
+    fn finish(&mut self) -> Result<(), CurveReproParseError> {
+        match self.tokens.next() {
+            Some(_) => Err(self.error(CurveReproParseErrorKind::InvalidValue)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[inline]
+fn name_of<T: PartialEq>(names: &[(T, &'static str)], value: T) -> &'static str {
+    names
+        .iter()
+        .find(|(candidate, _)| *candidate == value)
+        .map_or("unknown", |&(_, name)| name)
+}
+
+/// Parses a decimal integer, rejecting values outside the range of `I`.
+fn parse_int<I: CurveInt>(token: &str) -> Option<I> {
+    let (is_negative, digits) = match token.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, token),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let ten = I::from_u32(10);
+    let mut value = I::ZERO;
+    for byte in digits.bytes() {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        let digit = I::from_u32(u32::from(byte - b'0'));
+        value = if is_negative {
+            if value < (I::MIN + digit) / ten {
+                return None;
+            }
+            value * ten - digit
+        } else {
+            if value > (I::MAX - digit) / ten {
+                return None;
+            }
+            value * ten + digit
+        };
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use i_overlay::core::solver::Precision;
+    use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+
+    fn half_disk(center: IntPoint<i32>) -> CurveShape<i32> {
+        let one = FixedScale::<i32>::DENOMINATOR as i32;
+        let ellipse = EllipseFrame {
+            center,
+            axis_x: ArcVector { x: 100, y: 0 },
+            axis_y: ArcVector { x: 0, y: 100 },
+        };
+        let right = IntPoint::new(center.x + 100, center.y);
+        let top = IntPoint::new(center.x, center.y + 100);
+        let left = IntPoint::new(center.x - 100, center.y);
+        CurveShape::from_path(CurvePath::new(
+            right,
+            vec![
+                CurveSegment::Arc {
+                    arc: ArcSegment {
+                        ellipse,
+                        control_points: [right, IntPoint::new(center.x + 100, center.y + 100), top],
+                        weights: [one, 759_250_125, one],
+                        start_phase: ArcPhase { cos: one, sin: 0 },
+                        end_phase: ArcPhase { cos: 0, sin: one },
+                        direction: ArcDirection::CounterClockwise,
+                    },
+                },
+                CurveSegment::Arc {
+                    arc: ArcSegment {
+                        ellipse,
+                        control_points: [top, IntPoint::new(center.x - 100, center.y + 100), left],
+                        weights: [one, 759_250_125, one],
+                        start_phase: ArcPhase { cos: 0, sin: one },
+                        end_phase: ArcPhase { cos: -one, sin: 0 },
+                        direction: ArcDirection::CounterClockwise,
+                    },
+                },
+                CurveSegment::Line { to: right },
+            ],
+        ))
+    }
+
+    fn wave(x: i32, y: i32) -> CurveShape<i32> {
+        let start = IntPoint::new(x, y);
+        CurveShape::from_path(CurvePath::new(
+            start,
+            vec![
+                CurveSegment::Cubic {
+                    ctrl0: IntPoint::new(x + 60, y - 80),
+                    ctrl1: IntPoint::new(x + 120, y + 80),
+                    to: IntPoint::new(x + 180, y),
+                },
+                CurveSegment::Quad {
+                    ctrl: IntPoint::new(x + 90, y - 150),
+                    to: start,
+                },
+            ],
+        ))
+    }
+
+    fn captured_overlay() -> IntCurveOverlay<i32> {
+        let solver = Solver {
+            strategy: Strategy::List,
+            precision: Precision {
+                start: 1,
+                progression: 2,
+            },
+            multithreading: None,
+        };
+        let options = CurveOverlayOptions::default()
+            .with_min_chord_length_power(3)
+            .with_certified_intersections(true)
+            .with_output_direction(ContourDirection::Clockwise)
+            .with_canonical_output(true)
+            .with_min_contour_area(16)
+            .with_min_area_perimeter_ratio(1)
+            .with_min_segment_length(2)
+            .with_merge_collinear_lines(true);
+        let mut overlay = IntCurveOverlay::new()
+            .with_solver(solver)
+            .try_with_options(options)
+            .unwrap();
+        overlay.add_subject(half_disk(IntPoint::new(0, 0))).unwrap();
+        overlay.add_clip(wave(-90, 40)).unwrap();
+        overlay.add_subject(wave(-90, -40)).unwrap();
+        overlay
+    }
+
+    #[test]
+    fn text_round_trip_preserves_every_record() {
+        let overlay = captured_overlay();
+        let repro = overlay.capture_repro(OverlayRule::Xor, FillRule::EvenOdd);
+        let text = repro.to_string();
+
+        let restored: CurveOverlayRepro<i32> = text.parse().unwrap();
+        assert_eq!(restored.to_string(), text);
+        assert_eq!(restored.contours(), repro.contours());
+        assert_eq!(restored.options(), overlay.options());
+        assert_eq!(restored.solver().strategy, Strategy::List);
+        assert_eq!(
+            restored.solver().precision,
+            Precision {
+                start: 1,
+                progression: 2
+            }
+        );
+        assert!(restored.solver().multithreading.is_none());
+        assert_eq!(restored.overlay_rule(), OverlayRule::Xor);
+        assert_eq!(restored.fill_rule(), FillRule::EvenOdd);
+        assert_eq!(restored.adapter(), None);
+    }
+
+    #[test]
+    fn replay_matches_the_captured_overlay() {
+        for overlay_rule in OVERLAY_RULES.map(|(rule, _)| rule) {
+            let overlay = captured_overlay();
+            let repro = overlay.capture_repro(overlay_rule, FillRule::NonZero);
+            let restored: CurveOverlayRepro<i32> = repro.to_string().parse().unwrap();
+            let expected = overlay.overlay(overlay_rule, FillRule::NonZero);
+            assert_eq!(restored.replay(), expected, "{overlay_rule:?}");
+        }
+    }
+
+    #[test]
+    fn parse_accepts_comments_and_default_settings() {
+        let text = "
+            # union of two squares
+            i_curve_repro 1
+            overlay_rule union
+            fill_rule non_zero
+            subject 0 0
+            line 10 0
+            line 10 10
+            line 0 10
+            line 0 0
+            end
+            clip 5 5
+            line 15 5
+            line 15 15
+            line 5 15
+            line 5 5
+            end
+        ";
+        let repro: CurveOverlayRepro<i16> = text.parse().unwrap();
+        assert_eq!(repro.contours().len(), 2);
+        assert_eq!(repro.options(), CurveOverlayOptions::default());
+        assert_eq!(repro.replay().len(), 1);
+    }
+
+    #[test]
+    fn parse_reports_the_rejected_line() {
+        let parse = |text: &str| text.parse::<CurveOverlayRepro<i32>>().unwrap_err();
+        let header = "i_curve_repro 1\noverlay_rule union\nfill_rule non_zero\n";
+
+        assert_eq!(parse("").kind, CurveReproParseErrorKind::MissingHeader);
+        assert_eq!(
+            parse("i_curve_repro 2").kind,
+            CurveReproParseErrorKind::UnsupportedVersion
+        );
+        assert_eq!(
+            parse("i_curve_repro 1\nengine i64").kind,
+            CurveReproParseErrorKind::EngineMismatch
+        );
+        assert_eq!(
+            parse("i_curve_repro 1\noverlay_rule union").kind,
+            CurveReproParseErrorKind::MissingRule
+        );
+
+        let error = parse(&alloc::format!("{header}subject 0 0\nline 10 0\nspline 1 2\nend"));
+        assert_eq!(error.line, 6);
+        assert_eq!(error.kind, CurveReproParseErrorKind::UnknownRecord);
+
+        let error = parse(&alloc::format!("{header}subject 0 0\nline 10 0 7\nend"));
+        assert_eq!(error.line, 5);
+        assert_eq!(error.kind, CurveReproParseErrorKind::InvalidValue);
+
+        let error = parse(&alloc::format!("{header}subject 0 0\nline 10 0\nline 0 0"));
+        assert_eq!(error.kind, CurveReproParseErrorKind::UnterminatedContour);
+
+        let error = parse(&alloc::format!("{header}subject 0 0\nline 10 0\nend"));
+        assert_eq!(error.line, 4);
+        assert!(matches!(error.kind, CurveReproParseErrorKind::InvalidContour(_)));
+
+        let error = parse(&alloc::format!("{header}max_approximation_depth 99"));
+        assert!(matches!(error.kind, CurveReproParseErrorKind::InvalidOptions(_)));
+    }
+
+    #[test]
+    fn parse_int_rejects_values_outside_the_engine_range() {
+        assert_eq!(parse_int::<i16>("-32768"), Some(i16::MIN));
+        assert_eq!(parse_int::<i16>("32767"), Some(i16::MAX));
+        assert_eq!(parse_int::<i16>("32768"), None);
+        assert_eq!(parse_int::<i16>("-32769"), None);
+        assert_eq!(parse_int::<i64>("-9223372036854775808"), Some(i64::MIN));
+        assert_eq!(parse_int::<i32>("-"), None);
+        assert_eq!(parse_int::<i32>("+5"), None);
+        assert_eq!(parse_int::<i32>("1e3"), None);
+    }
+}
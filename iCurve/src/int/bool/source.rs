@@ -1,18 +1,48 @@
 use crate::int::CurveInt;
+use crate::int::curve::path::CurvePath;
 use crate::kernel::int::curve::segment::Segment;
+use core::ops::Range;
 use i_overlay::core::overlay::ShapeType;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub(crate) struct CurveId(pub(crate) usize);
 
+/// Input position of a source curve: the contour in input order, the
+/// segment within it, and the simple piece of that segment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CurveOrigin {
+    pub(crate) contour: usize,
+    pub(crate) segment: usize,
+    pub(crate) piece: usize,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct CurveSource<I: CurveInt> {
     pub(crate) curve: Segment<I>,
     pub(crate) shape_type: ShapeType,
+    pub(crate) origin: CurveOrigin,
 }
 
 impl<I: CurveInt> CurveSource<I> {
+    #[cfg(test)]
     pub(crate) fn new(curve: Segment<I>, shape_type: ShapeType) -> Self {
-        Self { curve, shape_type }
+        Self::with_origin(curve, shape_type, CurveOrigin::default())
+    }
+
+    pub(crate) fn with_origin(curve: Segment<I>, shape_type: ShapeType, origin: CurveOrigin) -> Self {
+        Self {
+            curve,
+            shape_type,
+            origin,
+        }
     }
 }
+
+/// Input contour together with the sources and edges derived from it.
+#[derive(Debug, Clone)]
+pub(crate) struct CurveContourSource<I: CurveInt> {
+    pub(crate) path: CurvePath<I>,
+    pub(crate) shape_type: ShapeType,
+    pub(crate) sources: Range<usize>,
+    pub(crate) edges: Range<usize>,
+}
@@ -0,0 +1,956 @@
+use crate::int::CurveInt;
+use crate::int::bool::overlay::{CurveInputError, validate_shape};
+use crate::int::curve::path::CurvePath;
+use crate::int::curve::shape::CurveShape;
+use crate::kernel::int::cross::intersector::{
+    ContactType, SegmentIntersectionBuffer, SegmentIntersector, SplitOptions,
+};
+use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::param::SegmentParam;
+use crate::kernel::int::curve::point_at::PointAt;
+use crate::kernel::int::curve::segment::Segment;
+use crate::kernel::int::normalization::canonical::{PushCanonicalSimpleParametricSegment, PushSimpleSegment};
+use alloc::vec::Vec;
+use core::ops::Range;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay::{ContourDirection, Overlay, ShapeType};
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::i_float::float::number::FloatNumber;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_float::int::rect::IntRect;
+use i_overlay::i_shape::int::IntPoint;
+use i_overlay::i_shape::int::shape::IntShapes;
+
+/// Largest number of chords a single segment is flattened into.
+const MAX_SEGMENT_SAMPLES: u32 = 4096;
+
+/// Largest distance by which snapping intersections and split control
+/// points to the grid moves a boundary of the exact result.
+const GRID_SNAP_DISTANCE: f64 = 1.0;
+
+/// Tolerances used by [`verify_overlay`].
+///
+/// Distances are expressed in the integer coordinate system. Construct
+/// this non-exhaustive configuration from [`Default`] and override only the
+/// values your application needs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CurveVerificationOptions {
+    /// Largest distance between a curve and the polyline that replaces it
+    /// in the reference computation.
+    pub flattening_tolerance: f64,
+    /// Distance from a segment end within which a crossing of two result
+    /// segments is attributed to their shared vertex.
+    ///
+    /// Crossings this close to a vertex are below the grid resolution the
+    /// overlay snaps intersections to.
+    pub vertex_tolerance: f64,
+    /// Expected orientation of result contours, matching
+    /// [`CurveOverlayOptions::output_direction`](crate::int::CurveOverlayOptions::output_direction).
+    /// With [`ContourDirection::CounterClockwise`], outer contours must run
+    /// counter-clockwise and holes clockwise; `Clockwise` expects both
+    /// reversed.
+    pub output_direction: ContourDirection,
+}
+
+impl Default for CurveVerificationOptions {
+    fn default() -> Self {
+        Self {
+            flattening_tolerance: 0.5,
+            vertex_tolerance: 8.0,
+            output_direction: ContourDirection::CounterClockwise,
+        }
+    }
+}
+
+impl CurveVerificationOptions {
+    /// Sets the largest distance between a curve and its polyline.
+    #[must_use]
+    pub const fn with_flattening_tolerance(mut self, tolerance: f64) -> Self {
+        self.flattening_tolerance = tolerance;
+        self
+    }
+
+    /// Sets the distance within which crossings count as shared vertices.
+    #[must_use]
+    pub const fn with_vertex_tolerance(mut self, tolerance: f64) -> Self {
+        self.vertex_tolerance = tolerance;
+        self
+    }
+
+    /// Sets the expected orientation of result contours.
+    #[must_use]
+    pub const fn with_output_direction(mut self, direction: ContourDirection) -> Self {
+        self.output_direction = direction;
+        self
+    }
+}
+
+/// Position of one segment in a Boolean result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurveSegmentLocation {
+    /// Zero-based result shape index.
+    pub shape: usize,
+    /// Zero-based contour index within the shape.
+    pub contour: usize,
+    /// Zero-based segment index within the contour.
+    pub segment: usize,
+}
+
+/// Defect found by [`verify_overlay`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum CurveVerificationIssue<I: CurveInt> {
+    /// A result shape is not a valid closed curve shape.
+    InvalidShape {
+        /// Zero-based result shape index.
+        shape: usize,
+        /// Structural error of the shape.
+        error: CurveInputError,
+    },
+    /// An outer contour or a hole does not run in the direction that
+    /// [`CurveVerificationOptions::output_direction`] expects. The first
+    /// contour of a shape is its outer contour. Slivers no wider than the
+    /// band snapping moves a boundary by have no reliable orientation and
+    /// are not checked.
+    WrongOrientation {
+        /// Zero-based result shape index.
+        shape: usize,
+        /// Zero-based contour index within the shape.
+        contour: usize,
+    },
+    /// Two result segments cross away from their endpoints. Crossings that
+    /// only close a grid-thin loop are snapping residue and are not
+    /// reported.
+    SelfIntersection {
+        /// Grid point of the contact.
+        point: IntPoint<I>,
+        /// The two segments in contact.
+        segments: [CurveSegmentLocation; 2],
+    },
+    /// Two result segments run along each other away from their endpoints.
+    ///
+    /// Snapping collapses gaps narrower than a grid unit, within a contour
+    /// or between two, into shared runs, so this is a warning rather than a
+    /// defect and does not fail [`CurveVerification::is_valid`]. Runs that
+    /// only close a grid-thin loop of one contour are not reported.
+    SharedBoundary {
+        /// Grid point of the contact.
+        point: IntPoint<I>,
+        /// The two segments in contact.
+        segments: [CurveSegmentLocation; 2],
+    },
+    /// The result differs from the polygon reference by more area than
+    /// the tolerance allows.
+    AreaMismatch {
+        /// Area of the symmetric difference of result and reference.
+        symmetric_difference: f64,
+        /// Largest accepted symmetric-difference area.
+        allowed: f64,
+    },
+}
+
+/// Outcome of [`verify_overlay`].
+#[derive(Debug, Clone)]
+pub struct CurveVerification<I: CurveInt> {
+    result_area: f64,
+    reference_area: f64,
+    symmetric_difference_area: f64,
+    issues: Vec<CurveVerificationIssue<I>>,
+}
+
+impl<I: CurveInt> CurveVerificationIssue<I> {
+    /// Returns whether the issue makes the result invalid, as every issue
+    /// except [`SharedBoundary`](Self::SharedBoundary) does.
+    #[inline]
+    pub fn is_defect(&self) -> bool {
+        !matches!(self, Self::SharedBoundary { .. })
+    }
+}
+
+impl<I: CurveInt> CurveVerification<I> {
+    /// Returns whether no defect was found.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        !self.issues.iter().any(CurveVerificationIssue::is_defect)
+    }
+
+    /// Returns every defect and warning found.
+    #[inline]
+    pub fn issues(&self) -> &[CurveVerificationIssue<I>] {
+        &self.issues
+    }
+
+    /// Returns the area of the flattened result.
+    #[inline]
+    pub fn result_area(&self) -> f64 {
+        self.result_area
+    }
+
+    /// Returns the area of the polygon Boolean of the flattened operands.
+    #[inline]
+    pub fn reference_area(&self) -> f64 {
+        self.reference_area
+    }
+
+    /// Returns the area of the symmetric difference of the flattened result
+    /// and the reference.
+    #[inline]
+    pub fn symmetric_difference_area(&self) -> f64 {
+        self.symmetric_difference_area
+    }
+}
+
+/// Checks a Boolean result against the operands it was computed from.
+///
+/// The operands and `result` are flattened to polylines within
+/// [`CurveVerificationOptions::flattening_tolerance`]. The polygon Boolean of
+/// the flattened operands is the reference. Both flattened boundaries stay
+/// within the flattening tolerance of the exact curves and the result moves
+/// by up to one grid unit where it snaps, so the symmetric difference may
+/// cover at most twice the flattening tolerance plus one unit per unit of
+/// boundary length. The result must also consist of closed contours
+/// oriented as [`CurveVerificationOptions::output_direction`] expects, and no
+/// two of its segments may cross away from their endpoints, as bounded by
+/// [`CurveVerificationOptions::vertex_tolerance`]. Crossings are found on the
+/// exact curves.
+///
+/// Snapping moves result boundaries by up to a grid unit, so the checks
+/// leave room for the slivers it produces. A region whose mean width is
+/// below the flattening tolerance plus one grid unit on each side is
+/// grid-thin: a grid-thin contour is not checked for orientation, and a
+/// contact that closes a grid-thin loop, along its contour or back to the
+/// nearest segment ends, is not reported. Such slivers still count towards
+/// the area. Gaps that snapping collapses into shared runs are reported as
+/// [`CurveVerificationIssue::SharedBoundary`] warnings, which
+/// [`CurveVerification::is_valid`] ignores.
+///
+/// The cost is comparable to the overlay itself, so production services
+/// usually verify a sample of operations.
+pub fn verify_overlay<I: CurveInt>(
+    subject: &[CurveShape<I>],
+    clip: &[CurveShape<I>],
+    result: &[CurveShape<I>],
+    overlay_rule: OverlayRule,
+    fill_rule: FillRule,
+    options: CurveVerificationOptions,
+) -> CurveVerification<I> {
+    let tolerance = options.flattening_tolerance;
+    let is_counter_clockwise = options.output_direction == ContourDirection::CounterClockwise;
+    let mut issues = Vec::new();
+    let mut points = Vec::new();
+
+    let mut reference_overlay = Overlay::new(0);
+    for (shapes, shape_type) in [(subject, ShapeType::Subject), (clip, ShapeType::Clip)] {
+        for path in shapes.iter().flat_map(|shape| &shape.contours) {
+            flatten_path(path, tolerance, &mut points);
+            reference_overlay.add_path_iter(points.iter().copied(), shape_type);
+        }
+    }
+    let reference = reference_overlay.overlay(overlay_rule, fill_rule);
+
+    let mut difference_overlay = Overlay::new(0);
+    let mut result_area = 0.0;
+    let mut perimeter = 0.0;
+    for (shape_index, shape) in result.iter().enumerate() {
+        if let Err(error) = validate_shape(shape) {
+            issues.push(CurveVerificationIssue::InvalidShape {
+                shape: shape_index,
+                error,
+            });
+        }
+
+        for (contour_index, path) in shape.contours.iter().enumerate() {
+            flatten_path(path, tolerance, &mut points);
+            let area = signed_area(&points);
+            let contour_length = length(&points);
+            let is_outer = contour_index == 0;
+            let is_reversed = (area > 0.0) != (is_outer == is_counter_clockwise);
+            if is_reversed && !is_grid_thin(area, contour_length, tolerance) {
+                issues.push(CurveVerificationIssue::WrongOrientation {
+                    shape: shape_index,
+                    contour: contour_index,
+                });
+            }
+            result_area += if is_outer { area.abs() } else { -area.abs() };
+            perimeter += contour_length;
+            difference_overlay.add_path_iter(points.iter().copied(), ShapeType::Subject);
+        }
+    }
+
+    for contour in reference.iter().flatten() {
+        perimeter += length(contour);
+        difference_overlay.add_path_iter(contour.iter().copied(), ShapeType::Clip);
+    }
+    let difference = difference_overlay.overlay(OverlayRule::Xor, FillRule::EvenOdd);
+
+    let symmetric_difference = shapes_area(&difference);
+    // The perimeter sums both boundaries, so half of it is their mean length.
+    let allowed = (2.0 * tolerance + GRID_SNAP_DISTANCE) * 0.5 * perimeter;
+    if symmetric_difference > allowed {
+        issues.push(CurveVerificationIssue::AreaMismatch {
+            symmetric_difference,
+            allowed,
+        });
+    }
+
+    find_self_intersections(result, tolerance, options.vertex_tolerance, &mut issues);
+
+    CurveVerification {
+        result_area,
+        reference_area: shapes_area(&reference),
+        symmetric_difference_area: symmetric_difference,
+        issues,
+    }
+}
+
+/// Replaces `points` with a closed polyline of `path` without its repeated
+/// closing point.
+pub(super) fn flatten_path<I: CurveInt>(path: &CurvePath<I>, tolerance: f64, points: &mut Vec<IntPoint<I>>) {
+    points.clear();
+    points.push(path.start);
+
+    let mut current = path.start;
+    for segment in &path.segments {
+        let (curve, end) = segment.clone().into_kernel_segment(current);
+        let count = sample_count(&curve, tolerance);
+        let denominator = I::from_u32(count);
+        for index in 1..count {
+            let param = SegmentParam::from_int(I::from_u32(index), denominator);
+            points.push(curve.point_at(param));
+        }
+        points.push(end);
+        current = end;
+    }
+
+    if points.len() > 1 && points.last() == points.first() {
+        points.pop();
+    }
+}
+
+/// Number of uniform chords that keep a segment within `tolerance` of its
+/// polyline, from the second differences of its control points.
+fn sample_count<I: CurveInt>(segment: &Segment<I>, tolerance: f64) -> u32 {
+    let (points, weight_ratio): (&[IntPoint<I>], f64) = match segment {
+        Segment::Line(_) => return 1,
+        Segment::Quad(quad) => (&quad.control_points, 1.0),
+        Segment::Cubic(cubic) => (&cubic.control_points, 1.0),
+        Segment::Arc(arc) => {
+            let weights = arc.weights.map(|weight| weight.to_f64());
+            let min = weights.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = weights.iter().copied().fold(0.0, f64::max);
+            (&arc.control_points, max / min)
+        }
+    };
+
+    let degree = (points.len() - 1) as f64;
+    let mut max_difference: f64 = 0.0;
+    for window in points.windows(3) {
+        let x = window[0].x.to_f64() - 2.0 * window[1].x.to_f64() + window[2].x.to_f64();
+        let y = window[0].y.to_f64() - 2.0 * window[1].y.to_f64() + window[2].y.to_f64();
+        max_difference = max_difference.max(x.abs().max(y.abs()));
+    }
+
+    let bound =
+        degree * (degree - 1.0) * max_difference * weight_ratio / (8.0 * tolerance.max(f64::MIN_POSITIVE));
+    let root = <f64 as FloatNumber>::sqrt(bound);
+    if root.is_nan() || root >= MAX_SEGMENT_SAMPLES as f64 {
+        return MAX_SEGMENT_SAMPLES;
+    }
+    let count = root as u32;
+    let count = if (count as f64) < root { count + 1 } else { count };
+    count.max(1)
+}
+
+pub(super) fn signed_area<I: CurveInt>(points: &[IntPoint<I>]) -> f64 {
+    let Some(&last) = points.last() else {
+        return 0.0;
+    };
+    let mut previous = last;
+    let mut doubled = 0.0;
+    for &point in points {
+        doubled += previous.x.to_f64() * point.y.to_f64() - point.x.to_f64() * previous.y.to_f64();
+        previous = point;
+    }
+    0.5 * doubled
+}
+
+pub(super) fn length<I: CurveInt>(points: &[IntPoint<I>]) -> f64 {
+    let Some(&last) = points.last() else {
+        return 0.0;
+    };
+    let mut previous = last;
+    let mut total = 0.0;
+    for &point in points {
+        let dx = point.x.to_f64() - previous.x.to_f64();
+        let dy = point.y.to_f64() - previous.y.to_f64();
+        total += <f64 as FloatNumber>::sqrt(dx * dx + dy * dy);
+        previous = point;
+    }
+    total
+}
+
+/// Area of polygon shapes whose first contour is the outer contour.
+fn shapes_area<I: CurveInt>(shapes: &IntShapes<I>) -> f64 {
+    shapes
+        .iter()
+        .map(|shape| {
+            shape
+                .iter()
+                .enumerate()
+                .map(|(index, contour)| {
+                    let area = signed_area(contour).abs();
+                    if index == 0 { area } else { -area }
+                })
+                .sum::<f64>()
+        })
+        .sum()
+}
+
+/// Returns `true` when a flattened loop is no wider than the band its two
+/// sides may move by, so snapping alone may cross its sides or turn its
+/// orientation over.
+///
+/// Each side lies within the flattening tolerance of the exact curve and
+/// moves by up to one grid unit where it snaps. The mean width of a loop
+/// is twice its area over its length, which covers both sides.
+#[inline]
+fn is_grid_thin(area: f64, length: f64, tolerance: f64) -> bool {
+    area.abs() <= (tolerance + GRID_SNAP_DISTANCE) * length
+}
+
+struct Piece<I: CurveInt> {
+    rect: IntRect<I>,
+    location: CurveSegmentLocation,
+    /// Index of the piece in contour order.
+    index: usize,
+    /// Contour-order indices of the pieces of its contour.
+    contour: Range<usize>,
+}
+
+/// Reports result segments that cross or overlap away from their endpoints.
+///
+/// Segments are split into the canonical simple pieces the overlay works
+/// on, and every pair of pieces with touching bounds is intersected.
+/// Contacts that close a grid-thin loop, as the sides of a sliver or of a
+/// thin spike do once snapped, are not reported. The loop follows the
+/// contour between two contacts of one contour, and a crossing may also
+/// close it through the nearest pair of piece ends. Overlaps are reported
+/// as shared boundaries.
+fn find_self_intersections<I: CurveInt>(
+    result: &[CurveShape<I>],
+    tolerance: f64,
+    vertex_tolerance: f64,
+    issues: &mut Vec<CurveVerificationIssue<I>>,
+) {
+    let mut curves = Vec::new();
+    let mut pieces = Vec::new();
+    let mut simple_curves = Vec::new();
+    let mut canonical_curves = Vec::new();
+    for (shape_index, shape) in result.iter().enumerate() {
+        for (contour_index, path) in shape.contours.iter().enumerate() {
+            let first = curves.len();
+            let mut current = path.start;
+            for (segment_index, segment) in path.segments.iter().enumerate() {
+                let (curve, end) = segment.clone().into_kernel_segment(current);
+                current = end;
+
+                let location = CurveSegmentLocation {
+                    shape: shape_index,
+                    contour: contour_index,
+                    segment: segment_index,
+                };
+                simple_curves.push_simple(curve);
+                for simple_curve in simple_curves.drain(..) {
+                    canonical_curves.push_canonical_simple_parametric(simple_curve);
+                }
+                for canonical in canonical_curves.drain(..) {
+                    pieces.push(Piece {
+                        rect: IntRect::with_points(canonical.curve.convex_hull().as_slice()).unwrap(),
+                        location,
+                        index: curves.len(),
+                        contour: 0..0,
+                    });
+                    curves.push(canonical.curve);
+                }
+            }
+            for piece in &mut pieces[first..] {
+                piece.contour = first..curves.len();
+            }
+        }
+    }
+    pieces.sort_unstable_by_key(|piece| piece.rect.min_x);
+
+    let mut buffer = SegmentIntersectionBuffer::default();
+    let mut points = Vec::new();
+    for (index, piece) in pieces.iter().enumerate() {
+        for other in &pieces[index + 1..] {
+            if other.rect.min_x > piece.rect.max_x {
+                break;
+            }
+            if !piece.rect.is_intersect_border_include(&other.rect) {
+                continue;
+            }
+
+            let pair = [piece, other];
+            let intersector =
+                SegmentIntersector::new(curves[piece.index], curves[other.index], SplitOptions::default());
+            intersector.intersect_with_buffer(&mut buffer);
+            let contacts = buffer.contacts();
+            let endpoints = pair.map(|piece| {
+                let chord = curves[piece.index].chord();
+                [chord.a, chord.b]
+            });
+            let is_away = |point: IntPoint<I>| {
+                !endpoints
+                    .iter()
+                    .flatten()
+                    .any(|&end| is_within(point, end, vertex_tolerance))
+            };
+            let is_same_contour = piece.contour == other.contour;
+            let is_snapped_walk = |params: [SegmentParam<I>; 2], points: &mut Vec<IntPoint<I>>| {
+                is_same_contour && is_snapped_contour_contact(&curves, pair, params, tolerance, points)
+            };
+            let segments = [piece.location, other.location];
+            if buffer.is_overlap() {
+                // Runs shared by two whole pieces have no contacts of their own.
+                let point = if contacts.is_empty() {
+                    Some(endpoints[0][0])
+                } else {
+                    // Shared runs longer than the tolerance count even when
+                    // they end at vertices; shorter ones are snapping residue.
+                    let is_long = contacts.iter().any(|contact| {
+                        contacts
+                            .iter()
+                            .any(|other| !is_within(contact.point, other.point, vertex_tolerance))
+                    });
+                    contacts
+                        .iter()
+                        .find(|contact| {
+                            (is_long || is_away(contact.point))
+                                && !is_snapped_walk([contact.t0, contact.t1], &mut points)
+                        })
+                        .map(|contact| contact.point)
+                };
+                if let Some(point) = point {
+                    issues.push(CurveVerificationIssue::SharedBoundary { point, segments });
+                }
+            } else if let Some(contact) = contacts.iter().find(|contact| {
+                contact.contact_type == ContactType::Cross
+                    && is_away(contact.point)
+                    && !is_snapped_crossing(
+                        pair.map(|piece| &curves[piece.index]),
+                        [contact.t0, contact.t1],
+                        tolerance,
+                        &mut points,
+                    )
+                    && !is_snapped_walk([contact.t0, contact.t1], &mut points)
+            }) {
+                issues.push(CurveVerificationIssue::SelfIntersection {
+                    point: contact.point,
+                    segments,
+                });
+            }
+        }
+    }
+}
+
+/// Returns `true` when a contact of two pieces of one contour closes a
+/// grid-thin loop with either part of the contour between them.
+fn is_snapped_contour_contact<I: CurveInt>(
+    curves: &[Segment<I>],
+    pieces: [&Piece<I>; 2],
+    params: [SegmentParam<I>; 2],
+    tolerance: f64,
+    points: &mut Vec<IntPoint<I>>,
+) -> bool {
+    [[0, 1], [1, 0]].into_iter().any(|[from, to]| {
+        points.clear();
+        push_contour_walk(
+            curves,
+            pieces[from],
+            [params[from].value(), params[to].value()],
+            pieces[to].index,
+            tolerance,
+            points,
+        );
+        is_grid_thin(signed_area(points), length(points), tolerance)
+    })
+}
+
+/// Pushes samples of the contour from parameter `params[0]` of `from`
+/// forward to parameter `params[1]` of the piece at index `to`.
+fn push_contour_walk<I: CurveInt>(
+    curves: &[Segment<I>],
+    from: &Piece<I>,
+    params: [I::Wide; 2],
+    to: usize,
+    tolerance: f64,
+    points: &mut Vec<IntPoint<I>>,
+) {
+    if from.index == to && params[0] <= params[1] {
+        push_samples(&curves[to], params[0], params[1], tolerance, points);
+        return;
+    }
+
+    let next = |index: usize| {
+        if index + 1 == from.contour.end {
+            from.contour.start
+        } else {
+            index + 1
+        }
+    };
+    push_samples(
+        &curves[from.index],
+        params[0],
+        SegmentParam::<I>::DENOMINATOR,
+        tolerance,
+        points,
+    );
+    let mut index = next(from.index);
+    while index != to {
+        push_samples(
+            &curves[index],
+            I::Wide::ZERO,
+            SegmentParam::<I>::DENOMINATOR,
+            tolerance,
+            points,
+        );
+        index = next(index);
+    }
+    push_samples(&curves[to], I::Wide::ZERO, params[1], tolerance, points);
+}
+
+/// Returns `true` when a crossing closes a grid-thin loop with the nearest
+/// pair of piece ends, so snapping alone may have caused it.
+///
+/// The loop runs along the first piece from the crossing to its end, over
+/// to the nearest end of the second piece, and back along it.
+fn is_snapped_crossing<I: CurveInt>(
+    curves: [&Segment<I>; 2],
+    params: [SegmentParam<I>; 2],
+    tolerance: f64,
+    points: &mut Vec<IntPoint<I>>,
+) -> bool {
+    let ends = curves.map(|curve| {
+        let chord = curve.chord();
+        [
+            (I::Wide::ZERO, chord.a),
+            (SegmentParam::<I>::DENOMINATOR, chord.b),
+        ]
+    });
+    let Some((end0, end1)) = ends[0]
+        .iter()
+        .flat_map(|&end0| ends[1].iter().map(move |&end1| (end0, end1)))
+        .min_by_key(|(end0, end1)| end0.1.sqr_distance(end1.1))
+    else {
+        return false;
+    };
+
+    points.clear();
+    push_samples(curves[0], params[0].value(), end0.0, tolerance, points);
+    push_samples(curves[1], end1.0, params[1].value(), tolerance, points);
+    is_grid_thin(signed_area(points), length(points), tolerance)
+}
+
+/// Pushes points of `curve` from parameter `from` to `to`, as densely as
+/// [`flatten_path`] samples the whole curve.
+fn push_samples<I: CurveInt>(
+    curve: &Segment<I>,
+    from: I::Wide,
+    to: I::Wide,
+    tolerance: f64,
+    points: &mut Vec<IntPoint<I>>,
+) {
+    let count = sample_count(curve, tolerance);
+    let samples = I::Wide::from_u32(count);
+    for index in 0..=count {
+        let param = from + (to - from) * I::Wide::from_u32(index) / samples;
+        points.push(curve.point_at(SegmentParam::new(I::from_wide(param))));
+    }
+}
+
+#[inline]
+fn is_within<I: CurveInt>(a: IntPoint<I>, b: IntPoint<I>, radius: f64) -> bool {
+    let dx = a.x.to_f64() - b.x.to_f64();
+    let dy = a.y.to_f64() - b.y.to_f64();
+    dx * dx + dy * dy <= radius * radius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int::bool::overlay::{CurveOverlayOptions, IntCurveOverlay};
+    use crate::int::curve::segment::CurveSegment;
+    use alloc::vec;
+
+    fn square(x0: i32, y0: i32, x1: i32, y1: i32) -> CurveShape<i32> {
+        CurveShape::from_path(CurvePath::new(
+            IntPoint::new(x0, y0),
+            vec![
+                CurveSegment::Line {
+                    to: IntPoint::new(x1, y0),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x1, y1),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x0, y1),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(x0, y0),
+                },
+            ],
+        ))
+    }
+
+    fn blob(x: i32, y: i32) -> CurveShape<i32> {
+        CurveShape::from_path(CurvePath::new(
+            IntPoint::new(x, y),
+            vec![
+                CurveSegment::Cubic {
+                    ctrl0: IntPoint::new(x + 400, y - 300),
+                    ctrl1: IntPoint::new(x + 800, y + 300),
+                    to: IntPoint::new(x + 1000, y + 600),
+                },
+                CurveSegment::Quad {
+                    ctrl: IntPoint::new(x + 100, y + 1200),
+                    to: IntPoint::new(x, y),
+                },
+            ],
+        ))
+    }
+
+    fn overlay(subject: &CurveShape<i32>, clip: &CurveShape<i32>, rule: OverlayRule) -> Vec<CurveShape<i32>> {
+        let mut overlay = IntCurveOverlay::new();
+        overlay.add_subject(subject.clone()).unwrap();
+        overlay.add_clip(clip.clone()).unwrap();
+        overlay.overlay(rule, FillRule::NonZero)
+    }
+
+    fn verify(
+        subject: &CurveShape<i32>,
+        clip: &CurveShape<i32>,
+        result: &[CurveShape<i32>],
+        rule: OverlayRule,
+    ) -> CurveVerification<i32> {
+        verify_overlay(
+            core::slice::from_ref(subject),
+            core::slice::from_ref(clip),
+            result,
+            rule,
+            FillRule::NonZero,
+            CurveVerificationOptions::default(),
+        )
+    }
+
+    #[test]
+    fn curve_overlay_results_verify() {
+        let subject = blob(0, 0);
+        let clip = square(300, 100, 1200, 700);
+
+        for rule in [
+            OverlayRule::Union,
+            OverlayRule::Intersect,
+            OverlayRule::Difference,
+            OverlayRule::Xor,
+        ] {
+            let result = overlay(&subject, &clip, rule);
+            let verification = verify(&subject, &clip, &result, rule);
+            assert!(verification.is_valid(), "{rule:?}: {:?}", verification.issues());
+            let relative = (verification.result_area() - verification.reference_area()).abs()
+                / verification.reference_area();
+            assert!(relative < 1.0e-3, "{rule:?}: relative area error {relative}");
+        }
+    }
+
+    #[test]
+    fn wrong_result_reports_area_mismatch() {
+        let subject = square(0, 0, 100, 100);
+        let clip = square(50, 50, 150, 150);
+        let result = overlay(&subject, &clip, OverlayRule::Union);
+
+        let verification = verify(&subject, &clip, &result, OverlayRule::Intersect);
+
+        assert!(matches!(
+            verification.issues(),
+            [CurveVerificationIssue::AreaMismatch { symmetric_difference, .. }]
+                if (*symmetric_difference - 15_000.0).abs() < 1.0
+        ));
+    }
+
+    #[test]
+    fn small_offset_reports_area_mismatch() {
+        let subject = square(0, 0, 100, 100);
+        let clip = square(50, 50, 150, 150);
+        let grown = square(47, 47, 103, 103);
+
+        let verification = verify(&subject, &clip, &[grown], OverlayRule::Intersect);
+
+        assert!(matches!(
+            verification.issues(),
+            [CurveVerificationIssue::AreaMismatch { symmetric_difference, allowed }]
+                if (*symmetric_difference - 636.0).abs() < 1.0 && *allowed < 636.0
+        ));
+    }
+
+    #[test]
+    fn clockwise_results_verify_against_the_expected_direction() {
+        let subject = blob(0, 0);
+        let clip = square(300, 100, 1200, 700);
+        let options = CurveOverlayOptions::default().with_output_direction(ContourDirection::Clockwise);
+        let mut overlay = IntCurveOverlay::new().try_with_options(options).unwrap();
+        overlay.add_subject(subject.clone()).unwrap();
+        overlay.add_clip(clip.clone()).unwrap();
+        let result = overlay.overlay(OverlayRule::Difference, FillRule::NonZero);
+        let verify_as = |direction| {
+            verify_overlay(
+                core::slice::from_ref(&subject),
+                core::slice::from_ref(&clip),
+                &result,
+                OverlayRule::Difference,
+                FillRule::NonZero,
+                CurveVerificationOptions::default().with_output_direction(direction),
+            )
+        };
+
+        let clockwise = verify_as(ContourDirection::Clockwise);
+        assert!(clockwise.is_valid(), "{:?}", clockwise.issues());
+        let counter_clockwise = verify_as(ContourDirection::CounterClockwise);
+        assert!(
+            counter_clockwise
+                .issues()
+                .iter()
+                .all(|issue| matches!(issue, CurveVerificationIssue::WrongOrientation { .. }))
+        );
+        assert_eq!(
+            counter_clockwise.issues().len(),
+            result.iter().map(|shape| shape.contours.len()).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn result_defects_are_reported() {
+        let subject = square(0, 0, 100, 100);
+        let clip = square(50, 50, 150, 150);
+        let mut reversed = subject.clone();
+        reversed.contours[0] = reversed.contours[0].reversed();
+        let unclosed = CurveShape::from_path(CurvePath::new(
+            IntPoint::new(0, 0),
+            vec![CurveSegment::Line {
+                to: IntPoint::new(10, 0),
+            }],
+        ));
+
+        let result = [reversed, clip.clone(), unclosed];
+        let verification = verify(&subject, &clip, &result, OverlayRule::Union);
+        let issues = verification.issues();
+
+        assert!(issues.contains(&CurveVerificationIssue::WrongOrientation { shape: 0, contour: 0 }));
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            CurveVerificationIssue::InvalidShape {
+                shape: 2,
+                error: CurveInputError::UnclosedContour { contour: 0 }
+            }
+        )));
+        let crossings: Vec<_> = issues
+            .iter()
+            .filter_map(|issue| match issue {
+                CurveVerificationIssue::SelfIntersection { point, segments } => Some((*point, *segments)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(crossings.len(), 2);
+        assert!(crossings.iter().all(|(point, segments)| {
+            [IntPoint::new(100, 50), IntPoint::new(50, 100)].contains(point)
+                && segments[0].shape != segments[1].shape
+        }));
+    }
+
+    #[test]
+    fn grid_thin_spikes_are_not_reported() {
+        // Both sides of the spike snap onto the same grid cells, so they
+        // cross and share a run between its base and its tip.
+        let spike = CurveShape::from_path(CurvePath::new(
+            IntPoint::new(122, 9),
+            vec![
+                CurveSegment::Cubic {
+                    ctrl0: IntPoint::new(116, 9),
+                    ctrl1: IntPoint::new(74, 15),
+                    to: IntPoint::new(39, 19),
+                },
+                CurveSegment::Cubic {
+                    ctrl0: IntPoint::new(59, 17),
+                    ctrl1: IntPoint::new(86, 13),
+                    to: IntPoint::new(121, 8),
+                },
+                CurveSegment::Quad {
+                    ctrl: IntPoint::new(121, 8),
+                    to: IntPoint::new(122, 9),
+                },
+            ],
+        ));
+        let mut reversed = spike.clone();
+        reversed.contours[0] = reversed.contours[0].reversed();
+
+        for result in [spike.clone(), reversed] {
+            let verification = verify(&spike, &spike, &[result], OverlayRule::Union);
+            assert!(verification.is_valid(), "{:?}", verification.issues());
+        }
+    }
+
+    #[test]
+    fn shared_boundaries_are_warnings() {
+        let bulge = CurveSegment::Quad {
+            ctrl: IntPoint::new(150, 50),
+            to: IntPoint::new(100, 100),
+        };
+        let subject = CurveShape::from_path(CurvePath::new(
+            IntPoint::new(0, 0),
+            vec![
+                CurveSegment::Line {
+                    to: IntPoint::new(100, 0),
+                },
+                bulge,
+                CurveSegment::Line {
+                    to: IntPoint::new(0, 100),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(0, 0),
+                },
+            ],
+        ));
+        let clip = CurveShape::from_path(CurvePath::new(
+            IntPoint::new(100, 100),
+            vec![
+                CurveSegment::Quad {
+                    ctrl: IntPoint::new(150, 50),
+                    to: IntPoint::new(100, 0),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(200, 0),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(200, 100),
+                },
+                CurveSegment::Line {
+                    to: IntPoint::new(100, 100),
+                },
+            ],
+        ));
+
+        // The union merges both shapes; keeping them apart leaves their
+        // common curve as a shared boundary.
+        let result = [subject.clone(), clip.clone()];
+        let verification = verify(&subject, &clip, &result, OverlayRule::Union);
+
+        assert!(verification.is_valid(), "{:?}", verification.issues());
+        assert!(!verification.issues().is_empty());
+        assert!(verification.issues().iter().all(|issue| matches!(
+            issue,
+            CurveVerificationIssue::SharedBoundary { segments, .. } if segments[0].shape != segments[1].shape
+        )));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::int::CurveInt;
+use crate::int::bool::approximate::CurveApproximator;
+use crate::int::bool::bounds::CurveBoundsBuffer;
+use crate::int::bool::data::CurveEdgeDataStore;
+use crate::int::bool::edge::CurveEdge;
+use crate::int::bool::passthrough::CurvePassThrough;
+use crate::int::bool::planarize::CurvePlanarizer;
+use crate::int::bool::recompose::CurveRecomposer;
+use crate::int::bool::refine::CurveContainmentRefiner;
+use crate::int::bool::source::{CurveContourSource, CurveSource};
+use crate::kernel::int::curve::segment::Segment;
+use crate::kernel::int::normalization::canonical::ParametricSegment;
+use alloc::vec::Vec;
+use i_overlay::core::extract::BooleanExtractionBuffer;
+use i_overlay::core::relate::PredicateOverlay;
+
+/// Reusable internal buffers of the curve Boolean pipeline.
+///
+/// Every overlay converts its inputs to source curves and edges, approximates
+/// and planarizes them, classifies the edge graph, and recomposes curves or
+/// evaluates a predicate. Each of those stages keeps its allocations inside a
+/// workspace, so moving one workspace through many small operations avoids
+/// reallocating them.
+///
+/// Attach a workspace with [`IntCurveOverlay::with_workspace`] and recover it
+/// with [`IntCurveOverlay::into_workspace`], or keep one overlay alive and
+/// call [`IntCurveOverlay::overlay_and_clear`]. The contents of a workspace
+/// never affect results.
+///
+/// [`IntCurveOverlay::with_workspace`]: crate::int::IntCurveOverlay::with_workspace
+/// [`IntCurveOverlay::into_workspace`]: crate::int::IntCurveOverlay::into_workspace
+/// [`IntCurveOverlay::overlay_and_clear`]: crate::int::IntCurveOverlay::overlay_and_clear
+pub struct CurveOverlayWorkspace<I: CurveInt> {
+    pub(super) curve_sources: Vec<CurveSource<I>>,
+    pub(super) curve_edges: Vec<CurveEdge<I>>,
+    pub(super) contours: Vec<CurveContourSource<I>>,
+    pub(super) simple_curves: Vec<Segment<I>>,
+    pub(super) canonical_curves: Vec<ParametricSegment<I>>,
+    pub(super) pass_through: CurvePassThrough<I>,
+    pub(super) approximator: CurveApproximator<I>,
+    pub(super) bounds: CurveBoundsBuffer<I>,
+    pub(super) planarizer: CurvePlanarizer<I>,
+    pub(super) refiner: CurveContainmentRefiner<I>,
+    pub(super) data_store: CurveEdgeDataStore,
+    pub(super) boolean_buffer: Option<BooleanExtractionBuffer<I>>,
+    pub(super) recomposer: CurveRecomposer<I>,
+    pub(super) predicate_overlay: PredicateOverlay<I>,
+}
+
+impl<I: CurveInt> CurveOverlayWorkspace<I> {
+    /// Creates an empty workspace. Buffers grow on first use.
+    pub fn new() -> Self {
+        Self {
+            curve_sources: Vec::new(),
+            curve_edges: Vec::new(),
+            contours: Vec::new(),
+            simple_curves: Vec::new(),
+            canonical_curves: Vec::new(),
+            pass_through: CurvePassThrough::new(),
+            approximator: CurveApproximator::new(),
+            bounds: CurveBoundsBuffer::new(),
+            planarizer: CurvePlanarizer::new(),
+            refiner: CurveContainmentRefiner::new(),
+            data_store: CurveEdgeDataStore::default(),
+            boolean_buffer: None,
+            recomposer: CurveRecomposer::new(),
+            predicate_overlay: PredicateOverlay::new(0),
+        }
+    }
+}
+
+impl<I: CurveInt> Default for CurveOverlayWorkspace<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -31,4 +31,29 @@ impl<I: CurveInt> CurvePath<I> {
     pub fn is_closed(&self) -> bool {
         self.end_point() == Some(self.start)
     }
+
+    /// Returns the path traversed in the opposite direction from its end point.
+    pub(crate) fn reversed(&self) -> Self {
+        let mut segments = Vec::with_capacity(self.segments.len());
+        for (index, segment) in self.segments.iter().enumerate().rev() {
+            let to = index
+                .checked_sub(1)
+                .map_or(self.start, |previous| self.segments[previous].end_point());
+            segments.push(match *segment {
+                CurveSegment::Line { .. } => CurveSegment::Line { to },
+                CurveSegment::Quad { ctrl, .. } => CurveSegment::Quad { ctrl, to },
+                CurveSegment::Cubic { ctrl0, ctrl1, .. } => CurveSegment::Cubic {
+                    ctrl0: ctrl1,
+                    ctrl1: ctrl0,
+                    to,
+                },
+                CurveSegment::Arc { arc } => CurveSegment::Arc { arc: arc.reversed() },
+            });
+        }
+
+        Self {
+            start: self.end_point().unwrap_or(self.start),
+            segments,
+        }
+    }
 }
@@ -25,6 +25,25 @@ impl<I: CurveInt> CurveShape<I> {
         }
     }
 
+    /// Returns the first contour of the shape, or `None` for a shape without
+    /// contours.
+    ///
+    /// Shapes returned by Boolean operations store their outer boundary
+    /// first and its holes after it. Other shapes follow this convention
+    /// only if their contours were added in that order; neither the order
+    /// nor the orientation of the contours is checked.
+    #[inline]
+    pub fn outer(&self) -> Option<&CurvePath<I>> {
+        self.contours.first()
+    }
+
+    /// Returns the contours after the [`outer`](Self::outer) one, which are
+    /// the holes of a Boolean result.
+    #[inline]
+    pub fn holes(&self) -> &[CurvePath<I>] {
+        self.contours.get(1..).unwrap_or_default()
+    }
+
     /// Returns the total number of curve segments in the shape.
     pub fn segment_count(&self) -> usize {
         self.contours.iter().map(|path| path.segments.len()).sum()
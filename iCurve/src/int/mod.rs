@@ -20,10 +20,27 @@ pub mod arc {
     pub use crate::kernel::int::curve::param::SegmentParam as CurveParameter;
 }
 
+pub use bool::arrangement::{
+    CurveArrangement, CurveArrangementEdge, CurveArrangementFace, CurveArrangementHalfEdge,
+    CurveArrangementSource, IntCurveArrangement,
+};
+pub use bool::diagnostics::{
+    CurveIntersection, CurveIntersectionSide, CurveIntersectionStatus, CurveOverlayDiagnostics,
+};
+pub use bool::hierarchy::{CurveContourNode, CurveContourTree};
+pub use bool::incremental::{CurveContourId, IncrementalCurveOverlay};
+pub use bool::layers::{CurveLayerRegion, CurveLayerSet, IntCurveLayerOverlay};
 pub(crate) use bool::overlay::validate_shape;
 pub use bool::overlay::{
-    CurveInputError, CurveOverlayOptions, CurveOverlayOptionsError, IntCurveOverlay, overlay,
+    CurveInputError, CurveOverlayError, CurveOverlayOptions, CurveOverlayOptionsError, CurveOverlayStage,
+    IntCurveOverlay, overlay,
+};
+pub use bool::prepared::PreparedCurveSubject;
+pub use bool::repro::{CurveOverlayRepro, CurveReproAdapter, CurveReproParseError, CurveReproParseErrorKind};
+pub use bool::verify::{
+    CurveSegmentLocation, CurveVerification, CurveVerificationIssue, CurveVerificationOptions, verify_overlay,
 };
+pub use bool::workspace::CurveOverlayWorkspace;
 pub use curve::path::CurvePath;
 pub use curve::segment::CurveSegment;
 pub use curve::shape::CurveShape;
@@ -0,0 +1,879 @@
+use crate::collections::stack_vec::StackVec;
+use crate::int::CurveInt;
+use crate::kernel::int::cross::float::{Point, cross, sqr_distance, sub, to_point, unit_param};
+use crate::kernel::int::cross::intersector::{ContactPoint, ContactType, SegmentIntersector};
+use crate::kernel::int::curve::arc::{ArcSegment, EllipseFrame};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use i_overlay::i_float::float::number::FloatNumber;
+use i_overlay::i_float::int::number::signed_product::SignedProduct;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Largest coefficient of the normalized quartic, relative to its terms,
+/// that is still treated as a shared supporting ellipse.
+const DEGENERATE_QUARTIC: f64 = 1e-12;
+
+/// Barycentric slack that keeps contacts at an arc end on the arc.
+const BARYCENTRIC_TOLERANCE: f64 = 1e-9;
+
+/// Squared distance within which an extremum of the quartic touches the
+/// other ellipse, so the contact rounds to one grid point on both curves.
+const TANGENT_SQR_DISTANCE: f64 = 0.25;
+
+/// Distance within which a point lies on an arc.
+const COINCIDENT_DISTANCE: f64 = 1.0;
+
+const BISECTION_STEPS: usize = 64;
+
+/// Gauss-Newton steps that project a point onto an arc.
+const PROJECTION_STEPS: usize = 4;
+
+/// Rational arc geometry in `f64` used to locate and invert contacts.
+///
+/// The rational control points and weights stay authoritative: contacts are
+/// evaluated on them, while the ellipse frames only provide implicit
+/// equations and identify shared supporting ellipses.
+struct ArcCurve<I: CurveInt> {
+    ends: [IntPoint<I>; 2],
+    points: [Point; 3],
+    weights: [f64; 3],
+    area: f64,
+}
+
+impl<I: CurveInt> SegmentIntersector<I> {
+    /// Detects arcs of one supporting ellipse that share a run.
+    ///
+    /// The frames compare exactly. The arcs share a run when an end of one
+    /// lies inside the other or the middle of the first lies on the second,
+    /// and then report the ends on the other arc as tangent contacts, like
+    /// overlapping quads and cubics. Returns `false` for arcs that at most
+    /// touch at their ends.
+    pub(super) fn intersect_arc_overlap(
+        &self,
+        arc0: ArcSegment<I>,
+        arc1: ArcSegment<I>,
+        output: &mut Vec<ContactPoint<I>>,
+    ) -> bool {
+        if !same_ellipse(&arc0.ellipse, &arc1.ellipse) {
+            return false;
+        }
+        let (Some(curve0), Some(curve1)) = (ArcCurve::new(&arc0), ArcCurve::new(&arc1)) else {
+            return false;
+        };
+
+        let radius = self.options.cross_radius.to_f64();
+        let is_inside = |curve: &ArcCurve<I>, point: IntPoint<I>| {
+            curve.end_param(point).is_none() && curve.param_of(to_point(point), radius).is_some()
+        };
+        let shares_run = curve0.ends.into_iter().any(|end| is_inside(&curve1, end))
+            || curve1.ends.into_iter().any(|end| is_inside(&curve0, end))
+            || curve1.param_of(curve0.point_at(0.5), radius).is_some();
+        if !shares_run {
+            return false;
+        }
+
+        self.push_overlap_ends(&curve0, &curve1, output);
+        true
+    }
+
+    /// Intersects two arcs through their supporting ellipses.
+    ///
+    /// Arcs of a shared supporting ellipse that reach this point touch at
+    /// most at their ends, which are reported. Circles are intersected
+    /// exactly, and other ellipse pairs solve the quartic of one arc
+    /// against the implicit equation of the other. Returns `false` when the
+    /// algebraic solve is inconclusive and generic subdivision must run
+    /// instead.
+    ///
+    /// The contacts are located in `f64`, which holds `i32` coordinates
+    /// with room to spare. Wider engines keep coordinates up to `2^57`,
+    /// whose rounding in `f64` exceeds one unit, so their arcs on distinct
+    /// ellipses always take generic subdivision.
+    pub(super) fn intersect_arcs(
+        &self,
+        arc0: ArcSegment<I>,
+        arc1: ArcSegment<I>,
+        output: &mut Vec<ContactPoint<I>>,
+    ) -> bool {
+        let (Some(curve0), Some(curve1)) = (ArcCurve::new(&arc0), ArcCurve::new(&arc1)) else {
+            return false;
+        };
+
+        if same_ellipse(&arc0.ellipse, &arc1.ellipse) {
+            self.push_overlap_ends(&curve0, &curve1, output);
+            return true;
+        }
+        if I::BITS > 32 {
+            return false;
+        }
+
+        if let (Some(radius0), Some(radius1)) = (circle_radius(&arc0.ellipse), circle_radius(&arc1.ellipse)) {
+            return self.intersect_circles(
+                [&curve0, &curve1],
+                [arc0.ellipse.center, arc1.ellipse.center],
+                [radius0, radius1],
+                output,
+            );
+        }
+
+        self.intersect_ellipses(&curve0, &curve1, &arc1.ellipse, output)
+    }
+
+    fn push_overlap_ends(
+        &self,
+        curve0: &ArcCurve<I>,
+        curve1: &ArcCurve<I>,
+        output: &mut Vec<ContactPoint<I>>,
+    ) {
+        for (end, t0) in curve0.ends.into_iter().zip([0.0, 1.0]) {
+            self.push_arc_contact(
+                curve0,
+                curve1,
+                to_point(end),
+                Some(t0),
+                ContactType::Tangent,
+                output,
+            );
+        }
+        for end in curve1.ends {
+            self.push_arc_contact(curve0, curve1, to_point(end), None, ContactType::Tangent, output);
+        }
+    }
+
+    /// Intersects two circles from their exact squared radii.
+    ///
+    /// With `d` the squared center distance and `k = d + r0^2 - r1^2`, the
+    /// contacts exist when `4 * d * r0^2 - k^2` is non-negative and touch
+    /// when it is zero. The sign is evaluated in double-width products.
+    fn intersect_circles(
+        &self,
+        curves: [&ArcCurve<I>; 2],
+        centers: [IntPoint<I>; 2],
+        sqr_radii: [I::Wide; 2],
+        output: &mut Vec<ContactPoint<I>>,
+    ) -> bool {
+        let dx = centers[1].x.to_wide() - centers[0].x.to_wide();
+        let dy = centers[1].y.to_wide() - centers[0].y.to_wide();
+        let sqr_distance = dx * dx + dy * dy;
+        if sqr_distance == I::Wide::ZERO {
+            // Distinct concentric circles never meet.
+            return true;
+        }
+
+        let k = sqr_distance + sqr_radii[0] - sqr_radii[1];
+        let Some(discriminant) = SignedProduct::multiply(sqr_distance, sqr_radii[0] << 2)
+            .checked_sub(SignedProduct::multiply(k, k))
+        else {
+            return false;
+        };
+
+        let [curve0, curve1] = curves;
+        let (dx, dy, d) = (dx.to_f64(), dy.to_f64(), sqr_distance.to_f64());
+        let along = k.to_f64() / (2.0 * d);
+        let base = Point {
+            x: centers[0].x.to_f64() + dx * along,
+            y: centers[0].y.to_f64() + dy * along,
+        };
+
+        match discriminant.sign() {
+            Ordering::Less => {}
+            Ordering::Equal => {
+                self.push_arc_contact(curve0, curve1, base, None, ContactType::Tangent, output);
+            }
+            Ordering::Greater => {
+                let disc = 4.0 * d * sqr_radii[0].to_f64() - k.to_f64() * k.to_f64();
+                let across = <f64 as FloatNumber>::sqrt(disc.max(0.0)) / (2.0 * d);
+                for sign in [-1.0, 1.0] {
+                    let point = Point {
+                        x: base.x - sign * dy * across,
+                        y: base.y + sign * dx * across,
+                    };
+                    self.push_arc_contact(curve0, curve1, point, None, ContactType::Cross, output);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Intersects `curve0` with the ellipse of `frame1` through the quartic
+    /// `W(t)^2 * q(A(t))`, where `A(t) = N(t) / W(t)` is the rational arc and
+    /// `q` is the normalized implicit equation of the ellipse.
+    ///
+    /// Extrema that touch the ellipse within half a grid unit are
+    /// tangencies, other sign changes are crossings, and arc ends on the
+    /// ellipse are reported like crossings.
+    fn intersect_ellipses(
+        &self,
+        curve0: &ArcCurve<I>,
+        curve1: &ArcCurve<I>,
+        frame1: &EllipseFrame<I>,
+        output: &mut Vec<ContactPoint<I>>,
+    ) -> bool {
+        let Some(implicit) = Implicit::new(frame1) else {
+            return false;
+        };
+        let Some(quartic) = curve0.quartic(&implicit) else {
+            return false;
+        };
+
+        // Tangencies go first: a double root may split into two sign changes
+        // that round onto the tangent point and are then dropped as duplicates.
+        let derivative = derivative(&quartic);
+        let mut extrema = StackVec::<f64, 4>::new();
+        sign_change_roots(&derivative[..4], &mut extrema);
+        for &t in extrema.as_slice() {
+            let point = curve0.point_at(t);
+            if implicit.sqr_distance(point) <= TANGENT_SQR_DISTANCE {
+                self.push_arc_contact(curve0, curve1, point, Some(t), ContactType::Tangent, output);
+            }
+        }
+
+        let mut roots = StackVec::<f64, 4>::new();
+        sign_change_roots(&quartic, &mut roots);
+        for &t in roots.as_slice() {
+            self.push_arc_contact(
+                curve0,
+                curve1,
+                curve0.point_at(t),
+                Some(t),
+                ContactType::Cross,
+                output,
+            );
+        }
+
+        for (end, t) in curve0.ends.into_iter().zip([0.0, 1.0]) {
+            let point = to_point(end);
+            if implicit.sqr_distance(point) <= TANGENT_SQR_DISTANCE {
+                self.push_arc_contact(curve0, curve1, point, Some(t), ContactType::Cross, output);
+            }
+        }
+
+        true
+    }
+
+    /// Snaps a contact to a nearby arc end, inverts it on both arcs, and
+    /// pushes it when it lies on both of them.
+    fn push_arc_contact(
+        &self,
+        curve0: &ArcCurve<I>,
+        curve1: &ArcCurve<I>,
+        point: Point,
+        t0: Option<f64>,
+        contact_type: ContactType,
+        output: &mut Vec<ContactPoint<I>>,
+    ) {
+        let radius = self.options.cross_radius.to_f64();
+        let snapped = curve0
+            .ends
+            .into_iter()
+            .chain(curve1.ends)
+            .map(|end| (end, sqr_distance(to_point(end), point)))
+            .filter(|&(_, distance)| distance <= radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(end, _)| end);
+
+        let (point, exact) = match snapped {
+            Some(end) => (to_point(end), end),
+            None => (
+                point,
+                IntPoint::new(I::from_rounded_float(point.x), I::from_rounded_float(point.y)),
+            ),
+        };
+
+        let t0 = match curve0.end_param(exact) {
+            Some(t) => t,
+            None => match t0 {
+                Some(t) => t,
+                None => match curve0.param_of(point, radius) {
+                    Some(t) => t,
+                    None => return,
+                },
+            },
+        };
+        let t1 = match curve1.end_param(exact) {
+            Some(t) => t,
+            None => match curve1.param_of(point, radius) {
+                Some(t) => t,
+                None => return,
+            },
+        };
+
+        if output.iter().any(|contact| contact.point == exact) {
+            return;
+        }
+        output.push(ContactPoint {
+            point: exact,
+            t0: unit_param(t0),
+            t1: unit_param(t1),
+            contact_type,
+        });
+    }
+}
+
+impl<I: CurveInt> ArcSegment<I> {
+    /// Returns the parameter at which the arc passes through `point`, up to
+    /// the rounding of split control points.
+    pub(super) fn param_of_point(&self, point: IntPoint<I>) -> Option<f64> {
+        let curve = ArcCurve::new(self)?;
+        if let Some(t) = curve.end_param(point) {
+            return Some(t);
+        }
+        let point = to_point(point);
+        let t = curve.param_of(point, COINCIDENT_DISTANCE)?;
+        (sqr_distance(curve.point_at(t), point) <= COINCIDENT_DISTANCE * COINCIDENT_DISTANCE).then_some(t)
+    }
+}
+
+impl<I: CurveInt> ArcCurve<I> {
+    fn new(arc: &ArcSegment<I>) -> Option<Self> {
+        let points = arc.control_points.map(to_point);
+        let area = cross(sub(points[1], points[0]), sub(points[2], points[0]));
+        if area == 0.0 {
+            return None;
+        }
+
+        Some(Self {
+            ends: [arc.control_points[0], arc.control_points[2]],
+            points,
+            weights: arc.weights.map(|weight| weight.to_f64()),
+            area,
+        })
+    }
+
+    fn point_at(&self, t: f64) -> Point {
+        let s = 1.0 - t;
+        let basis = [s * s, 2.0 * s * t, t * t];
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut weight = 0.0;
+        for ((point, w), b) in self.points.iter().zip(self.weights).zip(basis) {
+            let wb = w * b;
+            x += wb * point.x;
+            y += wb * point.y;
+            weight += wb;
+        }
+        Point {
+            x: x / weight,
+            y: y / weight,
+        }
+    }
+
+    #[inline]
+    fn end_param(&self, point: IntPoint<I>) -> Option<f64> {
+        if point == self.ends[0] {
+            Some(0.0)
+        } else if point == self.ends[1] {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rational parameter of a point on the supporting conic,
+    /// or `None` when the point lies outside the control triangle by more
+    /// than `chord_slack` beyond the chord.
+    ///
+    /// A point of the rational quadratic has barycentric coordinates
+    /// proportional to `w0 * (1 - t)^2`, `2 * w1 * t * (1 - t)`, and
+    /// `w2 * t^2`, so `t / (1 - t) = sqrt((l2 / w2) / (l0 / w0))`. The
+    /// slack admits points of the exact ellipse, which split arcs follow
+    /// only up to the rounding of their control points.
+    fn param_of(&self, point: Point, chord_slack: f64) -> Option<f64> {
+        let [p0, p1, p2] = self.points;
+        let l0 = cross(sub(p1, point), sub(p2, point)) / self.area;
+        let l1 = cross(sub(p2, point), sub(p0, point)) / self.area;
+        let l2 = cross(sub(p0, point), sub(p1, point)) / self.area;
+        if l0 < -BARYCENTRIC_TOLERANCE || l2 < -BARYCENTRIC_TOLERANCE {
+            return None;
+        }
+        let chord_length = <f64 as FloatNumber>::sqrt(sqr_distance(p0, p2));
+        if l1 * self.area.abs() < -chord_slack * chord_length {
+            return None;
+        }
+
+        let head = <f64 as FloatNumber>::sqrt(l0.max(0.0) / self.weights[0]);
+        let tail = <f64 as FloatNumber>::sqrt(l2.max(0.0) / self.weights[2]);
+        let sum = head + tail;
+        if sum <= 0.0 {
+            return None;
+        }
+
+        // The ratio is exact only on the conic. Off it, and most of all
+        // across the chord of a flat arc, project onto the arc instead.
+        let mut t = tail / sum;
+        for _ in 0..PROJECTION_STEPS {
+            let offset = sub(self.point_at(t), point);
+            let tangent = self.derivative_at(t);
+            let sqr_speed = tangent.x * tangent.x + tangent.y * tangent.y;
+            if sqr_speed == 0.0 {
+                break;
+            }
+            t = (t - (offset.x * tangent.x + offset.y * tangent.y) / sqr_speed).clamp(0.0, 1.0);
+        }
+        Some(t)
+    }
+
+    /// Returns the derivative `(N' W - N W') / W^2` of the rational arc.
+    fn derivative_at(&self, t: f64) -> Point {
+        let s = 1.0 - t;
+        let basis = [s * s, 2.0 * s * t, t * t];
+        let slopes = [-2.0 * s, 2.0 * (s - t), 2.0 * t];
+        let (mut x, mut y, mut weight) = (0.0, 0.0, 0.0);
+        let (mut dx, mut dy, mut slope) = (0.0, 0.0, 0.0);
+        for ((point, w), (b, db)) in self
+            .points
+            .iter()
+            .zip(self.weights)
+            .zip(basis.into_iter().zip(slopes))
+        {
+            x += w * b * point.x;
+            y += w * b * point.y;
+            weight += w * b;
+            dx += w * db * point.x;
+            dy += w * db * point.y;
+            slope += w * db;
+        }
+        let sqr_weight = weight * weight;
+        Point {
+            x: (dx * weight - x * slope) / sqr_weight,
+            y: (dy * weight - y * slope) / sqr_weight,
+        }
+    }
+
+    /// Returns the power-basis coefficients of `W(t)^2 * q(A(t))`, or `None`
+    /// when they cancel because both arcs share a supporting ellipse.
+    fn quartic(&self, implicit: &Implicit) -> Option<[f64; 5]> {
+        let scale = self.weights.iter().fold(0.0_f64, |max, &w| max.max(w));
+        let mut cos = [0.0; 3];
+        let mut sin = [0.0; 3];
+        let mut weight = [0.0; 3];
+        for (index, (point, w)) in self.points.iter().zip(self.weights).enumerate() {
+            let w = w / scale;
+            let (c, s) = implicit.phase(*point);
+            cos[index] = w * c;
+            sin[index] = w * s;
+            weight[index] = w;
+        }
+
+        let cos = bernstein_to_power(cos);
+        let sin = bernstein_to_power(sin);
+        let weight = bernstein_to_power(weight);
+        let cos_cos = multiply(cos, cos);
+        let sin_sin = multiply(sin, sin);
+        let weight_weight = multiply(weight, weight);
+
+        let mut quartic = [0.0; 5];
+        let mut magnitude = 0.0_f64;
+        for index in 0..5 {
+            quartic[index] = cos_cos[index] + sin_sin[index] - weight_weight[index];
+            magnitude = magnitude
+                .max(cos_cos[index].abs())
+                .max(sin_sin[index].abs())
+                .max(weight_weight[index].abs());
+        }
+
+        let largest = quartic.iter().fold(0.0_f64, |max, value| max.max(value.abs()));
+        (largest > DEGENERATE_QUARTIC * magnitude).then_some(quartic)
+    }
+}
+
+/// Normalized implicit equation `cos^2 + sin^2 - 1` of an ellipse frame,
+/// where `(cos, sin)` is the phase of a point before normalization.
+struct Implicit {
+    center: Point,
+    axis_x: Point,
+    axis_y: Point,
+    determinant: f64,
+}
+
+impl Implicit {
+    fn new<I: CurveInt>(frame: &EllipseFrame<I>) -> Option<Self> {
+        let axis_x = Point {
+            x: frame.axis_x.x.to_f64(),
+            y: frame.axis_x.y.to_f64(),
+        };
+        let axis_y = Point {
+            x: frame.axis_y.x.to_f64(),
+            y: frame.axis_y.y.to_f64(),
+        };
+        let determinant = cross(axis_x, axis_y);
+        (determinant != 0.0).then(|| Self {
+            center: to_point(frame.center),
+            axis_x,
+            axis_y,
+            determinant,
+        })
+    }
+
+    #[inline]
+    fn phase(&self, point: Point) -> (f64, f64) {
+        let offset = sub(point, self.center);
+        (
+            cross(offset, self.axis_y) / self.determinant,
+            cross(self.axis_x, offset) / self.determinant,
+        )
+    }
+
+    /// Estimates the squared distance from `point` to the ellipse by the
+    /// first-order ratio `q^2 / |grad q|^2`.
+    fn sqr_distance(&self, point: Point) -> f64 {
+        let (cos, sin) = self.phase(point);
+        let value = cos * cos + sin * sin - 1.0;
+        let gx = 2.0 * (cos * self.axis_y.y - sin * self.axis_x.y) / self.determinant;
+        let gy = 2.0 * (sin * self.axis_x.x - cos * self.axis_y.x) / self.determinant;
+        let gradient = gx * gx + gy * gy;
+        if gradient == 0.0 {
+            f64::INFINITY
+        } else {
+            value * value / gradient
+        }
+    }
+}
+
+/// Returns whether two frames describe the same ellipse.
+///
+/// An ellipse is `center + F * (cos, sin)` for the axis matrix `F`, so two
+/// frames agree exactly when their centers and `F * F^T` agree, whatever
+/// their axis order, signs, or rotation.
+fn same_ellipse<I: CurveInt>(a: &EllipseFrame<I>, b: &EllipseFrame<I>) -> bool {
+    a.center == b.center && shape_matrix(a) == shape_matrix(b)
+}
+
+fn shape_matrix<I: CurveInt>(frame: &EllipseFrame<I>) -> [I::Wide; 3] {
+    let (ux, uy) = (frame.axis_x.x.to_wide(), frame.axis_x.y.to_wide());
+    let (vx, vy) = (frame.axis_y.x.to_wide(), frame.axis_y.y.to_wide());
+    [ux * ux + vx * vx, ux * uy + vx * vy, uy * uy + vy * vy]
+}
+
+/// Returns the exact squared radius of a circular frame.
+fn circle_radius<I: CurveInt>(frame: &EllipseFrame<I>) -> Option<I::Wide> {
+    let [xx, xy, yy] = shape_matrix(frame);
+    let (ux, uy) = (frame.axis_x.x.to_wide(), frame.axis_x.y.to_wide());
+    let (vx, vy) = (frame.axis_y.x.to_wide(), frame.axis_y.y.to_wide());
+    let sqr_radius = ux * ux + uy * uy;
+    (xy == I::Wide::ZERO && xx == yy && sqr_radius == vx * vx + vy * vy).then_some(sqr_radius)
+}
+
+/// Collects the parameters in `[0, 1]` where `poly` changes sign.
+///
+/// The sign-changing roots of the derivative split the interval into
+/// monotone pieces, so each piece holds at most one root, found by bisection.
+fn sign_change_roots(poly: &[f64], roots: &mut StackVec<f64, 4>) {
+    roots.clear();
+    let mut breaks = StackVec::<f64, 5>::new();
+    breaks.push(0.0);
+    if poly.len() > 2 {
+        let derivative = derivative(poly);
+        let mut extrema = StackVec::<f64, 4>::new();
+        sign_change_roots(&derivative[..poly.len() - 1], &mut extrema);
+        breaks.extend_from_slice(extrema.as_slice());
+    }
+    breaks.push(1.0);
+
+    for (index, range) in breaks.as_slice().windows(2).enumerate() {
+        let (lo, hi) = (range[0], range[1]);
+        let lo_value = evaluate(poly, lo);
+        let hi_value = evaluate(poly, hi);
+        if index == 0 && lo_value == 0.0 {
+            roots.push(lo);
+        }
+        if hi_value == 0.0 {
+            roots.push(hi);
+        } else if lo_value != 0.0 && (lo_value < 0.0) != (hi_value < 0.0) {
+            roots.push(bisect(poly, lo, hi, lo_value < 0.0));
+        }
+    }
+}
+
+fn bisect(poly: &[f64], mut lo: f64, mut hi: f64, lo_is_negative: bool) -> f64 {
+    for _ in 0..BISECTION_STEPS {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        let value = evaluate(poly, mid);
+        if value == 0.0 {
+            return mid;
+        }
+        if (value < 0.0) == lo_is_negative {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+#[inline]
+fn evaluate(poly: &[f64], t: f64) -> f64 {
+    poly.iter()
+        .rev()
+        .fold(0.0, |value, &coefficient| value * t + coefficient)
+}
+
+#[inline]
+fn derivative(poly: &[f64]) -> [f64; 4] {
+    let mut result = [0.0; 4];
+    for (index, &coefficient) in poly.iter().enumerate().skip(1) {
+        result[index - 1] = index as f64 * coefficient;
+    }
+    result
+}
+
+#[inline]
+fn bernstein_to_power([c0, c1, c2]: [f64; 3]) -> [f64; 3] {
+    [c0, 2.0 * (c1 - c0), c0 - 2.0 * c1 + c2]
+}
+
+#[inline]
+fn multiply(a: [f64; 3], b: [f64; 3]) -> [f64; 5] {
+    let mut result = [0.0; 5];
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            result[i + j] += a * b;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::int::cross::intersector::{
+        ContactType, SegmentIntersectionBuffer, SegmentIntersector, SplitOptions,
+    };
+    use crate::kernel::int::curve::arc::{ArcDirection, ArcPhase, ArcSegment, ArcVector, EllipseFrame};
+    use crate::kernel::int::curve::param::SegmentParam;
+    use crate::kernel::int::curve::segment::Segment;
+    use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+    use i_overlay::i_shape::int::IntPoint;
+
+    const ONE: i32 = FixedScale::<i32>::DENOMINATOR as i32;
+    const DIAGONAL: i32 = 759_250_125;
+
+    /// Quarter of the ellipse `center + axis_x * cos + axis_y * sin` that
+    /// starts at phase `(cos, sin)` and turns counterclockwise.
+    fn quarter(center: [i32; 2], rx: i32, ry: i32, cos: i32, sin: i32) -> ArcSegment<i32> {
+        let point = |cos: i32, sin: i32| IntPoint::new(center[0] + rx * cos, center[1] + ry * sin);
+        ArcSegment {
+            ellipse: EllipseFrame {
+                center: IntPoint::new(center[0], center[1]),
+                axis_x: ArcVector { x: rx, y: 0 },
+                axis_y: ArcVector { x: 0, y: ry },
+            },
+            control_points: [point(cos, sin), point(cos - sin, sin + cos), point(-sin, cos)],
+            weights: [ONE, DIAGONAL, ONE],
+            start_phase: ArcPhase {
+                cos: cos * ONE,
+                sin: sin * ONE,
+            },
+            end_phase: ArcPhase {
+                cos: -sin * ONE,
+                sin: cos * ONE,
+            },
+            direction: ArcDirection::CounterClockwise,
+        }
+    }
+
+    /// The arc of [`quarter`] on the `i64` engine, scaled by `2^20`.
+    fn wide_quarter(center: [i32; 2], rx: i32, ry: i32, cos: i32, sin: i32) -> ArcSegment<i64> {
+        let arc = quarter(center, rx, ry, cos, sin);
+        let point = |point: IntPoint<i32>| IntPoint::new(i64::from(point.x) << 20, i64::from(point.y) << 20);
+        let vector = |vector: ArcVector<i32>| ArcVector {
+            x: i64::from(vector.x) << 20,
+            y: i64::from(vector.y) << 20,
+        };
+        let phase = |phase: ArcPhase<i32>| ArcPhase {
+            cos: i64::from(phase.cos) << 32,
+            sin: i64::from(phase.sin) << 32,
+        };
+        ArcSegment {
+            ellipse: EllipseFrame {
+                center: point(arc.ellipse.center),
+                axis_x: vector(arc.ellipse.axis_x),
+                axis_y: vector(arc.ellipse.axis_y),
+            },
+            control_points: arc.control_points.map(point),
+            weights: arc.weights.map(|weight| i64::from(weight) << 32),
+            start_phase: phase(arc.start_phase),
+            end_phase: phase(arc.end_phase),
+            direction: arc.direction,
+        }
+    }
+
+    fn is_overlap(a: ArcSegment<i32>, b: ArcSegment<i32>) -> bool {
+        let mut buffer = SegmentIntersectionBuffer::default();
+        SegmentIntersector::new(Segment::Arc(a), Segment::Arc(b), SplitOptions::default())
+            .intersect_with_buffer(&mut buffer);
+        buffer.is_overlap()
+    }
+
+    fn contacts(a: ArcSegment<i32>, b: ArcSegment<i32>) -> alloc::vec::Vec<super::ContactPoint<i32>> {
+        SegmentIntersector::new(Segment::Arc(a), Segment::Arc(b), SplitOptions::default()).intersect()
+    }
+
+    fn assert_on_arc(arc: ArcSegment<i32>, t: SegmentParam<i32>, point: IntPoint<i32>) {
+        let on_arc = arc.point_at(t);
+        assert!(on_arc.sqr_distance(point) <= 2, "{on_arc:?} != {point:?}");
+    }
+
+    #[test]
+    fn crossing_circles() {
+        let a = quarter([0, 0], 1_000, 1_000, 1, 0);
+        let b = quarter([1_000, 0], 1_000, 1_000, 0, 1);
+
+        let result = contacts(a, b);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Cross);
+        assert_eq!(result[0].point, IntPoint::new(500, 866));
+        assert_on_arc(a, result[0].t0, result[0].point);
+        assert_on_arc(b, result[0].t1, result[0].point);
+    }
+
+    #[test]
+    fn split_circle_pieces_keep_crossing_off_rounded_chords() {
+        // Pieces of two radius-100 circles after subdivision. Their rounded
+        // ends lie outside the circles, so the exact crossing at (50, 86.6)
+        // falls just beyond both chords.
+        let piece = |center: [i32; 2], points: [[i32; 2]; 3], weights: [i32; 3]| {
+            let mut arc = quarter(center, 100, 100, 1, 0);
+            arc.control_points = points.map(Into::into);
+            arc.weights = weights;
+            arc
+        };
+        let a = piece(
+            [0, 0],
+            [[55, 85], [46, 90], [37, 93]],
+            [926_323_842, 936_151_707, 955_807_438],
+        );
+        let b = piece(
+            [100, 0],
+            [[64, 93], [55, 90], [47, 85]],
+            [955_807_438, 936_151_707, 926_323_842],
+        );
+
+        let result = contacts(a, b);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(50, 87));
+        assert_on_arc(a, result[0].t0, result[0].point);
+        assert_on_arc(b, result[0].t1, result[0].point);
+    }
+
+    #[test]
+    fn tangent_circles_touch_once() {
+        let a = quarter([0, 0], 1_000, 1_000, 1, 0);
+        let b = quarter([1_200, 1_600], 1_000, 1_000, -1, 0);
+
+        let result = contacts(a, b);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Tangent);
+        assert_eq!(result[0].point, IntPoint::new(600, 800));
+        assert_on_arc(a, result[0].t0, result[0].point);
+        assert_on_arc(b, result[0].t1, result[0].point);
+    }
+
+    #[test]
+    fn separated_and_concentric_circles_do_not_meet() {
+        let a = quarter([0, 0], 1_000, 1_000, 1, 0);
+
+        assert!(contacts(a, quarter([1_200, 1_601], 1_000, 1_000, -1, 0)).is_empty());
+        assert!(contacts(a, quarter([0, 0], 900, 900, 1, 0)).is_empty());
+    }
+
+    #[test]
+    fn shared_ellipse_reports_overlap_ends() {
+        let arc = quarter([0, 0], 2_000, 1_000, 1, 0);
+        let head = arc.rational_split(SegmentParam::new(ONE / 4 * 3))[0];
+        let tail = arc.rational_split(SegmentParam::new(ONE / 4))[1];
+
+        let result = contacts(head, tail);
+
+        assert_eq!(result.len(), 2);
+        assert!(
+            result
+                .iter()
+                .all(|contact| contact.contact_type == ContactType::Tangent)
+        );
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == head.control_points[2])
+        );
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == tail.control_points[0])
+        );
+        for contact in result {
+            assert_on_arc(head, contact.t0, contact.point);
+            assert_on_arc(tail, contact.t1, contact.point);
+        }
+
+        assert!(is_overlap(head, tail));
+        assert!(is_overlap(arc, arc));
+        let start = tail.control_points[0];
+        assert_on_arc(head, Segment::Arc(head).param_of_point(start).unwrap(), start);
+
+        let next = quarter([0, 0], 2_000, 1_000, 0, 1);
+        let adjacent = contacts(arc, next);
+        assert!(
+            adjacent
+                .iter()
+                .all(|contact| contact.point == arc.control_points[2])
+        );
+        assert!(!is_overlap(arc, next));
+    }
+
+    #[test]
+    fn wide_engine_arcs_take_subdivision() {
+        let a = wide_quarter([0, 0], 1_000, 1_000, 1, 0);
+        let b = wide_quarter([1_000, 0], 1_000, 1_000, 0, 1);
+        let intersector = SegmentIntersector::new(Segment::Arc(a), Segment::Arc(b), SplitOptions::default());
+
+        let mut output = alloc::vec::Vec::new();
+        assert!(!intersector.intersect_arcs(a, b, &mut output));
+
+        let result = intersector.intersect();
+        assert_eq!(result.len(), 1);
+        let expected = IntPoint::new(500_i64 << 20, 866_025 * (1 << 20) / 1_000);
+        assert!(
+            result[0].point.sqr_distance(expected) <= 1 << 20,
+            "{:?}",
+            result[0].point
+        );
+    }
+
+    #[test]
+    fn crossing_ellipses() {
+        let a = quarter([0, 0], 2_000, 1_000, 1, 0);
+        let b = quarter([0, 0], 1_000, 2_000, 1, 0);
+
+        let result = contacts(a, b);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Cross);
+        assert_eq!(result[0].point, IntPoint::new(894, 894));
+        assert_on_arc(a, result[0].t0, result[0].point);
+        assert_on_arc(b, result[0].t1, result[0].point);
+    }
+
+    #[test]
+    fn tangent_ellipses_touch_once() {
+        // The second ellipse mirrors the first through (1600, 600), where
+        // both have the normal (2, 3).
+        let a = quarter([0, 0], 2_000, 1_000, 1, 0);
+        let b = quarter([3_200, 1_200], 2_000, 1_000, -1, 0);
+
+        let result = contacts(a, b);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Tangent);
+        assert_eq!(result[0].point, IntPoint::new(1_600, 600));
+        assert_on_arc(a, result[0].t0, result[0].point);
+        assert_on_arc(b, result[0].t1, result[0].point);
+    }
+}
@@ -0,0 +1,335 @@
+use crate::int::CurveInt;
+use crate::kernel::int::curve::segment::Segment;
+use crate::kernel::int::math::interval::Interval;
+use i_overlay::i_float::float::number::FloatNumber;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Newton steps that refine a contact before the enclosure test.
+const NEWTON_STEPS: usize = 8;
+
+/// Enclosure radii tried, as right shifts of the grid tolerance applied in
+/// steps of [`RADIUS_SHIFT_STEP`]. Small boxes keep the parameter intervals
+/// tight, larger ones absorb the rounding of large coordinates.
+const RADIUS_ATTEMPTS: u32 = 8;
+
+const RADIUS_SHIFT_STEP: u32 = 5;
+
+/// Largest parameter radius of an enclosure box.
+const MAX_PARAM_RADIUS: f64 = 0.25;
+
+type IntervalPoint = [Interval; 2];
+
+impl<I: CurveInt> Segment<I> {
+    /// Proves that this curve and `other` cross exactly once near the
+    /// parameters `t0` and `t1`, and that the crossing lies within
+    /// `tolerance` of `point` on both axes.
+    ///
+    /// Uses the Krawczyk operator of `F(t0, t1) = self(t0) - other(t1)` in
+    /// interval arithmetic on the exact control points. When it maps a box
+    /// into its own interior, the box holds a unique root, so the result
+    /// encloses the parameters of the crossing on both curves. The
+    /// enclosures may extend past the ends of a curve. Tangencies,
+    /// overlaps, and clustered crossings have a singular Jacobian and
+    /// return `None`.
+    pub(crate) fn certify_contact(
+        &self,
+        other: &Self,
+        t0: f64,
+        t1: f64,
+        point: IntPoint<I>,
+        tolerance: f64,
+    ) -> Option<[Interval; 2]> {
+        let curve0 = IntervalCurve::new(self);
+        let curve1 = IntervalCurve::new(other);
+        let [mut s, mut t] = [t0, t1];
+
+        for _ in 0..NEWTON_STEPS {
+            let [fx, fy] = residual(&curve0, &curve1, s, t)?;
+            let [[a, b], [c, d]] = jacobian(&curve0, &curve1, s, t)?;
+            let det = a * d - b * c;
+            if det == 0.0 || !det.is_finite() {
+                return None;
+            }
+            s -= (d * fx - b * fy) / det;
+            t -= (a * fy - c * fx) / det;
+            if !s.is_finite() || !t.is_finite() {
+                return None;
+            }
+        }
+
+        // Any matrix close to the inverse Jacobian at the midpoint works
+        // as the Krawczyk preconditioner.
+        let [[a, b], [c, d]] = jacobian(&curve0, &curve1, s, t)?;
+        let det = a * d - b * c;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let inverse = [[d / det, -b / det], [-c / det, a / det]];
+        let speeds = [a * a + c * c, b * b + d * d].map(<f64 as FloatNumber>::sqrt);
+        if speeds[0] == 0.0 || speeds[1] == 0.0 {
+            return None;
+        }
+
+        let target = [point.x.to_f64(), point.y.to_f64()];
+        for attempt in (0..RADIUS_ATTEMPTS).rev() {
+            let delta = tolerance / (1u64 << (attempt * RADIUS_SHIFT_STEP)) as f64;
+            let radii = speeds.map(|speed| (delta / speed).min(MAX_PARAM_RADIUS));
+            let boxes = [
+                Interval::new(s - radii[0], s + radii[0]),
+                Interval::new(t - radii[1], t + radii[1]),
+            ];
+            let Some(enclosure) = krawczyk(&curve0, &curve1, [s, t], boxes, inverse) else {
+                continue;
+            };
+            if !(boxes[0].contains_strictly(&enclosure[0]) && boxes[1].contains_strictly(&enclosure[1])) {
+                continue;
+            }
+
+            let [x, y] = curve0.point(enclosure[0])?;
+            let is_near = x.is_within(target[0] - tolerance, target[0] + tolerance)
+                && y.is_within(target[1] - tolerance, target[1] + tolerance);
+            return is_near.then_some(enclosure);
+        }
+
+        None
+    }
+}
+
+/// Curve with interval control points. A rational arc keeps its weighted
+/// points `w * p` and its weights separately.
+struct IntervalCurve {
+    degree: usize,
+    xs: [Interval; 4],
+    ys: [Interval; 4],
+    weights: Option<[Interval; 3]>,
+}
+
+impl IntervalCurve {
+    fn new<I: CurveInt>(segment: &Segment<I>) -> Self {
+        let mut xs = [Interval::point(0.0); 4];
+        let mut ys = [Interval::point(0.0); 4];
+        let mut copy = |points: &[IntPoint<I>]| {
+            for (index, point) in points.iter().enumerate() {
+                xs[index] = Interval::from_int(point.x);
+                ys[index] = Interval::from_int(point.y);
+            }
+            points.len() - 1
+        };
+
+        match segment {
+            Segment::Line(line) => {
+                let degree = copy(&line.control_points);
+                Self {
+                    degree,
+                    xs,
+                    ys,
+                    weights: None,
+                }
+            }
+            Segment::Quad(quad) => {
+                let degree = copy(&quad.control_points);
+                Self {
+                    degree,
+                    xs,
+                    ys,
+                    weights: None,
+                }
+            }
+            Segment::Cubic(cubic) => {
+                let degree = copy(&cubic.control_points);
+                Self {
+                    degree,
+                    xs,
+                    ys,
+                    weights: None,
+                }
+            }
+            Segment::Arc(arc) => {
+                let degree = copy(&arc.control_points);
+                let weights = arc.weights.map(Interval::from_int);
+                for (index, &weight) in weights.iter().enumerate() {
+                    xs[index] = xs[index] * weight;
+                    ys[index] = ys[index] * weight;
+                }
+                Self {
+                    degree,
+                    xs,
+                    ys,
+                    weights: Some(weights),
+                }
+            }
+        }
+    }
+
+    fn point(&self, t: Interval) -> Option<IntervalPoint> {
+        let n = self.degree + 1;
+        let x = bernstein(&self.xs[..n], t);
+        let y = bernstein(&self.ys[..n], t);
+        match self.weights {
+            None => Some([x, y]),
+            Some(weights) => {
+                let w = bernstein(&weights, t);
+                if w.contains_zero() {
+                    return None;
+                }
+                Some([x / w, y / w])
+            }
+        }
+    }
+
+    fn derivative(&self, t: Interval) -> Option<IntervalPoint> {
+        let n = self.degree + 1;
+        let dx = bernstein_derivative(&self.xs[..n], t);
+        let dy = bernstein_derivative(&self.ys[..n], t);
+        match self.weights {
+            None => Some([dx, dy]),
+            Some(weights) => {
+                // (N / w)' = (N' w - N w') / w^2
+                let w = bernstein(&weights, t);
+                if w.contains_zero() {
+                    return None;
+                }
+                let dw = bernstein_derivative(&weights, t);
+                let x = bernstein(&self.xs[..n], t);
+                let y = bernstein(&self.ys[..n], t);
+                let sqr_w = w * w;
+                Some([(dx * w - x * dw) / sqr_w, (dy * w - y * dw) / sqr_w])
+            }
+        }
+    }
+}
+
+fn residual(curve0: &IntervalCurve, curve1: &IntervalCurve, s: f64, t: f64) -> Option<[f64; 2]> {
+    let [x0, y0] = curve0.point(Interval::point(s))?;
+    let [x1, y1] = curve1.point(Interval::point(t))?;
+    Some([x0.mid() - x1.mid(), y0.mid() - y1.mid()])
+}
+
+fn jacobian(curve0: &IntervalCurve, curve1: &IntervalCurve, s: f64, t: f64) -> Option<[[f64; 2]; 2]> {
+    let [dx0, dy0] = curve0.derivative(Interval::point(s))?;
+    let [dx1, dy1] = curve1.derivative(Interval::point(t))?;
+    Some([[dx0.mid(), -dx1.mid()], [dy0.mid(), -dy1.mid()]])
+}
+
+/// Evaluates `K(X) = m - Y F(m) + (E - Y J(X)) (X - m)`.
+fn krawczyk(
+    curve0: &IntervalCurve,
+    curve1: &IntervalCurve,
+    [s, t]: [f64; 2],
+    boxes: [Interval; 2],
+    inverse: [[f64; 2]; 2],
+) -> Option<[Interval; 2]> {
+    let [x0, y0] = curve0.point(Interval::point(s))?;
+    let [x1, y1] = curve1.point(Interval::point(t))?;
+    let residual = [x0 - x1, y0 - y1];
+
+    let [dx0, dy0] = curve0.derivative(boxes[0])?;
+    let [dx1, dy1] = curve1.derivative(boxes[1])?;
+    let zero = Interval::point(0.0);
+    let jacobian = [[dx0, zero - dx1], [dy0, zero - dy1]];
+
+    let center = [s, t];
+    let offsets = [boxes[0] - Interval::point(s), boxes[1] - Interval::point(t)];
+    let mut result = [zero; 2];
+    for row in 0..2 {
+        let y = inverse[row].map(Interval::point);
+        let mut value = Interval::point(center[row]) - (y[0] * residual[0] + y[1] * residual[1]);
+        for column in 0..2 {
+            let identity = Interval::point(if row == column { 1.0 } else { 0.0 });
+            let product = y[0] * jacobian[0][column] + y[1] * jacobian[1][column];
+            value = value + (identity - product) * offsets[column];
+        }
+        result[row] = value;
+    }
+
+    Some(result)
+}
+
+/// Evaluates a Bernstein polynomial with de Casteljau's algorithm.
+fn bernstein(coefficients: &[Interval], t: Interval) -> Interval {
+    let mut values = [Interval::point(0.0); 4];
+    values[..coefficients.len()].copy_from_slice(coefficients);
+    for level in 1..coefficients.len() {
+        for index in 0..coefficients.len() - level {
+            values[index] = values[index] + (values[index + 1] - values[index]) * t;
+        }
+    }
+    values[0]
+}
+
+fn bernstein_derivative(coefficients: &[Interval], t: Interval) -> Interval {
+    let degree = coefficients.len() - 1;
+    let mut differences = [Interval::point(0.0); 3];
+    for index in 0..degree {
+        differences[index] = coefficients[index + 1] - coefficients[index];
+    }
+    bernstein(&differences[..degree], t) * Interval::point(degree as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::int::curve::cubic::CubicSegment;
+    use crate::kernel::int::curve::line::LineSegment;
+    use crate::kernel::int::curve::segment::Segment;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn line(a: [i32; 2], b: [i32; 2]) -> Segment<i32> {
+        Segment::Line(LineSegment {
+            control_points: [a.into(), b.into()],
+        })
+    }
+
+    fn cubic(points: [[i32; 2]; 4]) -> Segment<i32> {
+        Segment::Cubic(CubicSegment {
+            control_points: points.map(Into::into),
+        })
+    }
+
+    #[test]
+    fn crossing_lines_are_enclosed() {
+        let s0 = line([0, 0], [100, 100]);
+        let s1 = line([0, 100], [100, 0]);
+
+        let [t0, t1] = s0
+            .certify_contact(&s1, 0.49, 0.52, IntPoint::new(50, 50), 2.0)
+            .expect("transversal crossing is certified");
+        assert!(t0.lo <= 0.5 && 0.5 <= t0.hi);
+        assert!(t1.lo <= 0.5 && 0.5 <= t1.hi);
+        assert!(t0.hi - t0.lo < 1e-6);
+    }
+
+    #[test]
+    fn crossing_cubic_and_line_are_enclosed() {
+        let s0 = cubic([[0, 0], [0, 1024], [1024, 1024], [1024, 0]]);
+        let s1 = line([0, 400], [1024, 400]);
+
+        let [t0, t1] = s0
+            .certify_contact(&s1, 0.15, 0.06, IntPoint::new(65, 400), 2.0)
+            .expect("transversal crossing is certified");
+        assert!((t0.mid() - 0.153_89).abs() < 1e-5);
+        assert!((t1.mid() - 0.063_76).abs() < 1e-5);
+        assert!(t0.hi - t0.lo < 1e-3);
+    }
+
+    #[test]
+    fn tangent_contact_is_not_certified() {
+        let s0 = cubic([[0, 0], [0, 1024], [1024, 1024], [1024, 0]]);
+        let s1 = line([0, 768], [1024, 768]);
+
+        assert!(
+            s0.certify_contact(&s1, 0.5, 0.5, IntPoint::new(512, 768), 2.0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn far_snapped_point_is_not_certified() {
+        let s0 = line([0, 0], [100, 100]);
+        let s1 = line([0, 100], [100, 0]);
+
+        assert!(
+            s0.certify_contact(&s1, 0.5, 0.5, IntPoint::new(54, 50), 2.0)
+                .is_none()
+        );
+    }
+}
@@ -71,7 +71,7 @@ impl<I: CurveInt> SegmentChord<I> {
     }
 
     #[inline]
-    fn contains_collinear(&self, point: IntPoint<I>) -> bool {
+    pub(super) fn contains_collinear(&self, point: IntPoint<I>) -> bool {
         self.a.x.min(self.b.x) <= point.x
             && point.x <= self.a.x.max(self.b.x)
             && self.a.y.min(self.b.y) <= point.y
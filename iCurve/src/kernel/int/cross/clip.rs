@@ -0,0 +1,714 @@
+use crate::collections::stack_vec::StackVec;
+use crate::int::CurveInt;
+use crate::kernel::int::cross::float::{Point, cross, sqr_length};
+use crate::kernel::int::cross::intersector::{ContactPoint, ContactType, SegmentIntersector};
+use crate::kernel::int::cross::line::normalize;
+use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::cubic::CubicSegment;
+use crate::kernel::int::curve::param::SegmentParam;
+use crate::kernel::int::curve::segment::Segment;
+use crate::kernel::int::math::angle::ApproximateAngle;
+use alloc::vec::Vec;
+use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+use i_overlay::i_float::int::number::product_uint::UIntProduct;
+use i_overlay::i_float::int::number::uint::UIntNumber;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_float::int::vector::IntVector;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Share of a parameter range, in fifths, that a clipping step may keep;
+/// slower steps split the larger cubic in half instead.
+const MAX_CLIP_KEEP_FIFTHS: u32 = 4;
+
+/// Parameter width, in fixed-point units, below which a range stops
+/// shrinking.
+const MIN_PARAM_WIDTH: u32 = 4;
+
+/// Widening of a clipped range, in fixed-point units, that absorbs the
+/// rounding of its end parameters.
+const PARAM_SLACK: u32 = 2;
+
+/// Drift, in fixed-point coordinate units, of a sub-cubic cut out by two
+/// rounded de Casteljau splits.
+const SUB_CURVE_ERROR: u32 = 3;
+
+/// Leaves closer than `2^-MERGE_GAP_SHIFT` of the parameter range merge
+/// into one contact.
+const MERGE_GAP_SHIFT: u32 = 30;
+
+/// Squared length of a merged contact beyond which the cubics overlap.
+const OVERLAP_SQR_LENGTH: f64 = 2.0;
+
+/// Clipping steps after which generic subdivision takes over.
+const MAX_CLIP_STEPS: usize = 1024;
+
+/// Two cubics that do not overlap meet at most nine times.
+const MAX_CUBIC_CONTACTS: usize = 9;
+
+/// Control points of a cubic in fixed-point coordinates, scaled by
+/// `2^FixedScale::SHIFT` so sub-cubics keep a fraction of a unit.
+type FixedCubic<I> = [IntVector<I>; 4];
+
+/// Parameter ranges of both cubics that may still contain contacts.
+#[derive(Clone, Copy, Default)]
+pub(super) struct ClipPair<I: CurveInt> {
+    t0: ParamRange<I>,
+    t1: ParamRange<I>,
+}
+
+/// Fixed-point parameter range, in units of `SegmentParam::DENOMINATOR`.
+#[derive(Clone, Copy)]
+struct ParamRange<I: CurveInt> {
+    start: I::Wide,
+    end: I::Wide,
+}
+
+impl<I: CurveInt> Default for ParamRange<I> {
+    #[inline]
+    fn default() -> Self {
+        Self::unit()
+    }
+}
+
+impl<I: CurveInt> SegmentIntersector<I> {
+    /// Intersects two cubics by fat-line Bézier clipping (Sederberg–Nishita).
+    ///
+    /// Each step bounds one cubic by the fat line of the other and keeps the
+    /// parameter range where its convex hull stays inside that band. Steps
+    /// that remove too little split the larger cubic, so nearly tangent
+    /// pairs still converge. Returns `false` for nearly collinear or
+    /// overlapping pairs and when the step budget runs out, leaving them
+    /// to generic subdivision.
+    ///
+    /// Parameters and sub-cubics are fixed-point values in `I::Wide`, and
+    /// band distances are wide cross products widened by the drift of the
+    /// rounded splits, so every engine width clips to a fraction of a unit.
+    pub(super) fn intersect_cubics(
+        &self,
+        cubic0: CubicSegment<I>,
+        cubic1: CubicSegment<I>,
+        stack: &mut Vec<ClipPair<I>>,
+        output: &mut Vec<ContactPoint<I>>,
+    ) -> bool {
+        if self.is_nearly_collinear_pair(Segment::Cubic(cubic0), Segment::Cubic(cubic1)) {
+            return false;
+        }
+        let [first, last] = [cubic0.control_points, cubic1.control_points];
+        if first == last || first == [last[3], last[2], last[1], last[0]] {
+            return false;
+        }
+
+        let curves = [first.map(to_fixed), last.map(to_fixed)];
+        let mut leaves = StackVec::<ClipPair<I>, MAX_CUBIC_CONTACTS>::new();
+
+        stack.clear();
+        stack.push(ClipPair::default());
+
+        let mut steps = 0;
+        while let Some(pair) = stack.pop() {
+            steps += 1;
+            if steps > MAX_CLIP_STEPS {
+                return false;
+            }
+
+            let curve0 = pair.t0.sub_curve(&curves[0]);
+            let curve1 = pair.t1.sub_curve(&curves[1]);
+            if !bounds_overlap(&curve0, &curve1) {
+                continue;
+            }
+
+            let Some(t1) = clip(&curve0, &curve1).map(|range| pair.t1.sub_range(range)) else {
+                continue;
+            };
+            let curve1 = t1.sub_curve(&curves[1]);
+            let Some(t0) = clip(&curve1, &curve0).map(|range| pair.t0.sub_range(range)) else {
+                continue;
+            };
+            let curve0 = t0.sub_curve(&curves[0]);
+
+            let clipped = ClipPair { t0, t1 };
+            let extent0 = extent(&curve0);
+            let extent1 = extent(&curve1);
+            let is_point0 = t0.is_point(extent0);
+            let is_point1 = t1.is_point(extent1);
+            if is_point0 && is_point1 {
+                if !merge_leaf(&mut leaves, clipped) {
+                    return false;
+                }
+                continue;
+            }
+
+            if t0.is_slow_step(&pair.t0) && t1.is_slow_step(&pair.t1) {
+                let [a, b] = clipped.split(is_point1 || !is_point0 && extent0 >= extent1);
+                stack.push(b);
+                stack.push(a);
+            } else {
+                stack.push(clipped);
+            }
+        }
+
+        for leaf in leaves.as_slice() {
+            for (curve, range) in curves.iter().zip([leaf.t0, leaf.t1]) {
+                let sub_curve = range.sub_curve(curve);
+                if sqr_units(difference(sub_curve[3], sub_curve[0])) > OVERLAP_SQR_LENGTH {
+                    return false;
+                }
+            }
+        }
+
+        for leaf in leaves.as_slice() {
+            self.push_cubic_contact(&curves, [first, last], leaf.t0.middle(), leaf.t1.middle(), output);
+        }
+
+        true
+    }
+
+    fn is_nearly_collinear_pair(&self, segment0: Segment<I>, segment1: Segment<I>) -> bool {
+        let sin_angle_neg_pow2 = self.options.sin_angle_neg_pow2;
+        segment0.is_nearly_linear(sin_angle_neg_pow2)
+            && segment1.is_nearly_linear(sin_angle_neg_pow2)
+            && segment0
+                .chord()
+                .vector()
+                .is_nearly_collinear_with(segment1.chord().vector(), sin_angle_neg_pow2)
+    }
+
+    /// Rounds a contact between both cubics, snaps it to a nearby end, and
+    /// classifies it by the angle between the cubic tangents.
+    fn push_cubic_contact(
+        &self,
+        curves: &[FixedCubic<I>; 2],
+        control_points: [[IntPoint<I>; 4]; 2],
+        t0: I::Wide,
+        t1: I::Wide,
+        output: &mut Vec<ContactPoint<I>>,
+    ) {
+        let [left0, right0] = split(&curves[0], t0);
+        let [left1, right1] = split(&curves[1], t1);
+        let shift = FixedScale::<I>::SHIFT + 1;
+        let point = IntPoint::new(
+            I::from_wide((left0[3].x + left1[3].x).shr_round(shift)),
+            I::from_wide((left0[3].y + left1[3].y).shr_round(shift)),
+        );
+
+        let ends = control_points.map(|points| [points[0], points[3]]);
+        let exact = ends
+            .into_iter()
+            .flatten()
+            .map(|end| (end, end.sqr_distance(point)))
+            .filter(|&(_, distance)| distance <= self.options.cross_radius)
+            .min_by_key(|&(_, distance)| distance)
+            .map_or(point, |(end, _)| end);
+        if output.iter().any(|contact| contact.point == exact) {
+            return;
+        }
+
+        let end_param = |ends: [IntPoint<I>; 2], t: I::Wide| {
+            let value = if exact == ends[0] {
+                I::Wide::ZERO
+            } else if exact == ends[1] {
+                SegmentParam::<I>::DENOMINATOR
+            } else {
+                t
+            };
+            SegmentParam::new(I::from_wide(value))
+        };
+
+        // A third of each derivative, between the last two de Casteljau points.
+        let d0 = to_float(difference(right0[1], left0[2]));
+        let d1 = to_float(difference(right1[1], left1[2]));
+        let sin_limit = (1u64 << self.options.sin_angle_neg_pow2) as f64;
+        let sqr_cross = cross(d0, d1) * cross(d0, d1) * sin_limit * sin_limit;
+        let contact_type = if sqr_cross <= sqr_length(d0) * sqr_length(d1) {
+            ContactType::Tangent
+        } else {
+            ContactType::Cross
+        };
+
+        output.push(ContactPoint {
+            point: exact,
+            t0: end_param(ends[0], t0),
+            t1: end_param(ends[1], t1),
+            contact_type,
+        });
+    }
+}
+
+impl<I: CurveInt> ClipPair<I> {
+    /// Halves the first range when `first` is set and the second otherwise.
+    fn split(self, first: bool) -> [Self; 2] {
+        if first {
+            let [a, b] = self.t0.halves();
+            [Self { t0: a, ..self }, Self { t0: b, ..self }]
+        } else {
+            let [a, b] = self.t1.halves();
+            [Self { t1: a, ..self }, Self { t1: b, ..self }]
+        }
+    }
+
+    #[inline]
+    fn touches(&self, other: &Self) -> bool {
+        self.t0.touches(&other.t0) && self.t1.touches(&other.t1)
+    }
+}
+
+impl<I: CurveInt> ParamRange<I> {
+    #[inline]
+    fn unit() -> Self {
+        Self {
+            start: I::Wide::ZERO,
+            end: SegmentParam::<I>::DENOMINATOR,
+        }
+    }
+
+    #[inline]
+    fn width(&self) -> I::Wide {
+        self.end - self.start
+    }
+
+    #[inline]
+    fn middle(&self) -> I::Wide {
+        self.start + (self.width() >> 1)
+    }
+
+    /// Returns `true` once the sub-cubic fits in half a unit or the range
+    /// reaches the parameter resolution.
+    #[inline]
+    fn is_point(&self, extent: I::Wide) -> bool {
+        extent <= FixedScale::<I>::DENOMINATOR >> 1 || self.width() <= I::Wide::from_u32(MIN_PARAM_WIDTH)
+    }
+
+    /// Returns `true` when this range, clipped from `parent`, kept more of
+    /// it than a clipping step should.
+    #[inline]
+    fn is_slow_step(&self, parent: &Self) -> bool {
+        self.width() * I::Wide::from_u32(5) > parent.width() * I::Wide::from_u32(MAX_CLIP_KEEP_FIFTHS)
+    }
+
+    /// Maps a range local to `self` back to the parameters of the cubic,
+    /// widened by [`PARAM_SLACK`] and kept inside `self`.
+    fn sub_range(&self, local: Self) -> Self {
+        let slack = I::Wide::from_u32(PARAM_SLACK);
+        let start = self.start + scale::<I>(self.width(), local.start) - slack;
+        let end = self.start + scale::<I>(self.width(), local.end) + slack;
+        Self {
+            start: start.max(self.start),
+            end: end.min(self.end),
+        }
+    }
+
+    #[inline]
+    fn halves(&self) -> [Self; 2] {
+        let middle = self.middle();
+        [
+            Self {
+                start: self.start,
+                end: middle,
+            },
+            Self {
+                start: middle,
+                end: self.end,
+            },
+        ]
+    }
+
+    #[inline]
+    fn touches(&self, other: &Self) -> bool {
+        let gap = SegmentParam::<I>::DENOMINATOR >> MERGE_GAP_SHIFT;
+        self.start <= other.end + gap && other.start <= self.end + gap
+    }
+
+    #[inline]
+    fn union(&self, other: &Self) -> Self {
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Control points of the part of `curve` inside this range.
+    fn sub_curve(&self, curve: &FixedCubic<I>) -> FixedCubic<I> {
+        let denominator = SegmentParam::<I>::DENOMINATOR;
+        let head = if self.end < denominator {
+            split(curve, self.end)[0]
+        } else {
+            *curve
+        };
+        if self.start <= I::Wide::ZERO {
+            return head;
+        }
+        let product = <I::WideUInt as UIntNumber>::Product::multiply(
+            self.start.unsigned_abs(),
+            denominator.unsigned_abs(),
+        );
+        let local = product.divide_with_rounding(self.end.unsigned_abs());
+        split(&head, I::Wide::from_uint(local))[1]
+    }
+}
+
+/// Merges a leaf into the leaf it touches, or records a new one. Returns
+/// `false` when the pair has more leaves than two cubics can meet at.
+fn merge_leaf<I: CurveInt>(
+    leaves: &mut StackVec<ClipPair<I>, MAX_CUBIC_CONTACTS>,
+    leaf: ClipPair<I>,
+) -> bool {
+    if let Some(merged) = leaves.as_mut_slice().iter_mut().find(|item| item.touches(&leaf)) {
+        merged.t0 = merged.t0.union(&leaf.t0);
+        merged.t1 = merged.t1.union(&leaf.t1);
+        return true;
+    }
+    if leaves.is_full() {
+        return false;
+    }
+    leaves.push(leaf);
+    true
+}
+
+/// Returns the local parameter range of `curve` whose convex hull lies in
+/// the fat line of `fat`, or `None` when the hull misses it.
+///
+/// The fat line runs along the chord of `fat` and is narrowed by the
+/// classic `3/4` and `4/9` factors. Offsets from the chord start are
+/// scaled down together until their cross products fit in `I::Wide`, and
+/// the band is widened by the drift of both rounded sub-cubics. A chord
+/// that rounds to zero leaves `curve` unclipped.
+fn clip<I: CurveInt>(fat: &FixedCubic<I>, curve: &FixedCubic<I>) -> Option<ParamRange<I>> {
+    let origin = fat[0];
+    let offsets = [fat[1], fat[2], fat[3], curve[0], curve[1], curve[2], curve[3]]
+        .map(|point| difference(point, origin));
+    let (offsets, shift) = scale_offsets::<I>(offsets);
+    let [f1, f2, direction, c0, c1, c2, c3] = offsets;
+    if direction.x == I::Wide::ZERO && direction.y == I::Wide::ZERO {
+        return Some(ParamRange::unit());
+    }
+
+    // Both cubics drift by `SUB_CURVE_ERROR` and the scaled offsets by half
+    // a unit more, so each distance is off by at most `error` chord spans.
+    let error = I::Wide::from_u32(2 * SUB_CURVE_ERROR).shr_round(shift) + I::Wide::ONE;
+    let span = I::Wide::from_uint(direction.x.unsigned_abs() + direction.y.unsigned_abs());
+    let distance = |offset: IntVector<I>| direction.cross_product(offset);
+    let [d1, d2, slack, e0, e1, e2, e3] = normalize::<I, 7>([
+        distance(f1),
+        distance(f2),
+        span * error * I::Wide::TWO,
+        distance(c0),
+        distance(c1),
+        distance(c2),
+        distance(c3),
+    ]);
+
+    // Scaling by 36 keeps both narrowing factors integral; one more unit of
+    // slack covers the rounding of `normalize`.
+    let zero = I::Wide::ZERO;
+    let is_one_sided = d1 > zero && d2 > zero || d1 < zero && d2 < zero;
+    let factor = I::Wide::from_u32(if is_one_sided { 27 } else { 16 });
+    let thirty_six = I::Wide::from_u32(36);
+    let slack = (slack + I::Wide::ONE) * thirty_six;
+    let min = factor * d1.min(d2).min(zero) - slack;
+    let max = factor * d1.max(d2).max(zero) + slack;
+
+    let denominator = SegmentParam::<I>::DENOMINATOR;
+    let third = |i: u32| denominator * I::Wide::from_u32(i) / I::Wide::from_u32(3);
+    let hull = [
+        (zero, e0 * thirty_six),
+        (third(1), e1 * thirty_six),
+        (third(2), e2 * thirty_six),
+        (denominator, e3 * thirty_six),
+    ];
+
+    let mut start = denominator + I::Wide::ONE;
+    let mut end = -I::Wide::ONE;
+    for (i, &(x0, y0)) in hull.iter().enumerate() {
+        if (min..=max).contains(&y0) {
+            start = start.min(x0);
+            end = end.max(x0);
+        }
+        for &(x1, y1) in &hull[i + 1..] {
+            for level in [min, max] {
+                if y0 < level && level < y1 || y1 < level && level < y0 {
+                    let product = <I::WideUInt as UIntNumber>::Product::multiply(
+                        (x1 - x0).unsigned_abs(),
+                        (level - y0).unsigned_abs(),
+                    );
+                    let x = x0 + I::Wide::from_uint(product.divide_with_rounding((y1 - y0).unsigned_abs()));
+                    start = start.min(x);
+                    end = end.max(x);
+                }
+            }
+        }
+    }
+
+    (start <= end).then_some(ParamRange { start, end })
+}
+
+/// Scales offsets down together until each coordinate fits in half of
+/// `I::Wide`, so cross products of two offsets cannot overflow. Returns
+/// the scaled offsets and the shift.
+fn scale_offsets<I: CurveInt>(offsets: [IntVector<I>; 7]) -> ([IntVector<I>; 7], u32) {
+    let max = offsets
+        .iter()
+        .flat_map(|offset| [offset.x.unsigned_abs(), offset.y.unsigned_abs()])
+        .max()
+        .unwrap_or(I::WideUInt::ZERO);
+    let bits = I::WideUInt::BITS - max.leading_zeros();
+    let limit = I::WideUInt::BITS / 2 - 2;
+    if bits <= limit {
+        return (offsets, 0);
+    }
+    let shift = bits - limit;
+    let scaled = offsets.map(|offset| IntVector {
+        x: offset.x.shr_round(shift),
+        y: offset.y.shr_round(shift),
+    });
+    (scaled, shift)
+}
+
+/// Returns `true` when the control boxes of both curves, widened by the
+/// drift of their splits, overlap.
+fn bounds_overlap<I: CurveInt>(a: &FixedCubic<I>, b: &FixedCubic<I>) -> bool {
+    let [a_min, a_max] = bounds(a);
+    let [b_min, b_max] = bounds(b);
+    let slack = I::Wide::from_u32(2 * SUB_CURVE_ERROR);
+    a_min.x <= b_max.x + slack
+        && b_min.x <= a_max.x + slack
+        && a_min.y <= b_max.y + slack
+        && b_min.y <= a_max.y + slack
+}
+
+fn bounds<I: CurveInt>(curve: &FixedCubic<I>) -> [IntVector<I>; 2] {
+    let mut min = curve[0];
+    let mut max = curve[0];
+    for point in &curve[1..] {
+        min.x = min.x.min(point.x);
+        min.y = min.y.min(point.y);
+        max.x = max.x.max(point.x);
+        max.y = max.y.max(point.y);
+    }
+    [min, max]
+}
+
+/// Larger side of the control box, in fixed-point units.
+#[inline]
+fn extent<I: CurveInt>(curve: &FixedCubic<I>) -> I::Wide {
+    let [min, max] = bounds(curve);
+    (max.x - min.x).max(max.y - min.y)
+}
+
+/// Splits a fixed-point cubic at `t` with rounded de Casteljau steps.
+fn split<I: CurveInt>(curve: &FixedCubic<I>, t: I::Wide) -> [FixedCubic<I>; 2] {
+    let [p0, p1, p2, p3] = *curve;
+    let p01 = lerp(p0, p1, t);
+    let p12 = lerp(p1, p2, t);
+    let p23 = lerp(p2, p3, t);
+    let p012 = lerp(p01, p12, t);
+    let p123 = lerp(p12, p23, t);
+    let middle = lerp(p012, p123, t);
+    [[p0, p01, p012, middle], [middle, p123, p23, p3]]
+}
+
+#[inline]
+fn lerp<I: CurveInt>(a: IntVector<I>, b: IntVector<I>, t: I::Wide) -> IntVector<I> {
+    IntVector {
+        x: a.x + scale::<I>(b.x - a.x, t),
+        y: a.y + scale::<I>(b.y - a.y, t),
+    }
+}
+
+/// Returns `round(value * t / DENOMINATOR)` for a fixed-point parameter.
+#[inline]
+fn scale<I: CurveInt>(value: I::Wide, t: I::Wide) -> I::Wide {
+    let product = <I::WideUInt as UIntNumber>::Product::multiply(value.unsigned_abs(), t.unsigned_abs());
+    let magnitude =
+        I::Wide::from_uint(product.divide_with_rounding(SegmentParam::<I>::DENOMINATOR.unsigned_abs()));
+    if value < I::Wide::ZERO {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+#[inline]
+fn difference<I: CurveInt>(a: IntVector<I>, b: IntVector<I>) -> IntVector<I> {
+    IntVector {
+        x: a.x - b.x,
+        y: a.y - b.y,
+    }
+}
+
+#[inline]
+fn to_fixed<I: CurveInt>(point: IntPoint<I>) -> IntVector<I> {
+    let shift = FixedScale::<I>::SHIFT;
+    IntVector {
+        x: point.x.to_wide() << shift,
+        y: point.y.to_wide() << shift,
+    }
+}
+
+#[inline]
+fn to_float<I: CurveInt>(vector: IntVector<I>) -> Point {
+    Point {
+        x: vector.x.to_f64(),
+        y: vector.y.to_f64(),
+    }
+}
+
+/// Squared length of a fixed-point vector, in squared units.
+#[inline]
+fn sqr_units<I: CurveInt>(vector: IntVector<I>) -> f64 {
+    let unit = FixedScale::<I>::DENOMINATOR.to_f64();
+    sqr_length(to_float(vector)) / (unit * unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::int::cross::intersector::{
+        ContactPoint, ContactType, SegmentIntersector, SplitOptions,
+    };
+    use crate::kernel::int::curve::cubic::CubicSegment;
+    use crate::kernel::int::curve::point_at::PointAt;
+    use crate::kernel::int::curve::segment::Segment;
+    use alloc::vec::Vec;
+    use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn cubic(points: [[i32; 2]; 4]) -> CubicSegment<i32> {
+        CubicSegment {
+            control_points: points.map(IntPoint::from),
+        }
+    }
+
+    fn clip(a: CubicSegment<i32>, b: CubicSegment<i32>) -> Option<Vec<ContactPoint<i32>>> {
+        let intersector =
+            SegmentIntersector::new(Segment::Cubic(a), Segment::Cubic(b), SplitOptions::default());
+        let mut stack = Vec::new();
+        let mut output = Vec::new();
+        if !intersector.intersect_cubics(a, b, &mut stack, &mut output) {
+            return None;
+        }
+        for contact in &output {
+            assert!(Segment::Cubic(a).point_at(contact.t0).sqr_distance(contact.point) <= 2);
+            assert!(Segment::Cubic(b).point_at(contact.t1).sqr_distance(contact.point) <= 2);
+        }
+        Some(output)
+    }
+
+    fn wide_clip(a: CubicSegment<i64>, b: CubicSegment<i64>) -> Option<Vec<ContactPoint<i64>>> {
+        let intersector =
+            SegmentIntersector::new(Segment::Cubic(a), Segment::Cubic(b), SplitOptions::default());
+        let mut output = Vec::new();
+        if !intersector.intersect_cubics(a, b, &mut Vec::new(), &mut output) {
+            return None;
+        }
+        for contact in &output {
+            assert!(Segment::Cubic(a).point_at(contact.t0).sqr_distance(contact.point) <= 2);
+            assert!(Segment::Cubic(b).point_at(contact.t1).sqr_distance(contact.point) <= 2);
+        }
+        Some(output)
+    }
+
+    #[test]
+    fn crossing_cubics() {
+        let a = cubic([[100, 100], [100, 400], [600, 900], [1_000, 900]]);
+        let b = cubic([[100, 900], [100, 500], [600, 0], [1_000, 0]]);
+
+        let result = clip(a, b).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Cross);
+    }
+
+    #[test]
+    fn waves_cross_three_times() {
+        let a = cubic([[0, -1_000], [1_000, 3_000], [2_000, -3_000], [3_000, 1_000]]);
+        let b = cubic([[0, 1_000], [1_000, -3_000], [2_000, 3_000], [3_000, -1_000]]);
+
+        let result = clip(a, b).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == IntPoint::new(1_500, 0))
+        );
+    }
+
+    #[test]
+    fn arches_touch_at_their_apex() {
+        let a = cubic([[0, 0], [0, 400], [1_000, 400], [1_000, 0]]);
+        let b = cubic([[0, 600], [0, 200], [1_000, 200], [1_000, 600]]);
+
+        let result = clip(a, b).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Tangent);
+        assert_eq!(result[0].point, IntPoint::new(500, 300));
+    }
+
+    #[test]
+    fn separated_arches_do_not_meet() {
+        let a = cubic([[0, 0], [0, 400], [1_000, 400], [1_000, 0]]);
+        let b = cubic([[0, 604], [0, 204], [1_000, 204], [1_000, 604]]);
+
+        assert!(clip(a, b).unwrap().is_empty());
+    }
+
+    #[test]
+    fn smooth_join_meets_at_the_shared_end() {
+        let a = cubic([[0, 0], [300, 0], [600, 200], [1_000, 500]]);
+        let b = cubic([[1_000, 500], [1_400, 800], [1_700, 1_000], [2_000, 1_000]]);
+
+        let result = clip(a, b).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(1_000, 500));
+        assert_eq!(result[0].t0.value(), FixedScale::<i32>::DENOMINATOR);
+        assert_eq!(result[0].t1.value(), 0);
+    }
+
+    #[test]
+    fn wide_engines_clip_far_from_the_origin() {
+        let a = [[100, 100], [100, 400], [600, 900], [1_000, 900]];
+        let b = [[100, 900], [100, 500], [600, 0], [1_000, 0]];
+        let expected = clip(cubic(a), cubic(b)).unwrap();
+
+        let offset = 1_i64 << 56;
+        let shift = |points: [[i32; 2]; 4]| CubicSegment {
+            control_points: points.map(|[x, y]| IntPoint::new(offset + x as i64, offset + y as i64)),
+        };
+        let result = wide_clip(shift(a), shift(b)).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Cross);
+        let point = expected[0].point;
+        let expected = IntPoint::new(offset + point.x as i64, offset + point.y as i64);
+        assert!(result[0].point.sqr_distance(expected) <= 2);
+    }
+
+    #[test]
+    fn wide_engines_clip_long_cubics() {
+        let scale = 1_i64 << 40;
+        let wave = |points: [[i64; 2]; 4]| CubicSegment {
+            control_points: points.map(|[x, y]| IntPoint::new(x * scale, y * scale)),
+        };
+        let a = wave([[0, -1_000], [1_000, 3_000], [2_000, -3_000], [3_000, 1_000]]);
+        let b = wave([[0, 1_000], [1_000, -3_000], [2_000, 3_000], [3_000, -1_000]]);
+
+        let result = wide_clip(a, b).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == IntPoint::new(1_500 * scale, 0))
+        );
+    }
+
+    #[test]
+    fn overlapping_cubics_fall_back_to_subdivision() {
+        let a = cubic([[0, 0], [0, 400], [1_000, 400], [1_000, 0]]);
+        let b = cubic([[0, 0], [0, 200], [250, 300], [500, 300]]);
+
+        assert!(clip(a, a).is_none());
+        assert!(clip(a, b).is_none());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::int::CurveInt;
+use crate::kernel::int::curve::param::SegmentParam;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Point in `f64` used by the algebraic intersection paths.
+#[derive(Clone, Copy)]
+pub(super) struct Point {
+    pub(super) x: f64,
+    pub(super) y: f64,
+}
+
+/// Control points of a cubic in `f64`.
+pub(super) type Bezier = [Point; 4];
+
+#[inline]
+pub(super) fn unit_param<I: CurveInt>(t: f64) -> SegmentParam<I> {
+    let scaled = t.clamp(0.0, 1.0) * SegmentParam::<I>::DENOMINATOR.to_f64();
+    SegmentParam::new(I::from_rounded_float(scaled))
+}
+
+#[inline]
+pub(super) fn to_point<I: CurveInt>(point: IntPoint<I>) -> Point {
+    Point {
+        x: point.x.to_f64(),
+        y: point.y.to_f64(),
+    }
+}
+
+#[inline]
+pub(super) fn sub(a: Point, b: Point) -> Point {
+    Point {
+        x: a.x - b.x,
+        y: a.y - b.y,
+    }
+}
+
+#[inline]
+pub(super) fn cross(a: Point, b: Point) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+#[inline]
+pub(super) fn sqr_distance(a: Point, b: Point) -> f64 {
+    let offset = sub(a, b);
+    offset.x * offset.x + offset.y * offset.y
+}
+
+/// Splits a cubic at `t` with de Casteljau's algorithm.
+pub(super) fn split(curve: &Bezier, t: f64) -> [Bezier; 2] {
+    let [p0, p1, p2, p3] = *curve;
+    let p01 = lerp(p0, p1, t);
+    let p12 = lerp(p1, p2, t);
+    let p23 = lerp(p2, p3, t);
+    let p012 = lerp(p01, p12, t);
+    let p123 = lerp(p12, p23, t);
+    let middle = lerp(p012, p123, t);
+    [[p0, p01, p012, middle], [middle, p123, p23, p3]]
+}
+
+#[inline]
+pub(super) fn point_at(curve: &Bezier, t: f64) -> Point {
+    split(curve, t)[0][3]
+}
+
+/// Tangent of a cubic at `t`, a third of its derivative.
+pub(super) fn derivative_at(curve: &Bezier, t: f64) -> Point {
+    let [p0, p1, p2, p3] = *curve;
+    let a = lerp(sub(p1, p0), sub(p2, p1), t);
+    let b = lerp(sub(p2, p1), sub(p3, p2), t);
+    lerp(a, b, t)
+}
+
+#[inline]
+pub(super) fn lerp(a: Point, b: Point, t: f64) -> Point {
+    Point {
+        x: a.x + (b.x - a.x) * t,
+        y: a.y + (b.y - a.y) * t,
+    }
+}
+
+#[inline]
+pub(super) fn sqr_length(vector: Point) -> f64 {
+    vector.x * vector.x + vector.y * vector.y
+}
@@ -1,8 +1,9 @@
 use crate::collections::stack_vec::StackVec;
 use crate::int::CurveInt;
 use crate::kernel::int::cross::chord::ChordCross;
+use crate::kernel::int::cross::clip::ClipPair;
 use crate::kernel::int::cross::parallel::ParallelSegment;
-use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::chord::{Chord, SegmentChord};
 use crate::kernel::int::curve::param::SegmentParam;
 use crate::kernel::int::curve::segment::Segment;
 use crate::kernel::int::math::angle::ApproximateAngle;
@@ -15,14 +16,14 @@ pub(crate) struct SplitOptions<I: CurveInt> {
     pub(super) min_len_pow2: u32,
     pub(super) min_sqr_len_pow2: u32,
     min_separation_log2: u32,
-    sin_angle_neg_pow2: u32,
+    pub(super) sin_angle_neg_pow2: u32,
     pub(super) cross_radius: I::Wide,
 }
 
 pub(crate) struct SegmentIntersector<I: CurveInt> {
     pub(super) options: SplitOptions<I>,
-    original_segment_0: Segment<I>,
-    original_segment_1: Segment<I>,
+    pub(super) original_segment_0: Segment<I>,
+    pub(super) original_segment_1: Segment<I>,
 }
 
 struct Pair<I: CurveInt> {
@@ -40,7 +41,9 @@ struct Pair<I: CurveInt> {
 
 pub(crate) struct SegmentIntersectionBuffer<I: CurveInt> {
     stack: Vec<Pair<I>>,
+    clip_stack: Vec<ClipPair<I>>,
     contacts: Vec<ContactPoint<I>>,
+    is_overlap: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -78,13 +81,41 @@ impl<I: CurveInt> SegmentIntersector<I> {
         buffer: &'a mut SegmentIntersectionBuffer<I>,
     ) -> &'a [ContactPoint<I>] {
         buffer.contacts.clear();
-        self.intersect_into(&mut buffer.stack, &mut buffer.contacts);
+        buffer.is_overlap = self.intersect_overlap(&mut buffer.contacts);
+        if !buffer.is_overlap {
+            self.intersect_into(&mut buffer.stack, &mut buffer.clip_stack, &mut buffer.contacts);
+        }
         &buffer.contacts
     }
 
-    fn intersect_into(&self, stack: &mut Vec<Pair<I>>, output: &mut Vec<ContactPoint<I>>) {
+    fn intersect_into(
+        &self,
+        stack: &mut Vec<Pair<I>>,
+        clip_stack: &mut Vec<ClipPair<I>>,
+        output: &mut Vec<ContactPoint<I>>,
+    ) {
         stack.clear();
 
+        let is_solved = match (self.original_segment_0, self.original_segment_1) {
+            (Segment::Arc(arc0), Segment::Arc(arc1)) => self.intersect_arcs(arc0, arc1, output),
+            (Segment::Cubic(cubic0), Segment::Cubic(cubic1)) => {
+                self.intersect_cubics(cubic0, cubic1, clip_stack, output)
+            }
+            (Segment::Line(line0), Segment::Line(line1))
+                if !line0.chord().is_zero_length() && !line1.chord().is_zero_length() =>
+            {
+                let whole = (SegmentParam::half(), SegmentParam::half());
+                self.intersect_chords([line0.chord(), line1.chord()], [whole, whole], output);
+                true
+            }
+            (Segment::Line(line), curve) => self.intersect_line_curve(line, curve, true, output),
+            (curve, Segment::Line(line)) => self.intersect_line_curve(line, curve, false, output),
+            _ => false,
+        };
+        if is_solved {
+            return;
+        }
+
         let first = Pair {
             s0: self.original_segment_0,
             ch0: self.original_segment_0.convex_hull(),
@@ -121,17 +152,11 @@ impl<I: CurveInt> SegmentIntersector<I> {
             let min_sqr_len_log = sqr_len_0.max(sqr_len_1).ilog2();
 
             if min_sqr_len_log < self.options.min_sqr_len_pow2 {
-                if let Some(ChordCross::Point(point)) = chord0.cross(&chord1, self.options.cross_radius) {
-                    push_unique_contact(
-                        output,
-                        ContactPoint {
-                            point,
-                            t0: global_param(pair.t0, pair.step0, chord0.param_for_point(point)),
-                            t1: global_param(pair.t1, pair.step1, chord1.param_for_point(point)),
-                            contact_type: ContactType::Cross,
-                        },
-                    );
-                }
+                self.intersect_chords(
+                    [chord0, chord1],
+                    [(pair.t0, pair.step0), (pair.t1, pair.step1)],
+                    output,
+                );
                 continue;
             }
 
@@ -228,17 +253,64 @@ impl<I: CurveInt> SegmentIntersector<I> {
             }
         }
     }
+
+    /// Intersects two chords, where each chord spans `center ± step` of
+    /// its segment.
+    fn intersect_chords(
+        &self,
+        chords: [SegmentChord<I>; 2],
+        spans: [(SegmentParam<I>, SegmentParam<I>); 2],
+        output: &mut Vec<ContactPoint<I>>,
+    ) {
+        let [chord0, chord1] = chords;
+        let [(t0, step0), (t1, step1)] = spans;
+        let contact = |point: IntPoint<I>, contact_type: ContactType| ContactPoint {
+            point,
+            t0: global_param(t0, step0, chord0.param_for_point(point)),
+            t1: global_param(t1, step1, chord1.param_for_point(point)),
+            contact_type,
+        };
+        match chord0.cross(&chord1, self.options.cross_radius) {
+            Some(ChordCross::Point(point)) => push_unique_contact(output, contact(point, ContactType::Cross)),
+            // Collinear chords share a run, so each end lying on the other
+            // chord is where the run begins or ends.
+            Some(ChordCross::Overlay) => {
+                for point in [chord0.a, chord0.b, chord1.a, chord1.b] {
+                    if chord0.contains_collinear(point) && chord1.contains_collinear(point) {
+                        push_unique_contact(output, contact(point, ContactType::Tangent));
+                    }
+                }
+            }
+            None => {}
+        }
+    }
 }
 
 impl<I: CurveInt> Default for SegmentIntersectionBuffer<I> {
     fn default() -> Self {
         Self {
             stack: Vec::new(),
+            clip_stack: Vec::new(),
             contacts: Vec::new(),
+            is_overlap: false,
         }
     }
 }
 
+impl<I: CurveInt> SegmentIntersectionBuffer<I> {
+    /// Whether the last intersected pair runs along the same curve.
+    #[inline]
+    pub(crate) fn is_overlap(&self) -> bool {
+        self.is_overlap
+    }
+
+    /// Contacts of the last intersected pair.
+    #[inline]
+    pub(crate) fn contacts(&self) -> &[ContactPoint<I>] {
+        &self.contacts
+    }
+}
+
 fn push_unique_contact<I: CurveInt>(output: &mut Vec<ContactPoint<I>>, contact: ContactPoint<I>) {
     if !output.contains(&contact) {
         output.push(contact);
@@ -286,7 +358,15 @@ impl<I: CurveInt> SplitOptions<I> {
 mod tests {
     use crate::kernel::int::cross::intersector::{SegmentIntersector, SplitOptions};
     use crate::kernel::int::curve::cubic::CubicSegment;
+    use crate::kernel::int::curve::line::LineSegment;
     use crate::kernel::int::curve::segment::Segment;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn line(a: [i32; 2], b: [i32; 2]) -> Segment<i32> {
+        Segment::Line(LineSegment {
+            control_points: [a.into(), b.into()],
+        })
+    }
 
     #[test]
     fn test_0() {
@@ -312,4 +392,41 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].contact_type, super::ContactType::Cross);
     }
+
+    #[test]
+    fn crossing_lines_report_one_cross() {
+        let s0 = line([0, 0], [100, 100]);
+        let s1 = line([0, 100], [100, 0]);
+
+        let result = SegmentIntersector::new(s0, s1, SplitOptions::default()).intersect();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(50, 50));
+        assert_eq!(result[0].contact_type, super::ContactType::Cross);
+    }
+
+    #[test]
+    fn collinear_lines_report_tangent_overlap_ends() {
+        let s0 = line([0, 0], [100, 0]);
+        let s1 = line([40, 0], [160, 0]);
+
+        let mut result = SegmentIntersector::new(s0, s1, SplitOptions::default()).intersect();
+        result.sort_by_key(|contact| contact.point.x);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].point, IntPoint::new(40, 0));
+        assert_eq!(result[1].point, IntPoint::new(100, 0));
+        assert!(
+            result
+                .iter()
+                .all(|contact| contact.contact_type == super::ContactType::Tangent)
+        );
+    }
+
+    #[test]
+    fn disjoint_collinear_lines_report_nothing() {
+        let s0 = line([0, 0], [10, 10]);
+        let s1 = line([20, 20], [30, 30]);
+
+        let result = SegmentIntersector::new(s0, s1, SplitOptions::default()).intersect();
+        assert!(result.is_empty());
+    }
 }
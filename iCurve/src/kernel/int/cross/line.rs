@@ -0,0 +1,635 @@
+use crate::collections::stack_vec::StackVec;
+use crate::int::CurveInt;
+use crate::kernel::int::cross::intersector::{ContactPoint, ContactType, SegmentIntersector};
+use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::line::LineSegment;
+use crate::kernel::int::curve::param::SegmentParam;
+use crate::kernel::int::curve::point_at::PointAt;
+use crate::kernel::int::curve::segment::Segment;
+use crate::kernel::int::math::angle::ApproximateAngle;
+use crate::kernel::int::normalization::unit_quadratic::solve_unit_quadratic;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+use i_overlay::i_float::int::number::product_uint::UIntProduct;
+use i_overlay::i_float::int::number::signed_product::SignedProduct;
+use i_overlay::i_float::int::number::uint::UIntNumber;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_float::int::vector::IntVector;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Implicit equation `cross(b - a, p - a)` of a line segment.
+struct LineEquation<I: CurveInt> {
+    a: IntPoint<I>,
+    b: IntPoint<I>,
+    vector: IntVector<I>,
+    sqr_length: I::Wide,
+}
+
+/// Curve parameter where the curve meets the supporting line.
+#[derive(Clone, Copy, Default)]
+struct Root<I: CurveInt> {
+    t: SegmentParam<I>,
+    is_tangent: bool,
+}
+
+impl<I: CurveInt> SegmentIntersector<I> {
+    /// Intersects a line with a quad, cubic, or arc without subdivision.
+    ///
+    /// Substituting the curve into the implicit line equation gives a
+    /// quadratic for quads and for the numerator of an arc, and a cubic for
+    /// cubics. Quadratic roots come from the fixed-point unit solver and
+    /// their tangency from the exact discriminant sign; cubic roots are
+    /// bracketed between the roots of its derivative.
+    ///
+    /// Returns `false` when the curve is nearly collinear with the line and
+    /// generic subdivision must resolve a possible overlap.
+    pub(super) fn intersect_line_curve(
+        &self,
+        line: LineSegment<I>,
+        curve: Segment<I>,
+        line_is_first: bool,
+        output: &mut Vec<ContactPoint<I>>,
+    ) -> bool {
+        let [a, b] = line.control_points;
+        if a == b || self.is_nearly_collinear_curve(b - a, curve) {
+            return false;
+        }
+        let equation = LineEquation::new(a, b);
+
+        let mut roots = StackVec::<Root<I>, 5>::new();
+        let (start, end) = match curve {
+            Segment::Line(_) => return false,
+            Segment::Quad(quad) => {
+                let [s0, s1, s2] = quad.control_points.map(|point| equation.value(point));
+                if s0 == I::Wide::ZERO && s1 == I::Wide::ZERO && s2 == I::Wide::ZERO {
+                    return false;
+                }
+                quadratic_roots([s0, s1, s2], &mut roots);
+                (s0, s2)
+            }
+            Segment::Arc(arc) => {
+                let values = arc.control_points.map(|point| equation.value(point));
+                if values.iter().all(|&value| value == I::Wide::ZERO) {
+                    return false;
+                }
+                let mut weighted = [I::Wide::ZERO; 3];
+                for ((weighted, value), weight) in weighted.iter_mut().zip(values).zip(arc.weights) {
+                    *weighted = weighted_value::<I>(value, weight.to_wide());
+                }
+                quadratic_roots(weighted, &mut roots);
+                (values[0], values[2])
+            }
+            Segment::Cubic(cubic) => {
+                let values = cubic.control_points.map(|point| equation.value(point));
+                if values.iter().all(|&value| value == I::Wide::ZERO) {
+                    return false;
+                }
+                cubic_roots(values, &mut roots);
+                (values[0], values[3])
+            }
+        };
+
+        let one = SegmentParam::new(I::from_wide(SegmentParam::<I>::DENOMINATOR));
+        for (value, t) in [(start, SegmentParam::new(I::ZERO)), (end, one)] {
+            if value == I::Wide::ZERO {
+                roots.push(Root { t, is_tangent: false });
+            }
+        }
+
+        let ends = [curve.point_at(SegmentParam::new(I::ZERO)), curve.point_at(one)];
+        for root in roots.as_slice() {
+            self.push_line_contact(&equation, curve, ends, *root, line_is_first, output);
+        }
+
+        true
+    }
+
+    /// Nearly straight curves running along the line may overlap it, which
+    /// only the parallel sampling of generic subdivision resolves.
+    fn is_nearly_collinear_curve(&self, line_vector: IntVector<I>, curve: Segment<I>) -> bool {
+        let sin_angle_neg_pow2 = self.options.sin_angle_neg_pow2;
+        curve.is_nearly_linear(sin_angle_neg_pow2)
+            && curve
+                .chord()
+                .vector()
+                .is_nearly_collinear_with(line_vector, sin_angle_neg_pow2)
+    }
+
+    fn push_line_contact(
+        &self,
+        equation: &LineEquation<I>,
+        curve: Segment<I>,
+        curve_ends: [IntPoint<I>; 2],
+        root: Root<I>,
+        line_is_first: bool,
+        output: &mut Vec<ContactPoint<I>>,
+    ) {
+        let point = curve.point_at(root.t);
+        let radius = self.options.cross_radius;
+        let snapped = [equation.a, equation.b, curve_ends[0], curve_ends[1]]
+            .into_iter()
+            .map(|end| (end, end.sqr_distance(point)))
+            .filter(|&(_, distance)| distance <= radius)
+            .min_by_key(|&(_, distance)| distance)
+            .map_or(point, |(end, _)| end);
+
+        let Some(line_t) = equation.param_for_point(snapped) else {
+            return;
+        };
+        let curve_t = if snapped == curve_ends[0] {
+            SegmentParam::new(I::ZERO)
+        } else if snapped == curve_ends[1] {
+            SegmentParam::new(I::from_wide(SegmentParam::<I>::DENOMINATOR))
+        } else {
+            root.t
+        };
+
+        if output.iter().any(|contact| contact.point == snapped) {
+            return;
+        }
+        let (t0, t1) = if line_is_first {
+            (line_t, curve_t)
+        } else {
+            (curve_t, line_t)
+        };
+        output.push(ContactPoint {
+            point: snapped,
+            t0,
+            t1,
+            contact_type: if root.is_tangent {
+                ContactType::Tangent
+            } else {
+                ContactType::Cross
+            },
+        });
+    }
+}
+
+impl<I: CurveInt> LineEquation<I> {
+    #[inline]
+    fn new(a: IntPoint<I>, b: IntPoint<I>) -> Self {
+        let vector = b - a;
+        Self {
+            a,
+            b,
+            vector,
+            sqr_length: vector.sqr_length(),
+        }
+    }
+
+    #[inline]
+    fn value(&self, point: IntPoint<I>) -> I::Wide {
+        self.vector.cross_product(point - self.a)
+    }
+
+    /// Returns the line parameter of a point projecting inside the segment.
+    fn param_for_point(&self, point: IntPoint<I>) -> Option<SegmentParam<I>> {
+        if point == self.a {
+            return Some(SegmentParam::new(I::ZERO));
+        }
+        if point == self.b {
+            return Some(SegmentParam::new(I::from_wide(SegmentParam::<I>::DENOMINATOR)));
+        }
+
+        let projection = (point - self.a).dot_product(self.vector);
+        if projection < I::Wide::ZERO || projection > self.sqr_length {
+            return None;
+        }
+        let product = <I::WideUInt as UIntNumber>::Product::multiply(
+            projection.unsigned_abs(),
+            SegmentParam::<I>::DENOMINATOR.unsigned_abs(),
+        );
+        let value = product.divide_with_rounding(self.sqr_length.unsigned_abs());
+        Some(SegmentParam::new(I::from_wide(I::Wide::from_uint(value))))
+    }
+}
+
+/// Collects the interior roots of the Bernstein quadratic with the given
+/// control values.
+///
+/// The discriminant sign is exact, so a double root is reported once as a
+/// tangency. Root values come from the fixed-point unit solver after the
+/// coefficients are scaled down to a range where its products fit.
+fn quadratic_roots<I: CurveInt>(values: [I::Wide; 3], roots: &mut StackVec<Root<I>, 5>) {
+    let [s0, s1, s2] = values;
+    let a = s0 - (s1 << 1) + s2;
+    let b = (s1 - s0) << 1;
+    let c = s0;
+
+    let discriminant = SignedProduct::multiply(b, b).checked_sub(SignedProduct::multiply(a << 2, c));
+    match discriminant.map(|discriminant| discriminant.sign()) {
+        Some(Ordering::Less) => {}
+        Some(Ordering::Equal) => {
+            if let Some(t) = quadratic_extremum::<I>(a, b) {
+                roots.push(Root { t, is_tangent: true });
+            }
+        }
+        _ => {
+            let [a, b, c] = normalize::<I, 3>([a, b, c]);
+            let found = solve_unit_quadratic::<I>(a, b, c);
+            // Two close roots merged after scaling. The extremum value
+            // `-D / 4a` only changes sign from the start value `c` when `a`
+            // and `c` share a sign; otherwise both roots lie outside the
+            // unit interval.
+            let is_merged = c != I::Wide::ZERO && (a < I::Wide::ZERO) == (c < I::Wide::ZERO);
+            if found.is_empty()
+                && is_merged
+                && let Some(t) = quadratic_extremum::<I>(a, b)
+            {
+                roots.push(Root { t, is_tangent: false });
+            }
+            for t in found {
+                roots.push(Root { t, is_tangent: false });
+            }
+        }
+    }
+}
+
+/// Returns the interior parameter `-b / 2a` of the quadratic's extremum.
+fn quadratic_extremum<I: CurveInt>(a: I::Wide, b: I::Wide) -> Option<SegmentParam<I>> {
+    if a == I::Wide::ZERO {
+        return None;
+    }
+    let [a, b] = normalize::<I, 2>([a, b]);
+    let t = FixedScale::<I>::div_to_scaled_round(-b, a << 1);
+    is_interior::<I>(t).then(|| SegmentParam::new(I::from_wide(t)))
+}
+
+/// Collects the interior roots of the Bernstein cubic with the given
+/// control values.
+///
+/// The roots of the derivative split the unit interval into monotone
+/// pieces. A piece whose ends have opposite signs holds one crossing, found
+/// by fixed-point bisection. A derivative root where the cubic vanishes is a
+/// tangency unless the pieces on both sides have opposite signs, as at a
+/// flat inflection, where the cubic still crosses.
+fn cubic_roots<I: CurveInt>(values: [I::Wide; 4], roots: &mut StackVec<Root<I>, 5>) {
+    let [s0, s1, s2, s3] = values;
+    let three = I::Wide::from_u32(3);
+    let coefficients = normalize::<I, 4>([
+        s3 - s0 + three * (s1 - s2),
+        three * (s0 - (s1 << 1) + s2),
+        three * (s1 - s0),
+        s0,
+    ]);
+    let [a, b, c, _] = coefficients;
+
+    // Parameters of the piece ends, skipping the empty piece a double
+    // derivative root would leave.
+    let mut breaks = [I::Wide::ZERO; 4];
+    let mut len = 1;
+    let mut extrema = solve_unit_quadratic::<I>(three * a, b << 1, c);
+    extrema.as_mut_slice().sort_unstable_by_key(|t| t.value());
+    let ends = extrema.as_slice().iter().map(|t| t.value());
+    for t in ends.chain(core::iter::once(SegmentParam::<I>::DENOMINATOR)) {
+        if t != breaks[len - 1] {
+            breaks[len] = t;
+            len += 1;
+        }
+    }
+    let break_values = breaks.map(|t| evaluate::<I>(coefficients, t));
+
+    for index in 1..len {
+        let (lo, hi) = (breaks[index - 1], breaks[index]);
+        let (lo_value, hi_value) = (break_values[index - 1], break_values[index]);
+        if hi_value == I::Wide::ZERO && is_interior::<I>(hi) {
+            // The last break is the curve end, so an interior one always
+            // has a piece after it.
+            let next_value = break_values[index + 1];
+            let is_crossing = lo_value != I::Wide::ZERO
+                && next_value != I::Wide::ZERO
+                && (lo_value < I::Wide::ZERO) != (next_value < I::Wide::ZERO);
+            roots.push(Root {
+                t: SegmentParam::new(I::from_wide(hi)),
+                is_tangent: !is_crossing,
+            });
+        } else if lo_value != I::Wide::ZERO
+            && hi_value != I::Wide::ZERO
+            && (lo_value < I::Wide::ZERO) != (hi_value < I::Wide::ZERO)
+        {
+            let t = bisect::<I>(coefficients, lo, hi, lo_value, hi_value);
+            if is_interior::<I>(t) {
+                roots.push(Root {
+                    t: SegmentParam::new(I::from_wide(t)),
+                    is_tangent: false,
+                });
+            }
+        }
+    }
+}
+
+/// Finds the fixed-point parameter closest to the single root between two
+/// samples of opposite sign.
+fn bisect<I: CurveInt>(
+    coefficients: [I::Wide; 4],
+    mut lo: I::Wide,
+    mut hi: I::Wide,
+    mut lo_value: I::Wide,
+    mut hi_value: I::Wide,
+) -> I::Wide {
+    while hi - lo > I::Wide::ONE {
+        let mid = (lo + hi) >> 1;
+        let mid_value = evaluate::<I>(coefficients, mid);
+        if mid_value == I::Wide::ZERO {
+            return mid;
+        }
+        if (mid_value < I::Wide::ZERO) == (lo_value < I::Wide::ZERO) {
+            lo = mid;
+            lo_value = mid_value;
+        } else {
+            hi = mid;
+            hi_value = mid_value;
+        }
+    }
+
+    if lo_value.unsigned_abs() <= hi_value.unsigned_abs() {
+        lo
+    } else {
+        hi
+    }
+}
+
+/// Evaluates the power-basis cubic at a fixed-point parameter by Horner's rule.
+#[inline]
+fn evaluate<I: CurveInt>(coefficients: [I::Wide; 4], t: I::Wide) -> I::Wide {
+    let [a, b, c, d] = coefficients;
+    let shift = FixedScale::<I>::SHIFT;
+    let value = (a * t).shr_round(shift) + b;
+    let value = (value * t).shr_round(shift) + c;
+    (value * t).shr_round(shift) + d
+}
+
+/// Scales coefficients down together until each fits in `SHIFT - 2` bits.
+///
+/// A common power-of-two factor keeps every root, and the reduced range
+/// keeps fixed-point products and the unit solver's discriminant inside
+/// `I::Wide`.
+pub(super) fn normalize<I: CurveInt, const N: usize>(coefficients: [I::Wide; N]) -> [I::Wide; N] {
+    let max = coefficients
+        .iter()
+        .map(|coefficient| coefficient.unsigned_abs())
+        .max()
+        .unwrap_or(I::WideUInt::ZERO);
+    let bits = I::WideUInt::BITS - max.leading_zeros();
+    let limit = FixedScale::<I>::SHIFT - 2;
+    if bits <= limit {
+        return coefficients;
+    }
+    let shift = bits - limit;
+    coefficients.map(|coefficient| coefficient.shr_round(shift))
+}
+
+/// Returns `round(value * weight / ONE)` for a fixed-point arc weight.
+#[inline]
+fn weighted_value<I: CurveInt>(value: I::Wide, weight: I::Wide) -> I::Wide {
+    let product = SignedProduct::multiply(value, weight);
+    let magnitude = I::Wide::from_uint(
+        product
+            .magnitude()
+            .divide_with_rounding(FixedScale::<I>::DENOMINATOR.unsigned_abs()),
+    );
+    if product.is_negative() {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+#[inline]
+fn is_interior<I: CurveInt>(t: I::Wide) -> bool {
+    t > I::Wide::ZERO && t < SegmentParam::<I>::DENOMINATOR
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::int::cross::intersector::{
+        ContactPoint, ContactType, SegmentIntersector, SplitOptions,
+    };
+    use crate::kernel::int::curve::arc::{ArcDirection, ArcPhase, ArcSegment, ArcVector, EllipseFrame};
+    use crate::kernel::int::curve::cubic::CubicSegment;
+    use crate::kernel::int::curve::line::LineSegment;
+    use crate::kernel::int::curve::point_at::PointAt;
+    use crate::kernel::int::curve::quad::QuadSegment;
+    use crate::kernel::int::curve::segment::Segment;
+    use alloc::vec::Vec;
+    use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+    use i_overlay::i_shape::int::IntPoint;
+
+    fn line(a: [i32; 2], b: [i32; 2]) -> Segment<i32> {
+        Segment::Line(LineSegment {
+            control_points: [a.into(), b.into()],
+        })
+    }
+
+    fn contacts(s0: Segment<i32>, s1: Segment<i32>) -> Vec<ContactPoint<i32>> {
+        let contacts = SegmentIntersector::new(s0, s1, SplitOptions::default()).intersect();
+        for contact in &contacts {
+            assert!(s0.point_at(contact.t0).sqr_distance(contact.point) <= 2);
+            assert!(s1.point_at(contact.t1).sqr_distance(contact.point) <= 2);
+        }
+        contacts
+    }
+
+    #[test]
+    fn line_misses_quad_with_roots_outside_unit_interval() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [
+                [-2_380, 4_877].into(),
+                [-1_873, 3_923].into(),
+                [-915, 3_241].into(),
+            ],
+        });
+
+        assert!(contacts(line([696, 4_877], [-915, 6_035]), quad).is_empty());
+    }
+
+    #[test]
+    fn line_crosses_quad_twice() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [500, 1_000].into(), [1_000, 0].into()],
+        });
+
+        let result = contacts(line([-100, 250], [1_100, 250]), quad);
+
+        assert_eq!(result.len(), 2);
+        assert!(
+            result
+                .iter()
+                .all(|contact| contact.contact_type == ContactType::Cross)
+        );
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == IntPoint::new(146, 250))
+        );
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == IntPoint::new(854, 250))
+        );
+    }
+
+    #[test]
+    fn line_touches_quad_apex() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [500, 1_000].into(), [1_000, 0].into()],
+        });
+
+        let result = contacts(quad, line([-100, 500], [1_100, 500]));
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Tangent);
+        assert_eq!(result[0].point, IntPoint::new(500, 500));
+        assert_eq!(result[0].t0.value(), FixedScale::<i32>::DENOMINATOR / 2);
+    }
+
+    #[test]
+    fn line_misses_quad_apex() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [500, 1_000].into(), [1_000, 0].into()],
+        });
+
+        assert!(contacts(quad, line([-100, 501], [1_100, 501])).is_empty());
+    }
+
+    #[test]
+    fn line_crosses_cubic_three_times() {
+        let cubic = Segment::Cubic(CubicSegment {
+            control_points: [
+                [0, -1_000].into(),
+                [1_000, 3_000].into(),
+                [2_000, -3_000].into(),
+                [3_000, 1_000].into(),
+            ],
+        });
+
+        let result = contacts(line([-100, 0], [3_100, 0]), cubic);
+
+        assert_eq!(result.len(), 3);
+        assert!(
+            result
+                .iter()
+                .all(|contact| contact.contact_type == ContactType::Cross)
+        );
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == IntPoint::new(1_500, 0))
+        );
+    }
+
+    #[test]
+    fn line_crosses_cubic_at_a_flat_inflection() {
+        // The cubic runs along the line at its inflection but passes to the
+        // other side.
+        let cubic = Segment::Cubic(CubicSegment {
+            control_points: [
+                [0, -1_000].into(),
+                [1_000, 1_000].into(),
+                [2_000, -1_000].into(),
+                [3_000, 1_000].into(),
+            ],
+        });
+
+        let result = contacts(line([-100, 0], [3_100, 0]), cubic);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(1_500, 0));
+        assert_eq!(result[0].contact_type, ContactType::Cross);
+    }
+
+    #[test]
+    fn line_touches_cubic_extremum() {
+        let cubic = Segment::Cubic(CubicSegment {
+            control_points: [
+                [0, 0].into(),
+                [1_000, 1_500].into(),
+                [2_000, 1_500].into(),
+                [3_000, 0].into(),
+            ],
+        });
+
+        let result = contacts(line([-100, 1_125], [3_100, 1_125]), cubic);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(1_500, 1_125));
+        assert_eq!(result[0].contact_type, ContactType::Tangent);
+    }
+
+    #[test]
+    fn line_stops_at_the_curve() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [500, 1_000].into(), [1_000, 0].into()],
+        });
+
+        let result = contacts(line([500, 500], [500, 1_000]), quad);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(500, 500));
+        assert_eq!(result[0].t0.value(), 0);
+
+        assert!(contacts(line([500, 503], [500, 1_000]), quad).is_empty());
+    }
+
+    #[test]
+    fn line_crosses_arc() {
+        let one = FixedScale::<i32>::DENOMINATOR as i32;
+        let arc = Segment::Arc(ArcSegment {
+            ellipse: EllipseFrame {
+                center: IntPoint::new(0, 0),
+                axis_x: ArcVector { x: 1_000, y: 0 },
+                axis_y: ArcVector { x: 0, y: 1_000 },
+            },
+            control_points: [
+                IntPoint::new(1_000, 0),
+                IntPoint::new(1_000, 1_000),
+                IntPoint::new(0, 1_000),
+            ],
+            weights: [one, 759_250_125, one],
+            start_phase: ArcPhase { cos: one, sin: 0 },
+            end_phase: ArcPhase { cos: 0, sin: one },
+            direction: ArcDirection::CounterClockwise,
+        });
+
+        let result = contacts(line([0, 0], [1_000, 1_000]), arc);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].contact_type, ContactType::Cross);
+        assert_eq!(result[0].point, IntPoint::new(707, 707));
+
+        let result = contacts(arc, line([0, 1_000], [1_000, 1_000]));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(0, 1_000));
+        assert_eq!(result[0].t0.value(), FixedScale::<i32>::DENOMINATOR);
+    }
+
+    #[test]
+    fn collinear_curve_falls_back_to_subdivision() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [500, 0].into(), [1_000, 0].into()],
+        });
+
+        let result = contacts(line([250, 0], [750, 0]), quad);
+
+        assert!(
+            result
+                .iter()
+                .all(|contact| contact.contact_type == ContactType::Tangent)
+        );
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn nearly_collinear_curve_falls_back_to_subdivision() {
+        let segment = LineSegment {
+            control_points: [[0, 1].into(), [1_000, 1].into()],
+        };
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [500, 4].into(), [1_000, 0].into()],
+        });
+        let intersector = SegmentIntersector::new(Segment::Line(segment), quad, SplitOptions::default());
+
+        let mut output = Vec::new();
+        assert!(!intersector.intersect_line_curve(segment, quad, true, &mut output));
+        assert!(output.is_empty());
+    }
+}
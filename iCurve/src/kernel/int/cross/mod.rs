@@ -1,6 +1,12 @@
+mod arc;
+mod certify;
 mod chord;
+mod clip;
+mod float;
 #[cfg(test)]
 mod intersect;
 pub(crate) mod intersector;
+mod line;
+mod overlap;
 mod parallel;
 mod segment;
@@ -0,0 +1,419 @@
+use crate::collections::stack_vec::StackVec;
+use crate::int::CurveInt;
+use crate::kernel::int::cross::float::{
+    Bezier, Point, derivative_at, lerp, point_at, sqr_distance, sub, to_point, unit_param,
+};
+use crate::kernel::int::cross::intersector::{ContactPoint, ContactType, SegmentIntersector};
+use crate::kernel::int::curve::chord::Chord;
+use crate::kernel::int::curve::param::SegmentParam;
+use crate::kernel::int::curve::segment::Segment;
+use alloc::vec::Vec;
+use i_overlay::i_float::int::number::wide_int::WideIntNumber;
+use i_overlay::i_shape::int::IntPoint;
+
+/// Squared distance within which an end of one curve lies on the other.
+const COINCIDENT_SQR_DISTANCE: f64 = 1.0;
+
+/// Largest per-coordinate difference between control points of coinciding
+/// pieces. Each integer split rounds control points by half a unit, and
+/// either piece may come from its own split of the source curve.
+const SPLIT_ROUNDING: u32 = 2;
+
+/// Evenly spaced parameters that seed the inversion of a point.
+const INVERSION_SEEDS: usize = 8;
+
+const NEWTON_STEPS: usize = 8;
+
+/// End of one curve that lies on the other, with its parameter on both.
+#[derive(Clone, Copy, Default)]
+struct Correspondence<I: CurveInt> {
+    point: IntPoint<I>,
+    t0: f64,
+    t1: f64,
+}
+
+impl<I: CurveInt> SegmentIntersector<I> {
+    /// Detects quads or cubics that share their supporting curve, such as
+    /// two pieces of one source curve. Arcs are compared by their supporting
+    /// ellipses instead.
+    ///
+    /// The ends of each curve are inverted on the other one, and the two
+    /// outermost matches bound the candidate overlap on both curves. Both
+    /// ranges are cut out by integer subdivision and must have the same
+    /// control points up to split rounding, with quads raised to cubics.
+    /// Coinciding curves report the ends interior to the other curve as
+    /// tangent contacts, like sampled parallel overlaps. Returns `false`
+    /// when the curves do not coincide.
+    pub(super) fn intersect_overlap(&self, output: &mut Vec<ContactPoint<I>>) -> bool {
+        let (segment0, segment1) = (self.original_segment_0, self.original_segment_1);
+        if let (Segment::Arc(arc0), Segment::Arc(arc1)) = (segment0, segment1) {
+            return self.intersect_arc_overlap(arc0, arc1, output);
+        }
+        let (Some(curve0), Some(curve1)) = (float_curve(&segment0), float_curve(&segment1)) else {
+            return false;
+        };
+        let ends = [segment0, segment1].map(|segment| {
+            let chord = segment.chord();
+            [chord.a, chord.b]
+        });
+
+        let mut matches = StackVec::<Correspondence<I>, 4>::new();
+        for (end, t0) in ends[0].into_iter().zip([0.0, 1.0]) {
+            if let Some(t1) = invert(&curve1, to_point(end)) {
+                matches.push(Correspondence { point: end, t0, t1 });
+            }
+        }
+        for (end, t1) in ends[1].into_iter().zip([0.0, 1.0]) {
+            if matches.as_slice().iter().any(|item| item.point == end) {
+                continue;
+            }
+            if let Some(t0) = invert(&curve0, to_point(end)) {
+                matches.push(Correspondence { point: end, t0, t1 });
+            }
+        }
+
+        let matches = matches.as_slice();
+        let (Some(first), Some(last)) = (
+            matches.iter().min_by(|a, b| a.t0.total_cmp(&b.t0)),
+            matches.iter().max_by(|a, b| a.t0.total_cmp(&b.t0)),
+        ) else {
+            return false;
+        };
+
+        let pieces = (
+            segment0.subsegment(unit_param(first.t0), first.point, unit_param(last.t0), last.point),
+            segment1.subsegment(unit_param(first.t1), first.point, unit_param(last.t1), last.point),
+        );
+        let (Some(piece0), Some(piece1)) = pieces else {
+            return false;
+        };
+        if !same_control_points(&piece0, &piece1, false) {
+            return false;
+        }
+
+        for item in matches {
+            let t0 = unit_param::<I>(item.t0);
+            let t1 = unit_param::<I>(item.t1);
+            if (is_interior(t0) || is_interior(t1))
+                && !output.iter().any(|contact| contact.point == item.point)
+            {
+                output.push(ContactPoint {
+                    point: item.point,
+                    t0,
+                    t1,
+                    contact_type: ContactType::Tangent,
+                });
+            }
+        }
+
+        true
+    }
+}
+
+impl<I: CurveInt> Segment<I> {
+    /// Returns whether a quad or cubic traces the same curve as `other`
+    /// between the same ends, in either direction, up to the rounding of
+    /// split control points.
+    pub(crate) fn coincides_with(&self, other: &Self) -> bool {
+        let (chord0, chord1) = (self.chord(), other.chord());
+        if (chord0.a, chord0.b) == (chord1.a, chord1.b) {
+            same_control_points(self, other, false)
+        } else if (chord0.a, chord0.b) == (chord1.b, chord1.a) {
+            same_control_points(self, other, true)
+        } else {
+            false
+        }
+    }
+
+    /// Returns the parameter at which a quad, cubic, or arc passes through
+    /// `point`, up to the rounding of split control points.
+    pub(crate) fn param_of_point(&self, point: IntPoint<I>) -> Option<SegmentParam<I>> {
+        let t = match self {
+            Segment::Arc(arc) => arc.param_of_point(point)?,
+            _ => invert(&float_curve(self)?, to_point(point))?,
+        };
+        Some(unit_param::<I>(t))
+    }
+}
+
+/// Compares the control points of two quads or cubics, raised to cubics,
+/// with `reverse` walking `other` from its end.
+fn same_control_points<I: CurveInt>(segment: &Segment<I>, other: &Segment<I>, reverse: bool) -> bool {
+    let (Some(points0), Some(mut points1)) = (tripled_cubic(segment), tripled_cubic(other)) else {
+        return false;
+    };
+    if reverse {
+        points1.reverse();
+    }
+
+    let tolerance = I::Wide::from_u32(3 * SPLIT_ROUNDING);
+    points0.iter().zip(points1.iter()).all(|(a, b)| {
+        a.iter()
+            .zip(b.iter())
+            .all(|(&a, &b)| a - b <= tolerance && b - a <= tolerance)
+    })
+}
+
+/// Returns the control points of a quad or cubic as a cubic scaled by
+/// three, which keeps the degree elevation of quads exact in integers.
+fn tripled_cubic<I: CurveInt>(segment: &Segment<I>) -> Option<[[I::Wide; 2]; 4]> {
+    let wide = |point: IntPoint<I>| [point.x.to_wide(), point.y.to_wide()];
+    let three = I::Wide::from_u32(3);
+    match segment {
+        Segment::Quad(quad) => {
+            let [p0, p1, p2] = quad.control_points.map(wide);
+            let two = I::Wide::TWO;
+            Some([
+                p0.map(|value| three * value),
+                [p0[0] + two * p1[0], p0[1] + two * p1[1]],
+                [two * p1[0] + p2[0], two * p1[1] + p2[1]],
+                p2.map(|value| three * value),
+            ])
+        }
+        Segment::Cubic(cubic) => Some(
+            cubic
+                .control_points
+                .map(|point| wide(point).map(|value| three * value)),
+        ),
+        _ => None,
+    }
+}
+
+/// Returns a quad or cubic as a float cubic with the same parametrization.
+fn float_curve<I: CurveInt>(segment: &Segment<I>) -> Option<Bezier> {
+    match segment {
+        Segment::Quad(quad) => Some(elevate(quad.control_points.map(to_point))),
+        Segment::Cubic(cubic) => Some(cubic.control_points.map(to_point)),
+        _ => None,
+    }
+}
+
+/// Returns the parameter of the point of `curve` nearest to `point` when
+/// they coincide.
+fn invert(curve: &Bezier, point: Point) -> Option<f64> {
+    if sqr_distance(curve[0], point) == 0.0 {
+        return Some(0.0);
+    }
+    if sqr_distance(curve[3], point) == 0.0 {
+        return Some(1.0);
+    }
+
+    let distance = |t: f64| sqr_distance(point_at(curve, t), point);
+    let mut t = (0..=INVERSION_SEEDS)
+        .map(|index| index as f64 / INVERSION_SEEDS as f64)
+        .min_by(|&a, &b| distance(a).total_cmp(&distance(b)))
+        .unwrap_or(0.0);
+
+    for _ in 0..NEWTON_STEPS {
+        let offset = sub(point_at(curve, t), point);
+        let first = scale(derivative_at(curve, t), 3.0);
+        let second = second_derivative_at(curve, t);
+        let slope = dot(offset, first);
+        let curvature = dot(first, first) + dot(offset, second);
+        if curvature <= 0.0 {
+            break;
+        }
+        t = (t - slope / curvature).clamp(0.0, 1.0);
+    }
+
+    (distance(t) <= COINCIDENT_SQR_DISTANCE).then_some(t)
+}
+
+/// Raises a quad to the cubic with the same points and parametrization.
+fn elevate([p0, p1, p2]: [Point; 3]) -> Bezier {
+    let third = 1.0 / 3.0;
+    [p0, lerp(p1, p0, third), lerp(p1, p2, third), p2]
+}
+
+fn second_derivative_at(curve: &Bezier, t: f64) -> Point {
+    let [p0, p1, p2, p3] = *curve;
+    let a = sub(sub(p2, p1), sub(p1, p0));
+    let b = sub(sub(p3, p2), sub(p2, p1));
+    scale(lerp(a, b, t), 6.0)
+}
+
+#[inline]
+fn is_interior<I: CurveInt>(t: SegmentParam<I>) -> bool {
+    t.value() > I::Wide::ZERO && t.value() < SegmentParam::<I>::DENOMINATOR
+}
+
+#[inline]
+fn scale(vector: Point, factor: f64) -> Point {
+    Point {
+        x: vector.x * factor,
+        y: vector.y * factor,
+    }
+}
+
+#[inline]
+fn dot(a: Point, b: Point) -> f64 {
+    a.x * b.x + a.y * b.y
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::int::cross::intersector::{
+        ContactPoint, ContactType, SegmentIntersector, SplitOptions,
+    };
+    use crate::kernel::int::curve::cubic::CubicSegment;
+    use crate::kernel::int::curve::point_at::PointAt;
+    use crate::kernel::int::curve::quad::QuadSegment;
+    use crate::kernel::int::curve::segment::Segment;
+    use alloc::vec::Vec;
+    use i_overlay::i_float::int::number::fixed_scale::FixedScale;
+    use i_overlay::i_shape::int::IntPoint;
+
+    const ARCH: [[i32; 2]; 4] = [[0, 0], [0, 1_024], [1_024, 1_024], [1_024, 0]];
+
+    fn cubic(points: [[i32; 2]; 4]) -> Segment<i32> {
+        Segment::Cubic(CubicSegment {
+            control_points: points.map(IntPoint::from),
+        })
+    }
+
+    fn overlap(a: Segment<i32>, b: Segment<i32>) -> Option<Vec<ContactPoint<i32>>> {
+        let intersector = SegmentIntersector::new(a, b, SplitOptions::default());
+        let mut output = Vec::new();
+        if !intersector.intersect_overlap(&mut output) {
+            return None;
+        }
+        for contact in &output {
+            assert_eq!(contact.contact_type, ContactType::Tangent);
+            assert!(a.point_at(contact.t0).sqr_distance(contact.point) <= 2);
+            assert!(b.point_at(contact.t1).sqr_distance(contact.point) <= 2);
+        }
+        Some(output)
+    }
+
+    fn param(t: f64) -> i64 {
+        (t * FixedScale::<i32>::DENOMINATOR as f64) as i64
+    }
+
+    #[test]
+    fn cubic_contains_its_middle_piece() {
+        let piece = cubic([[160, 576], [352, 832], [672, 832], [864, 576]]);
+
+        let result = overlap(cubic(ARCH), piece).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].point, IntPoint::new(160, 576));
+        assert_eq!(result[0].t0.value(), param(0.25));
+        assert_eq!(result[0].t1.value(), 0);
+        assert_eq!(result[1].point, IntPoint::new(864, 576));
+        assert_eq!(result[1].t0.value(), param(0.75));
+    }
+
+    #[test]
+    fn reversed_pieces_overlap_partially() {
+        let left = cubic([[512, 768], [256, 768], [0, 512], [0, 0]]);
+        let piece = cubic([[160, 576], [352, 832], [672, 832], [864, 576]]);
+
+        let result = overlap(left, piece).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == IntPoint::new(160, 576))
+        );
+        assert!(
+            result
+                .iter()
+                .any(|contact| contact.point == IntPoint::new(512, 768))
+        );
+    }
+
+    #[test]
+    fn quad_contains_its_half() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [512, 1_024].into(), [1_024, 0].into()],
+        });
+        let half = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [256, 512].into(), [512, 512].into()],
+        });
+
+        let result = overlap(quad, half).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(512, 512));
+        assert_eq!(result[0].t0.value(), param(0.5));
+        assert_eq!(result[0].t1.value(), param(1.0));
+    }
+
+    #[test]
+    fn identical_cubics_overlap_without_interior_ends() {
+        assert!(overlap(cubic(ARCH), cubic(ARCH)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn cubics_sharing_ends_do_not_overlap() {
+        let flatter = cubic([[0, 0], [0, 512], [1_024, 512], [1_024, 0]]);
+
+        assert!(overlap(cubic(ARCH), flatter).is_none());
+    }
+
+    #[test]
+    fn overlap_replaces_sampled_parallel_ends() {
+        let piece = cubic([[160, 576], [352, 832], [672, 832], [864, 576]]);
+
+        let contacts = SegmentIntersector::new(cubic(ARCH), piece, SplitOptions::default()).intersect();
+
+        assert_eq!(contacts.len(), 2);
+        assert!(
+            contacts
+                .iter()
+                .all(|contact| contact.contact_type == ContactType::Tangent)
+        );
+    }
+
+    #[test]
+    fn rounded_pieces_coincide_in_either_direction() {
+        let piece = cubic([[160, 576], [352, 832], [672, 832], [864, 576]]);
+        let rounded = cubic([[864, 576], [673, 831], [351, 833], [160, 576]]);
+        let other = cubic([[160, 576], [352, 900], [672, 900], [864, 576]]);
+
+        assert!(piece.coincides_with(&piece));
+        assert!(piece.coincides_with(&rounded));
+        assert!(!piece.coincides_with(&other));
+        assert!(!piece.coincides_with(&cubic(ARCH)));
+    }
+
+    #[test]
+    fn quad_overlaps_the_cubic_it_raises_to() {
+        let raised = cubic([[0, 0], [512, 1_024], [1_024, 1_024], [1_536, 0]]);
+        let half = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [384, 768].into(), [768, 768].into()],
+        });
+
+        let result = overlap(raised, half).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point, IntPoint::new(768, 768));
+        assert_eq!(result[0].t0.value(), param(0.5));
+        assert_eq!(result[0].t1.value(), param(1.0));
+        assert!(overlap(half, raised).is_some());
+    }
+
+    #[test]
+    fn quad_and_cubic_between_shared_ends_coincide() {
+        let quad = Segment::Quad(QuadSegment {
+            control_points: [[0, 0].into(), [768, 1_536].into(), [1_536, 0].into()],
+        });
+        let raised = cubic([[1_536, 0], [1_025, 1_023], [511, 1_025], [0, 0]]);
+
+        assert!(quad.coincides_with(&raised));
+        assert!(raised.coincides_with(&quad));
+        assert!(!quad.coincides_with(&cubic([[0, 0], [512, 1_030], [1_024, 1_024], [1_536, 0]])));
+    }
+
+    #[test]
+    fn control_points_beyond_split_rounding_do_not_coincide() {
+        // Both inner control points moved by three units in opposite
+        // directions keep every point of the curve within one unit.
+        let piece = cubic([[160, 576], [352, 832], [672, 832], [864, 576]]);
+        let moved = cubic([[160, 576], [355, 832], [669, 832], [864, 576]]);
+
+        assert!(!piece.coincides_with(&moved));
+        assert!(overlap(cubic(ARCH), moved).is_none());
+    }
+}
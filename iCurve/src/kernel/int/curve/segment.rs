@@ -33,6 +33,16 @@ impl<I: CurveInt> Segment<I> {
             Segment::Arc(arc) => StackVec::with_slice_as_convex(&arc.control_points),
         }
     }
+
+    #[inline]
+    pub(crate) fn reverse(&mut self) {
+        match self {
+            Segment::Line(line) => line.control_points.reverse(),
+            Segment::Quad(quad) => quad.control_points.reverse(),
+            Segment::Cubic(cubic) => cubic.control_points.reverse(),
+            Segment::Arc(arc) => arc.reverse(),
+        }
+    }
 }
 
 impl<I: CurveInt> Chord<I> for Segment<I> {
@@ -322,12 +322,7 @@ impl<I: CurveInt> Segment<I> {
         };
 
         if reverse {
-            match &mut result {
-                Segment::Line(line) => line.control_points.reverse(),
-                Segment::Quad(quad) => quad.control_points.reverse(),
-                Segment::Cubic(cubic) => cubic.control_points.reverse(),
-                Segment::Arc(arc) => arc.reverse(),
-            }
+            result.reverse();
         }
 
         Some(result)
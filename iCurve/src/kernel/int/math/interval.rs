@@ -0,0 +1,147 @@
+use crate::int::CurveInt;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Largest magnitude below which every integer converts to `f64` exactly.
+const EXACT_INTEGER_LIMIT: f64 = 9_007_199_254_740_992.0;
+
+/// Closed `f64` interval. Every operation widens its rounded bounds by one
+/// unit in the last place, so the result encloses the exact real result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Interval {
+    pub(crate) lo: f64,
+    pub(crate) hi: f64,
+}
+
+impl Interval {
+    #[inline]
+    pub(crate) const fn new(lo: f64, hi: f64) -> Self {
+        Self { lo, hi }
+    }
+
+    #[inline]
+    pub(crate) const fn point(value: f64) -> Self {
+        Self { lo: value, hi: value }
+    }
+
+    /// Encloses an integer coordinate or weight.
+    #[inline]
+    pub(crate) fn from_int<I: CurveInt>(value: I) -> Self {
+        let value = value.to_f64();
+        if value.abs() <= EXACT_INTEGER_LIMIT {
+            Self::point(value)
+        } else {
+            Self::new(value.next_down(), value.next_up())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn mid(&self) -> f64 {
+        0.5 * (self.lo + self.hi)
+    }
+
+    #[inline]
+    pub(crate) fn contains_zero(&self) -> bool {
+        self.lo <= 0.0 && self.hi >= 0.0
+    }
+
+    /// Returns whether `other` lies in the interior of this interval.
+    #[inline]
+    pub(crate) fn contains_strictly(&self, other: &Self) -> bool {
+        self.lo < other.lo && other.hi < self.hi
+    }
+
+    #[inline]
+    pub(crate) fn is_within(&self, lo: f64, hi: f64) -> bool {
+        lo <= self.lo && self.hi <= hi
+    }
+
+    #[inline]
+    fn outward(lo: f64, hi: f64) -> Self {
+        Self::new(lo.next_down(), hi.next_up())
+    }
+
+    #[inline]
+    fn bounds(values: [f64; 4]) -> Self {
+        let lo = values.into_iter().fold(f64::INFINITY, f64::min);
+        let hi = values.into_iter().fold(f64::NEG_INFINITY, f64::max);
+        Self::outward(lo, hi)
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::bounds([
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ])
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+
+    /// Divides by an interval that must not contain zero.
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        debug_assert!(!rhs.contains_zero());
+        Self::bounds([
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interval;
+
+    #[test]
+    fn operations_enclose_exact_results() {
+        let third = Interval::point(1.0) / Interval::point(3.0);
+        assert!(third.lo < third.hi);
+        let one = third * Interval::point(3.0);
+        assert!(one.lo <= 1.0 && 1.0 <= one.hi);
+        assert!(one.is_within(0.999_999, 1.000_001));
+
+        let product = Interval::new(-2.0, 3.0) * Interval::new(-5.0, 4.0);
+        assert!(product.lo <= -15.0 && product.hi >= 12.0);
+
+        let difference = Interval::new(1.0, 2.0) - Interval::new(0.5, 3.0);
+        assert!(difference.lo <= -2.0 && difference.hi >= 1.5);
+    }
+
+    #[test]
+    fn large_integers_are_widened() {
+        let exact = Interval::from_int(1_i64 << 40);
+        assert_eq!(exact.lo, exact.hi);
+
+        let rounded = Interval::from_int(i64::MAX - 1);
+        assert!(rounded.lo < rounded.hi);
+        assert!(rounded.lo <= (i64::MAX - 1) as f64 && (i64::MAX - 1) as f64 <= rounded.hi);
+    }
+}
@@ -1,3 +1,4 @@
 pub(crate) mod angle;
+pub(crate) mod interval;
 #[cfg(test)]
 mod quadratic_equation;
@@ -4,4 +4,4 @@ pub(crate) mod cubic;
 pub(crate) mod line;
 mod monotone;
 pub(crate) mod quad;
-mod unit_quadratic;
+pub(crate) mod unit_quadratic;
@@ -5,7 +5,7 @@ use i_overlay::i_float::int::number::fixed_scale::FixedScale;
 use i_overlay::i_float::int::number::uint::UIntNumber;
 use i_overlay::i_float::int::number::wide_int::WideIntNumber;
 
-pub(super) fn solve_unit_quadratic<I: CurveInt>(
+pub(crate) fn solve_unit_quadratic<I: CurveInt>(
     a: I::Wide,
     b: I::Wide,
     c: I::Wide,
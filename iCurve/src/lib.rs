@@ -8,15 +8,20 @@ extern crate alloc;
 
 mod collections;
 pub mod float;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub mod generate;
 pub mod int;
 mod kernel;
 
 pub use float::{
-    CurveBuildError, CurveBuilder, CurveConversionError, CurveConversionReport, CurvePath as FloatCurvePath,
-    CurveResource, CurveResourceOverlayExt, CurveSegment as FloatCurveSegment, CurveShape as FloatCurveShape,
-    FloatCurveOverlay, FloatCurveOverlayConversionReport, FloatCurveOverlayOptions,
-    FloatCurveOverlayOptionsError,
+    AdaptiveCurveOverlay, AdaptiveCurveOverlayOptions, CurveBuildError, CurveBuilder, CurveConversionError,
+    CurveConversionReport, CurveEngine, CurveEscalationReason, CurvePath as FloatCurvePath, CurveResource,
+    CurveResourceOverlayExt, CurveResourceRelateExt, CurveSegment as FloatCurveSegment,
+    CurveShape as FloatCurveShape, FloatCurveOverlay, FloatCurveOverlayConversionReport,
+    FloatCurveOverlayOptions, FloatCurveOverlayOptionsError, FloatPreparedCurveSubject,
 };
 pub use i_overlay::core::fill_rule::FillRule;
+pub use i_overlay::core::overlay::ContourDirection;
 pub use i_overlay::core::overlay_rule::OverlayRule;
 pub use i_overlay::core::solver::{Precision, Solver};
+pub use int::{CurveContourNode, CurveContourTree, CurveOverlayError, CurveOverlayStage};
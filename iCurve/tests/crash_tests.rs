@@ -2,7 +2,9 @@
 mod tests {
     use i_curve::float::CurveConverter;
     use i_curve::float::arc::{Ellipse, EllipticArc};
-    use i_curve::int::{CurvePath, CurveSegment, CurveShape, IntCurveOverlay, IntPoint, ShapeType};
+    use i_curve::int::{
+        CurveOverlayRepro, CurvePath, CurveSegment, CurveShape, IntCurveOverlay, IntPoint, ShapeType,
+    };
     use i_curve::{CurveBuilder, FillRule, OverlayRule};
 
     #[test]
@@ -229,4 +231,35 @@ mod tests {
         let result = overlay.overlay(OverlayRule::Clip, FillRule::NonZero);
         assert!(!result.is_empty());
     }
+
+    /// Repro record of `test_00` as captured by `IntCurveOverlay::capture_repro`.
+    #[test]
+    fn repro_record_replays_test_00() {
+        let repro: CurveOverlayRepro<i32> = "
+            i_curve_repro 1
+            engine i32
+            overlay_rule union
+            fill_rule non_zero
+            subject -200 0
+            line 200 0
+            quad 0 240 -200 0
+            end
+            clip -110 55
+            line 110 55
+            quad -177 -145 -110 55
+            end
+        "
+        .parse()
+        .unwrap();
+
+        let mut overlay = IntCurveOverlay::new();
+        for (shape_type, path) in repro.contours() {
+            overlay
+                .add_shape(CurveShape::from_path(path.clone()), *shape_type)
+                .unwrap();
+        }
+        let result = overlay.overlay(repro.overlay_rule(), repro.fill_rule());
+        assert_eq!(repro.replay(), result);
+        assert_eq!(result.len(), 1);
+    }
 }
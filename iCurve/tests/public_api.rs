@@ -4,12 +4,15 @@ use i_curve::float::arc::{
 };
 use i_curve::int::arc::{ArcDirection, ArcPhase, ArcVector, EllipseFrame, RationalArc, RationalArcError};
 use i_curve::int::{
-    CurveInputError, CurveOverlayOptions, CurveOverlayOptionsError, CurvePath, CurveSegment, CurveShape,
-    IntCurveOverlay, IntPoint, overlay,
+    CurveArrangement, CurveInputError, CurveIntersectionStatus, CurveOverlayOptions,
+    CurveOverlayOptionsError, CurveOverlayRepro, CurvePath, CurveSegment, CurveShape, CurveVerificationIssue,
+    CurveVerificationOptions, IntCurveArrangement, IntCurveLayerOverlay, IntCurveOverlay, IntPoint,
+    ShapeType, overlay, verify_overlay,
 };
 use i_curve::{
-    CurveBuilder, CurveConversionReport, FillRule, FloatCurveOverlay, FloatCurveOverlayConversionReport,
-    FloatCurveOverlayOptions, OverlayRule, Precision, Solver,
+    ContourDirection, CurveBuilder, CurveConversionReport, CurveOverlayError, CurveOverlayStage, FillRule,
+    FloatCurveOverlay, FloatCurveOverlayConversionReport, FloatCurveOverlayOptions, OverlayRule, Precision,
+    Solver,
 };
 
 fn rectangle(x0: i32, y0: i32, x1: i32, y1: i32) -> CurveShape<i32> {
@@ -93,6 +96,86 @@ fn scoped_integer_overlay_is_complete() -> Result<(), CurveInputError> {
     Ok(())
 }
 
+#[test]
+fn arrangement_faces_report_per_path_windings() -> Result<(), CurveInputError> {
+    let mut builder = IntCurveArrangement::new();
+    let squares = builder.add_shape(rectangle(0, 0, 100, 100))?;
+    let cut = builder.add_path(CurvePath::new(
+        IntPoint::new(50, -20),
+        vec![CurveSegment::Line {
+            to: IntPoint::new(50, 120),
+        }],
+    ))?;
+    assert_eq!((squares, cut), (0..1, 1));
+
+    let arrangement = builder.build();
+
+    assert_eq!(arrangement.faces().len(), 3);
+    for (index, face) in arrangement.faces().iter().enumerate().skip(1) {
+        assert_eq!(face.winding, vec![1, 0]);
+        let shape = arrangement.face_shape(index).expect("bounded face");
+        assert_eq!(shape.contours.len(), 1);
+    }
+    assert!(
+        arrangement
+            .face_shape(CurveArrangement::<i32>::UNBOUNDED_FACE)
+            .is_none()
+    );
+    Ok(())
+}
+
+#[test]
+fn layered_overlay_labels_regions_with_covering_layers() -> Result<(), CurveInputError> {
+    let mut layers = IntCurveLayerOverlay::new();
+    let zoning = layers.add_layer(rectangle(0, 0, 100, 100), FillRule::NonZero)?;
+    let flood = layers.add_layer(rectangle(50, 0, 150, 100), FillRule::EvenOdd)?;
+    let parcel = layers.add_layer(rectangle(200, 0, 250, 50), FillRule::NonZero)?;
+
+    let regions = layers.overlay();
+
+    assert_eq!(regions.len(), 4);
+    let both = regions
+        .iter()
+        .find(|region| region.layers.contains(zoning) && region.layers.contains(flood))
+        .expect("overlap region");
+    assert_eq!(both.layers.len(), 2);
+    assert!(regions.iter().any(|region| region.layers.iter().eq([parcel])));
+    Ok(())
+}
+
+#[test]
+fn certified_overlay_reports_line_crossings() {
+    let options = CurveOverlayOptions::default().with_certified_intersections(true);
+    let mut curves = IntCurveOverlay::new().try_with_options(options).unwrap();
+    curves.add_subject(rectangle(0, 0, 100, 100)).unwrap();
+    curves.add_clip(rectangle(50, 25, 150, 75)).unwrap();
+
+    let (result, diagnostics) = curves.overlay_with_diagnostics(OverlayRule::Intersect, FillRule::NonZero);
+
+    assert_eq!(result.len(), 1);
+    assert!(diagnostics.is_certified());
+    assert_eq!(diagnostics.uncertified().count(), 0);
+
+    let points: Vec<_> = diagnostics
+        .intersections()
+        .iter()
+        .map(|item| item.point)
+        .collect();
+    assert_eq!(points.len(), 2);
+    assert!(points.contains(&IntPoint::new(100, 25)));
+    assert!(points.contains(&IntPoint::new(100, 75)));
+    for intersection in diagnostics.intersections() {
+        assert_eq!(intersection.status, CurveIntersectionStatus::Certified);
+        let [subject, clip] = intersection.curves;
+        assert_eq!(
+            (subject.shape_type, subject.contour, subject.segment),
+            (ShapeType::Subject, 0, 1)
+        );
+        assert_eq!(clip.shape_type, ShapeType::Clip);
+        assert!(subject.start.value() <= subject.end.value());
+    }
+}
+
 #[test]
 fn extended_builder_validates_before_adding_input() {
     let mut curves = IntCurveOverlay::new();
@@ -351,6 +434,123 @@ fn float_curve_resources_accept_shape_collections_and_paths() {
     assert_eq!(result.len(), 1);
 }
 
+#[test]
+fn spatial_predicates_answer_without_building_results() {
+    use i_curve::CurveResourceRelateExt as _;
+
+    let frame = float_rectangle(0.0, 0.0, 4.0, 4.0);
+    let inner = float_rectangle(1.0, 1.0, 2.0, 2.0);
+    let neighbour = float_rectangle(4.0, 0.0, 6.0, 4.0);
+    let distant = float_rectangle(10.0, 10.0, 12.0, 12.0);
+
+    assert!(frame.intersects(&inner, FillRule::NonZero));
+    assert!(frame.contains(&inner, FillRule::NonZero));
+    assert!(inner.within_as::<i64>(&frame, FillRule::NonZero));
+    assert!(!frame.within(&inner, FillRule::NonZero));
+    assert!(frame.touches(&neighbour, FillRule::NonZero));
+    assert!(!frame.touches(&inner, FillRule::NonZero));
+    assert!(frame.disjoint(&distant, FillRule::NonZero));
+    assert!(
+        [&inner, &distant]
+            .as_slice()
+            .intersects(&frame, FillRule::EvenOdd)
+    );
+
+    let mut overlay = IntCurveOverlay::new();
+    overlay.add_subject(rectangle(0, 0, 4, 4)).unwrap();
+    overlay.add_clip(rectangle(4, 4, 8, 8)).unwrap();
+    assert!(overlay.touches(FillRule::NonZero));
+
+    let overlay = FloatCurveOverlay::<_, i32>::new(&inner, &frame);
+    assert!(!overlay.disjoint(FillRule::NonZero));
+}
+
+#[test]
+fn try_overlay_is_available_on_every_overlay_entry_point() {
+    use i_curve::CurveResourceOverlayExt as _;
+
+    let subjects = [
+        float_rectangle(0.0, 0.0, 2.0, 2.0),
+        float_rectangle(4.0, 0.0, 6.0, 2.0),
+    ];
+    let clip = float_rectangle(1.0, -1.0, 5.0, 3.0);
+
+    let result: Result<_, CurveOverlayError> =
+        subjects
+            .as_slice()
+            .try_overlay(&clip, OverlayRule::Intersect, FillRule::NonZero);
+    assert_eq!(result.unwrap().len(), 2);
+    let result = subjects
+        .as_slice()
+        .try_overlay_as::<i64>(&clip, OverlayRule::Union, FillRule::NonZero);
+    assert_eq!(result.unwrap().len(), 1);
+
+    let mut overlay = IntCurveOverlay::new();
+    overlay.add_subject(rectangle(0, 0, 10, 10)).unwrap();
+    overlay.add_clip(rectangle(5, 5, 15, 15)).unwrap();
+    let result = overlay.try_overlay(OverlayRule::Xor, FillRule::NonZero).unwrap();
+    assert_eq!(result.len(), 2);
+
+    let error = CurveOverlayError::UnreconstructableEdge;
+    assert_eq!(error.stage(), CurveOverlayStage::Recomposition);
+}
+
+#[test]
+fn overlay_results_can_be_verified_against_their_operands() {
+    let subject = [rectangle(0, 0, 100, 100)];
+    let clip = [rectangle(50, 50, 150, 150)];
+    let options = CurveVerificationOptions::default();
+
+    let result = overlay(
+        subject[0].clone(),
+        clip[0].clone(),
+        OverlayRule::Union,
+        FillRule::NonZero,
+    )
+    .unwrap();
+    let verification = verify_overlay(
+        &subject,
+        &clip,
+        &result,
+        OverlayRule::Union,
+        FillRule::NonZero,
+        options,
+    );
+    assert!(verification.is_valid(), "{:?}", verification.issues());
+    assert_eq!(verification.reference_area(), 17_500.0);
+
+    let verification = verify_overlay(
+        &subject,
+        &clip,
+        &subject,
+        OverlayRule::Union,
+        FillRule::NonZero,
+        options,
+    );
+    assert!(matches!(
+        verification.issues(),
+        [CurveVerificationIssue::AreaMismatch { .. }]
+    ));
+}
+
+#[test]
+fn float_overlay_repro_records_the_adapter() {
+    let subject = float_rectangle(0.1, 0.2, 10.3, 10.7);
+    let clip = float_rectangle(4.0, 2.0, 12.0, 8.0);
+    let overlay = FloatCurveOverlay::<[f64; 2], i32>::new(&subject, &clip);
+    let scale = overlay.scale();
+
+    let repro = overlay.capture_repro(OverlayRule::Difference, FillRule::NonZero);
+    let restored: CurveOverlayRepro<i32> = repro.to_string().parse().unwrap();
+    let adapter = restored.adapter().expect("float overlay adapter");
+    assert_eq!(adapter, repro.adapter().unwrap());
+    assert_eq!(adapter.scale, scale);
+    assert_eq!(restored.contours(), repro.contours());
+
+    let result = overlay.overlay(OverlayRule::Difference, FillRule::NonZero);
+    assert_eq!(restored.replay().len(), result.len());
+}
+
 #[test]
 fn manual_integer_overlay_round_trips_through_one_adapter() {
     let subject = float_rectangle(0.0, 0.0, 10.0, 10.0);
@@ -383,6 +583,136 @@ fn manual_integer_overlay_round_trips_through_one_adapter() {
     assert_eq!(float_result[0].contours().len(), 1);
 }
 
+#[test]
+fn overlay_hierarchy_orders_islands_inside_holes() {
+    let frame: i_curve::FloatCurveShape<[f64; 2]> = CurveBuilder::new()
+        .move_to([0.0, 0.0])
+        .unwrap()
+        .line_to([10.0, 0.0])
+        .unwrap()
+        .line_to([10.0, 10.0])
+        .unwrap()
+        .line_to([0.0, 10.0])
+        .unwrap()
+        .close_contour()
+        .unwrap()
+        .move_to([2.0, 2.0])
+        .unwrap()
+        .line_to([8.0, 2.0])
+        .unwrap()
+        .line_to([8.0, 8.0])
+        .unwrap()
+        .line_to([2.0, 8.0])
+        .unwrap()
+        .close_contour()
+        .unwrap()
+        .build()
+        .unwrap();
+    let island = float_rectangle(4.0, 4.0, 6.0, 6.0);
+
+    let (shapes, tree) = FloatCurveOverlay::<_, i32>::new(&frame, &island)
+        .overlay_with_hierarchy(OverlayRule::Union, FillRule::EvenOdd);
+
+    assert_eq!(shapes.len(), 2);
+    let nodes = tree.nodes();
+    assert_eq!(nodes.len(), 3);
+    let [outer, hole, inner] = [nodes[0], nodes[1], nodes[2]];
+    assert_eq!((outer.depth, outer.parent), (0, None));
+    assert!(hole.is_hole());
+    assert_eq!(hole.parent, Some(0));
+    assert_eq!((inner.depth, inner.parent), (2, Some(1)));
+    assert_eq!(shapes[outer.shape].holes().len(), 1);
+    assert!(shapes[inner.shape].holes().is_empty());
+    assert_eq!(
+        shapes[inner.shape].outer(),
+        shapes[inner.shape].contours().first()
+    );
+
+    let shape = rectangle(0, 0, 4, 4);
+    assert_eq!(shape.outer(), shape.contours.first());
+    assert!(shape.holes().is_empty());
+}
+
+#[test]
+fn float_overlay_output_direction_reverses_result_contours() {
+    let subject = float_rectangle(0.0, 0.0, 10.0, 10.0);
+    let clip = float_rectangle(5.0, 2.0, 12.0, 8.0);
+    let signed_area = |shape: &i_curve::FloatCurveShape<[f64; 2]>| {
+        let path = shape.outer().unwrap();
+        let mut points = vec![path.start()];
+        points.extend(path.segments().iter().map(|segment| segment.end_point()));
+        points
+            .windows(2)
+            .map(|pair| pair[0][0] * pair[1][1] - pair[1][0] * pair[0][1])
+            .sum::<f64>()
+    };
+
+    let run = |direction| {
+        let options = FloatCurveOverlayOptions::default().with_output_direction(direction);
+        FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+            .try_with_options(options)
+            .unwrap()
+            .overlay(OverlayRule::Union, FillRule::NonZero)
+    };
+
+    assert!(signed_area(&run(ContourDirection::CounterClockwise)[0]) > 0.0);
+    assert!(signed_area(&run(ContourDirection::Clockwise)[0]) < 0.0);
+}
+
+#[test]
+fn canonical_float_output_is_independent_of_operand_order() {
+    let left = float_rectangle(0.0, 0.0, 4.0, 4.0);
+    let right = float_rectangle(6.0, 0.0, 10.0, 4.0);
+    let options = FloatCurveOverlayOptions::default().with_canonical_output(true);
+    let union = |subject, clip| {
+        FloatCurveOverlay::<_, i32>::new(subject, clip)
+            .try_with_options(options)
+            .unwrap()
+            .overlay(OverlayRule::Union, FillRule::NonZero)
+    };
+
+    let result = union(&right, &left);
+
+    assert_eq!(result, union(&left, &right));
+    assert_eq!(result[0].outer().unwrap().start(), [0.0, 0.0]);
+    assert_eq!(result[1].outer().unwrap().start(), [6.0, 0.0]);
+}
+
+#[test]
+fn float_slivers_are_dropped_in_input_units() {
+    let subject = float_rectangle(0.0, 0.0, 10.0, 10.0);
+    let clip = float_rectangle(0.0, 0.0, 10.0, 9.999);
+    let difference = |options| {
+        FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+            .try_with_options(options)
+            .unwrap()
+            .overlay(OverlayRule::Difference, FillRule::NonZero)
+    };
+
+    assert_eq!(difference(FloatCurveOverlayOptions::default()).len(), 1);
+    assert!(difference(FloatCurveOverlayOptions::default().with_min_area_perimeter_ratio(0.01)).is_empty());
+    assert!(difference(FloatCurveOverlayOptions::default().with_min_contour_area(0.1)).is_empty());
+}
+
+#[test]
+fn float_micro_segments_are_absorbed_in_input_units() {
+    let subject = float_rectangle(0.0, 0.0, 10.0, 10.0);
+    let clip = float_rectangle(10.0, 0.001, 20.0, 10.001);
+    let union = |options| {
+        FloatCurveOverlay::<_, i32>::new(&subject, &clip)
+            .try_with_options(options)
+            .unwrap()
+            .overlay(OverlayRule::Union, FillRule::NonZero)
+    };
+
+    let plain = union(FloatCurveOverlayOptions::default());
+    let cleaned = union(FloatCurveOverlayOptions::default().with_min_segment_length(0.01));
+
+    assert_eq!(plain[0].outer().unwrap().segments().len(), 8);
+    assert_eq!(cleaned.len(), 1);
+    assert_eq!(cleaned[0].outer().unwrap().segments().len(), 6);
+}
+
 #[test]
 fn float_overlay_supports_explicit_i64_solver() {
     let subject = float_rectangle(0.0, 0.0, 10.0, 10.0);